@@ -17,7 +17,10 @@ fn get_chunks(name: &str) -> Result<(Vec<Chunk>, Option<TextureAtlas2d<Texture2d
     name
   );
 
-  GoxReader::read(&file)
+  let (mut chunks, texture): (Vec<Chunk>, _) = GoxReader::read(&file)?;
+  Chunk::update_neighbors(&mut chunks);
+
+  Ok((chunks, texture))
 }
 
 fn voxel_benchmark(c: &mut Criterion) {
@@ -0,0 +1,134 @@
+use gaiku_common::{
+  dual::{corner, corner_values, normal, EDGES},
+  prelude::*,
+};
+use glam::Vec3;
+
+/// Point where the surface crosses an edge of a cell, with the surface normal at that point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HermitePoint {
+  pub position: [f32; 3],
+  pub normal: [f32; 3],
+}
+
+/// Hermite data of the cells of a chunk, the cell `[x, y, z]` goes from the voxel `[x, y, z]`
+/// to the voxel `[x + 1, y + 1, z + 1]`, it has the cells from `-1` to `size - 1` so the cells
+/// made with the neighbor data are included.
+///
+/// The normals are the gradient of the trilinear interpolation of the cell corners, they only
+/// depend on the cell so the chunks that share a cell get the same data.
+#[derive(Clone, Debug)]
+pub struct HermiteData {
+  size: [isize; 3],
+  cells: Vec<Vec<HermitePoint>>,
+}
+
+impl HermiteData {
+  pub fn new<V, C>(chunk: &C, isosurface: &Isosurface) -> Self
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+  {
+    let size = [
+      chunk.width() as isize + 1,
+      chunk.height() as isize + 1,
+      chunk.depth() as isize + 1,
+    ];
+    let mut cells = Vec::with_capacity((size[0] * size[1] * size[2]) as usize);
+
+    for z in -1..size[2] - 1 {
+      for y in -1..size[1] - 1 {
+        for x in -1..size[0] - 1 {
+          let values = corner_values(chunk, isosurface, [x, y, z]);
+          cells.push(intersections([x, y, z], &values));
+        }
+      }
+    }
+
+    Self { size, cells }
+  }
+
+  /// Hermite data of the cell `[x, y, z]`, empty if the surface doesn't cross it.
+  pub fn get(&self, x: isize, y: isize, z: isize) -> &[HermitePoint] {
+    let [width, height, depth] = self.size;
+
+    if x < -1 || y < -1 || z < -1 || x >= width - 1 || y >= height - 1 || z >= depth - 1 {
+      return &[];
+    }
+
+    &self.cells[((x + 1) + (y + 1) * width + (z + 1) * width * height) as usize]
+  }
+}
+
+/// Points where the surface crosses the edges of the cell at `cell` with the signed density
+/// `values` of its corners.
+fn intersections(cell: [isize; 3], values: &[f32; 8]) -> Vec<HermitePoint> {
+  let origin = Vec3::new(cell[0] as f32, cell[1] as f32, cell[2] as f32);
+
+  EDGES
+    .iter()
+    .filter(|(start, end)| (values[*start] >= 0.0) != (values[*end] >= 0.0))
+    .map(|(start, end)| {
+      let t = values[*start] / (values[*start] - values[*end]);
+      let start = Vec3::from(corner(*start));
+      let local = start + (Vec3::from(corner(*end)) - start) * t;
+
+      HermitePoint {
+        position: (origin + local).into(),
+        normal: normal(values, local.into()),
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::chunk::Chunk;
+
+  #[test]
+  fn neighbor_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut neighbor = Chunk::new([4.0, 0.0, 0.0], 4, 4, 4);
+
+    for x in 0..4 {
+      for y in 0..4 {
+        for z in 0..4 {
+          // A slope rising along x and z
+          let density = |world_x: f32| {
+            ((3.5 - y as f32 + (world_x + z as f32) * 0.3) * 60.0).clamp(0.0, 255.0) as u8
+          };
+
+          chunk.set(x, y, z, (1, density(x as f32)));
+          neighbor.set(x, y, z, (1, density(x as f32 + 4.0)));
+        }
+      }
+    }
+
+    chunk.update_neighbor_data(&neighbor);
+    neighbor.update_neighbor_data(&chunk);
+
+    let isosurface = Isosurface::default();
+    let data = HermiteData::new(&chunk, &isosurface);
+    let neighbor_data = HermiteData::new(&neighbor, &isosurface);
+
+    assert!(data.get(0, 3, 0).len() >= 3);
+    assert!(data.get(4, 0, 0).is_empty());
+
+    // The last cell of the chunk is the first cell of the neighbor
+    for y in -1..4 {
+      for z in -1..4 {
+        let points = data.get(3, y, z);
+        let neighbor_points = neighbor_data.get(-1, y, z);
+
+        assert_eq!(points.len(), neighbor_points.len());
+        for (point, neighbor_point) in points.iter().zip(neighbor_points.iter()) {
+          let position = Vec3::from(neighbor_point.position) + Vec3::new(4.0, 0.0, 0.0);
+
+          assert!((Vec3::from(point.position) - position).length() < 1e-5);
+          assert_eq!(point.normal, neighbor_point.normal);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  dual::{add_quad, quads, Diagonal},
+  prelude::*,
+  Result,
+};
+use glam::Vec3;
+
+mod hermite;
+mod qef;
+
+pub use self::hermite::{HermiteData, HermitePoint};
+
+/// Dual contouring terrain generation, it places a vertex in each cell crossed by the surface
+/// solving the quadratic error function of the cell `HermiteData`, so the sharp edges and
+/// corners of the density are kept instead of rounded as marching cubes does.
+///
+/// Each edge crossed by the surface adds a quad joining the vertices of the 4 cells around it,
+/// with the material of the voxel inside the surface as atlas index. When the options have a
+/// texture each quad is mapped to the tile of its material with `triplanar_uv`.
+pub struct DualContouringBaker;
+
+impl DualContouringBaker {
+  fn bake_chunk<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    let size = [
+      chunk.width() as isize,
+      chunk.height() as isize,
+      chunk.depth() as isize,
+    ];
+    let isosurface = &options.isosurface;
+    let hermite = HermiteData::new(chunk, isosurface);
+    let mut vertices = HashMap::new();
+
+    // The vertices of the cells in the neighbor data can be moved up to its far side
+    let mut builder = MeshBuilder::create(
+      [
+        size[0] as f32 / 2.0,
+        size[1] as f32 / 2.0,
+        size[2] as f32 / 2.0,
+      ],
+      [
+        size[0] as f32 + 2.0,
+        size[1] as f32 + 2.0,
+        size[2] as f32 + 2.0,
+      ],
+    );
+
+    quads(chunk, isosurface, |cells, material| {
+      let quad = cells.map(|cell| {
+        *vertices
+          .entry(cell)
+          .or_insert_with(|| cell_vertex(&hermite, cell))
+      });
+
+      add_quad(&mut builder, options, quad, Diagonal::Flattest, material);
+    });
+
+    Ok(builder.build::<M>())
+  }
+}
+
+impl Baker for DualContouringBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Subsample,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
+
+/// Position of the vertex of the cell and its normal, the average of the Hermite normals.
+fn cell_vertex(hermite: &HermiteData, cell: [isize; 3]) -> ([f32; 3], [f32; 3]) {
+  let points = hermite.get(cell[0], cell[1], cell[2]);
+  let start = Vec3::new(cell[0] as f32, cell[1] as f32, cell[2] as f32);
+  let position = qef::solve(points, start, start + Vec3::one());
+  let normal = points
+    .iter()
+    .fold(Vec3::zero(), |sum, point| sum + Vec3::from(point.normal));
+
+  if normal.length_squared() > 0.0 {
+    (position.into(), normal.normalize().into())
+  } else {
+    (position.into(), [0.0; 3])
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{
+    chunk::Chunk,
+    mesh::Mesh,
+    texture::{Texture2d, TextureAtlas2d},
+  };
+
+  fn bake(chunk: &Chunk, options: &BakerOptions<Texture2d>) -> Mesh {
+    DualContouringBaker::bake::<_, Chunk, Texture2d, Mesh>(chunk, options)
+      .unwrap()
+      .unwrap()
+  }
+
+  fn options(shading: Shading) -> BakerOptions<Texture2d> {
+    BakerOptions {
+      shading,
+      isosurface: Isosurface {
+        isolevel: 0.5,
+        ..Default::default()
+      },
+      ..Default::default()
+    }
+  }
+
+  /// Chunk with a solid box from `start` to `end`, the density falls linearly out of its faces.
+  fn cuboid(start: f32, end: f32) -> Chunk {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 10, 10, 10);
+
+    for x in 0..10 {
+      for y in 0..10 {
+        for z in 0..10 {
+          let distance = [x, y, z]
+            .iter()
+            .map(|value| (*value as f32 - start).min(end - *value as f32))
+            .fold(f32::MAX, f32::min);
+          let density = (127.5 + distance * 50.0).clamp(0.0, 255.0);
+
+          chunk.set(x, y, z, (1, density.round() as u8));
+        }
+      }
+    }
+
+    chunk
+  }
+
+  #[test]
+  fn plane_test() {
+    // The chunk in the middle of 3x3 chunks, so the ground continues past its borders
+    let mut chunks = vec![];
+    for x in -1..2 {
+      for z in -1..2 {
+        let mut chunk = Chunk::new([x as f32 * 4.0, 0.0, z as f32 * 4.0], 4, 8, 4);
+
+        for x in 0..4 {
+          for y in 0..8 {
+            for z in 0..4 {
+              let density = (128.0 + (3.4 - y as f32) * 50.0).clamp(0.0, 255.0);
+              chunk.set(x, y, z, (2, density as u8));
+            }
+          }
+        }
+
+        chunks.push(chunk);
+      }
+    }
+    Chunk::update_neighbors(&mut chunks);
+    let chunk = &chunks[4];
+
+    let mesh = bake(chunk, &options(Shading::Smooth));
+
+    // One quad per vertical edge, the surface is at the isolevel between 148 and 98
+    assert_eq!(mesh.get_indices().len(), 4 * 4 * 6);
+    for position in mesh.get_positions() {
+      assert!((position[1] - 3.41).abs() < 1e-4);
+    }
+    for normal in mesh.get_normals() {
+      assert!(Vec3::from(*normal).abs_diff_eq(Vec3::unit_y(), 1e-4));
+    }
+    assert!(mesh.get_atlas_indices().iter().all(|index| *index == 2));
+  }
+
+  #[test]
+  fn sharp_features_test() {
+    let chunk = cuboid(2.3, 6.6);
+    let mesh = bake(&chunk, &options(Shading::Flat));
+    let positions = mesh.get_positions();
+    let nearest = |points: &mut dyn Iterator<Item = [f32; 3]>, target: [f32; 3]| {
+      points
+        .map(|point| (Vec3::from(point) - Vec3::from(target)).length())
+        .fold(f32::MAX, f32::min)
+    };
+
+    // Marching cubes places its vertices on the edge intersections, cutting the corners and
+    // edges of the box
+    let hermite = HermiteData::new(&chunk, &options(Shading::Flat).isosurface);
+    let mut intersections = vec![];
+    for x in 0..10 {
+      for y in 0..10 {
+        for z in 0..10 {
+          intersections.extend(hermite.get(x, y, z).iter().map(|point| point.position));
+        }
+      }
+    }
+
+    let mut features = vec![[2.3, 2.3, 4.5], [6.6, 4.5, 6.6], [4.5, 2.3, 6.6]];
+    for &x in [2.3, 6.6].iter() {
+      for &y in [2.3, 6.6].iter() {
+        for &z in [2.3, 6.6].iter() {
+          features.push([x, y, z]);
+        }
+      }
+    }
+
+    for feature in features {
+      let vertex = nearest(&mut positions.iter().copied(), feature);
+      let intersection = nearest(&mut intersections.iter().copied(), feature);
+
+      assert!(vertex < intersection * 0.7, "{} {}", vertex, intersection);
+    }
+
+    // The triangles face outwards
+    let center = Vec3::new(4.45, 4.45, 4.45);
+    for triangle in mesh.get_indices().chunks(3) {
+      let [a, b, c] = [0, 1, 2].map(|index| Vec3::from(positions[triangle[index] as usize]));
+      let normal = (b - a).cross(c - a);
+
+      assert!(normal.dot((a + b + c) / 3.0 - center) > 0.0);
+    }
+  }
+
+  #[test]
+  fn options_test() {
+    let chunk = cuboid(2.3, 6.6);
+    let options = BakerOptions {
+      texture: Some(TextureAtlas2d::<Texture2d>::new(16)),
+      level_of_detail: 2,
+      ..options(Shading::Smooth)
+    };
+    let mesh = bake(&chunk, &options);
+
+    assert_eq!(mesh.get_uvs().len(), mesh.get_positions().len());
+    assert_eq!(mesh.get_normals().len(), mesh.get_positions().len());
+
+    // Every uv is inside the tile of its material
+    let texture = options.texture.as_ref().unwrap();
+    for (uv, atlas_index) in mesh.get_uvs().iter().zip(mesh.get_atlas_indices().iter()) {
+      let (start, _, end, _) = texture.get_uv(*atlas_index);
+
+      assert!(start[0] <= uv[0] && uv[0] <= end[0]);
+      assert!(start[1] <= uv[1] && uv[1] <= end[1]);
+    }
+
+    // The downsampled mesh is scaled back to the chunk size
+    let max = mesh
+      .get_positions()
+      .iter()
+      .fold(0.0f32, |max, position| max.max(position[0]));
+    assert!(max > 5.0 && max <= 10.0);
+
+    let empty = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    assert!(
+      DualContouringBaker::bake::<_, Chunk, Texture2d, Mesh>(&empty, &Default::default())
+        .unwrap()
+        .is_none()
+    );
+  }
+}
@@ -0,0 +1,175 @@
+use glam::Vec3;
+
+use crate::hermite::HermitePoint;
+
+/// Eigenvalues smaller than this fraction of the largest one are dropped, so the vertex only
+/// moves away from the mass point along the directions constrained by the normals.
+const THRESHOLD: f32 = 0.1;
+
+/// Rotation sweeps of the Jacobi eigenvalue method, a 3x3 matrix converges in a few.
+const SWEEPS: usize = 8;
+
+/// Returns the point that minimizes the squared distance to the tangent planes of `points`
+/// (the quadratic error function), clamped to the box from `start` to `end`.
+///
+/// The system is solved around the mass point of `points` with the truncated pseudo-inverse, so
+/// flat areas and edges keep the vertex close to the mass point on the free directions.
+pub(crate) fn solve(points: &[HermitePoint], start: Vec3, end: Vec3) -> Vec3 {
+  if points.is_empty() {
+    return (start + end) / 2.0;
+  }
+
+  let mass_point = points
+    .iter()
+    .fold(Vec3::zero(), |sum, point| sum + Vec3::from(point.position))
+    / points.len() as f32;
+
+  let mut ata = [[0.0; 3]; 3];
+  let mut atb = [0.0; 3];
+
+  for point in points {
+    let normal = point.normal;
+    let distance = Vec3::from(normal).dot(Vec3::from(point.position) - mass_point);
+
+    for row in 0..3 {
+      for column in 0..3 {
+        ata[row][column] += normal[row] * normal[column];
+      }
+      atb[row] += normal[row] * distance;
+    }
+  }
+
+  let (eigenvalues, eigenvectors) = eigen(ata);
+  let largest = eigenvalues
+    .iter()
+    .fold(0.0f32, |max, value| max.max(*value));
+  let mut offset = Vec3::zero();
+
+  for (index, eigenvalue) in eigenvalues.iter().enumerate() {
+    if largest <= 0.0 || *eigenvalue < largest * THRESHOLD {
+      continue;
+    }
+
+    let eigenvector = Vec3::new(
+      eigenvectors[0][index],
+      eigenvectors[1][index],
+      eigenvectors[2][index],
+    );
+
+    offset += eigenvector * (eigenvector.dot(atb.into()) / eigenvalue);
+  }
+
+  (mass_point + offset).max(start).min(end)
+}
+
+/// Eigenvalues and eigenvectors (the columns of the matrix) of the symmetric matrix `matrix`
+/// with the Jacobi eigenvalue method.
+fn eigen(matrix: [[f32; 3]; 3]) -> ([f32; 3], [[f32; 3]; 3]) {
+  let mut matrix = matrix;
+  let mut eigenvectors = IDENTITY;
+
+  for _ in 0..SWEEPS {
+    for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+      if matrix[p][q].abs() < 1e-9 {
+        continue;
+      }
+
+      let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+      let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+      let cos = 1.0 / (t * t + 1.0).sqrt();
+      let sin = t * cos;
+
+      let mut rotation = IDENTITY;
+      rotation[p][p] = cos;
+      rotation[q][q] = cos;
+      rotation[p][q] = sin;
+      rotation[q][p] = -sin;
+
+      matrix = multiply(transpose(rotation), multiply(matrix, rotation));
+      eigenvectors = multiply(eigenvectors, rotation);
+    }
+  }
+
+  ([matrix[0][0], matrix[1][1], matrix[2][2]], eigenvectors)
+}
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+fn multiply(a: [[f32; 3]; 3], b: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+  let mut result = [[0.0; 3]; 3];
+
+  for row in 0..3 {
+    for column in 0..3 {
+      result[row][column] = (0..3).map(|index| a[row][index] * b[index][column]).sum();
+    }
+  }
+
+  result
+}
+
+fn transpose(matrix: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+  let mut result = [[0.0; 3]; 3];
+
+  for row in 0..3 {
+    for column in 0..3 {
+      result[row][column] = matrix[column][row];
+    }
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn point(position: [f32; 3], normal: [f32; 3]) -> HermitePoint {
+    HermitePoint { position, normal }
+  }
+
+  fn assert_near(a: Vec3, b: [f32; 3]) {
+    assert!((a - Vec3::from(b)).length() < 1e-4, "{:?} != {:?}", a, b);
+  }
+
+  #[test]
+  fn corner_test() {
+    let start = Vec3::zero();
+    let end = Vec3::new(2.0, 2.0, 2.0);
+
+    // Three planes meet at the corner, far from the mass point
+    let corner = [
+      point([1.2, 0.1, 0.3], [1.0, 0.0, 0.0]),
+      point([0.2, 1.2, 0.5], [0.0, 1.0, 0.0]),
+      point([0.4, 0.1, 1.2], [0.0, 0.0, 1.0]),
+    ];
+    assert_near(solve(&corner, start, end), [1.2, 1.2, 1.2]);
+
+    // Two planes keep the mass point along the edge
+    let edge = [
+      point([1.5, 0.2, 0.0], [1.0, 0.0, 0.0]),
+      point([0.5, 0.5, 1.0], [0.0, 0.0, 1.0]),
+      point([1.5, 0.8, 2.0], [1.0, 0.0, 0.0]),
+    ];
+    assert_near(solve(&edge, start, end), [1.5, 0.5, 1.0]);
+
+    // A tilted plane projects the mass point on it
+    let normal = Vec3::new(1.0, 1.0, 0.0).normalize().into();
+    let plane = [
+      point([1.0, 0.0, 0.0], normal),
+      point([0.0, 1.0, 2.0], normal),
+    ];
+    assert_near(solve(&plane, start, end), [0.5, 0.5, 1.0]);
+  }
+
+  #[test]
+  fn clamp_test() {
+    // The planes meet below the cell
+    let points = [
+      point([1.0, 0.8, 0.5], [0.0, 1.0, 0.0]),
+      point([0.0, 0.0, 0.5], Vec3::new(0.0, 1.0, 1.0).normalize().into()),
+    ];
+    assert_near(solve(&points, Vec3::zero(), Vec3::one()), [0.5, 0.8, 0.0]);
+
+    assert_near(solve(&[], Vec3::zero(), Vec3::one()), [0.5, 0.5, 0.5]);
+  }
+}
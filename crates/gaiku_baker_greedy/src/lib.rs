@@ -0,0 +1,416 @@
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  mesh::face_occlusion,
+  prelude::*,
+  texture::TILES,
+  Result,
+};
+
+/// Cubical voxel terrain generation that merges the coplanar faces with the same atlas index
+/// (and ambient occlusion when enabled) into rectangles, it culls the same faces as
+/// `VoxelBaker` but generates far less vertices.
+///
+/// When the options have a texture the uvs are in atlas space and start at the tile of the face,
+/// running a tile per voxel, so the faces of a single voxel are textured as with `VoxelBaker`
+/// and the merged ones need a shader that wraps the uvs inside the tile given by
+/// `Meshify::get_tile_rects`.
+pub struct GreedyVoxelBaker;
+
+impl GreedyVoxelBaker {
+  fn bake_chunk<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    let size = [
+      chunk.width() as usize,
+      chunk.height() as usize,
+      chunk.depth() as usize,
+    ];
+    let mut builder = MeshBuilder::create(
+      [
+        size[0] as f32 / 2.0,
+        size[1] as f32 / 2.0,
+        size[2] as f32 / 2.0,
+      ],
+      [size[0] as f32, size[1] as f32, size[2] as f32],
+    );
+
+    for axis in 0..3 {
+      // The slices are walked on the other two axes, `u` cross `v` points towards `axis`
+      let u = (axis + 1) % 3;
+      let v = (axis + 2) % 3;
+      let mut mask: Vec<Option<(u16, [u8; 4])>> = vec![None; size[u] * size[v]];
+      let mut normal = [0.0; 3];
+
+      for &side in [1, -1].iter() {
+        normal[axis] = side as f32;
+
+        // Corners of the rectangle at `i`, `j`, counter-clockwise when looking at `u` cross `v`
+        let rectangle = |slice: usize, i: usize, j: usize, width: usize, height: usize| {
+          let plane = if side > 0 { slice + 1 } else { slice } as f32;
+          let corners = [
+            (i, j),
+            (i + width, j),
+            (i + width, j + height),
+            (i, j + height),
+          ];
+          let mut face = [[0.0; 3]; 4];
+
+          for (index, (corner_u, corner_v)) in corners.iter().enumerate() {
+            face[index][axis] = plane;
+            face[index][u] = *corner_u as f32;
+            face[index][v] = *corner_v as f32;
+          }
+
+          face
+        };
+
+        for slice in 0..size[axis] {
+          for j in 0..size[v] {
+            for i in 0..size[u] {
+              let mut position = [0; 3];
+              position[axis] = slice as isize;
+              position[u] = i as isize;
+              position[v] = j as isize;
+
+              let mut neighbor = position;
+              neighbor[axis] += side;
+
+              let [x, y, z] = position;
+              let [nx, ny, nz] = neighbor;
+
+              mask[i + j * size[u]] = if !chunk.is_air(x, y, z) && chunk.is_air(nx, ny, nz) {
+                // The materials past the last tile of the atlas use it, as in `get_uv`
+                let atlas_index = chunk.get(x, y, z).material().min(TILES - 1);
+                let occlusion = if options.ambient_occlusion {
+                  face_occlusion(chunk, position, normal, &rectangle(slice, i, j, 1, 1))
+                } else {
+                  [3; 4]
+                };
+
+                Some((atlas_index, occlusion))
+              } else {
+                None
+              };
+            }
+          }
+
+          for j in 0..size[v] {
+            let mut i = 0;
+
+            while i < size[u] {
+              let key = mask[i + j * size[u]];
+              let (atlas_index, mut occlusion) = match key {
+                Some(key) => key,
+                None => {
+                  i += 1;
+                  continue;
+                }
+              };
+
+              let mut width = 1;
+              while i + width < size[u] && mask[i + width + j * size[u]] == key {
+                width += 1;
+              }
+
+              let mut height = 1;
+              while j + height < size[v]
+                && (i..i + width).all(|k| mask[k + (j + height) * size[u]] == key)
+              {
+                height += 1;
+              }
+
+              for row in j..j + height {
+                for k in i..i + width {
+                  mask[k + row * size[u]] = None;
+                }
+              }
+
+              let mut face = rectangle(slice, i, j, width, height);
+              let tile = options.texture.as_ref().map(|texture| {
+                let (start, _, end, _) = texture.get_uv(atlas_index);
+                let mut uv = [[0.0; 2]; 4];
+
+                for (index, vertex) in face.iter().enumerate() {
+                  let offset = [0, 1, 2].map(|axis| vertex[axis] - face[0][axis]);
+                  let [tile_u, tile_v] = tile_uv(axis, offset);
+
+                  uv[index] = [
+                    start[0] + tile_u * (end[0] - start[0]),
+                    start[1] + tile_v * (end[1] - start[1]),
+                  ];
+                }
+
+                (uv, [start[0], start[1], end[0], end[1]])
+              });
+              let mut uv = tile.map(|(uv, _)| uv);
+
+              if side < 0 {
+                face.swap(1, 3);
+                occlusion.swap(1, 3);
+                if let Some(uv) = uv.as_mut() {
+                  uv.swap(1, 3);
+                }
+              }
+
+              builder.add_tiled_face(
+                face,
+                Some(normal),
+                uv,
+                atlas_index,
+                Some(occlusion).filter(|_| options.ambient_occlusion),
+                tile.map(|(_, tile_rect)| tile_rect),
+              );
+
+              i += width;
+            }
+          }
+        }
+      }
+    }
+
+    Ok(builder.build::<M>())
+  }
+}
+
+impl Baker for GreedyVoxelBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Majority,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
+
+/// Uv in voxel units of an offset along a face perpendicular to `axis`, the `v` coordinate of
+/// the side faces follows the `y` axis so the textures stand upright.
+fn tile_uv(axis: usize, [x, y, z]: [f32; 3]) -> [f32; 2] {
+  match axis {
+    0 => [z, y],
+    1 => [x, z],
+    _ => [x, y],
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{
+    chunk::Chunk,
+    mesh::Mesh,
+    texture::{Texture2d, TextureAtlas2d},
+  };
+
+  fn bake(chunk: &Chunk, options: &BakerOptions<Texture2d>) -> Mesh {
+    GreedyVoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(chunk, options)
+      .unwrap()
+      .unwrap()
+  }
+
+  /// Cross product of the triangle `triangle` edges.
+  fn cross(mesh: &Mesh, triangle: &[u32]) -> [f32; 3] {
+    let positions = mesh.get_positions();
+    let [a, b, c] = [
+      positions[triangle[0] as usize],
+      positions[triangle[1] as usize],
+      positions[triangle[2] as usize],
+    ];
+    let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+
+    [
+      ab[1] * ac[2] - ab[2] * ac[1],
+      ab[2] * ac[0] - ab[0] * ac[2],
+      ab[0] * ac[1] - ab[1] * ac[0],
+    ]
+  }
+
+  /// Sum of the area of all the triangles of the mesh.
+  fn area(mesh: &Mesh) -> f32 {
+    mesh
+      .get_indices()
+      .chunks(3)
+      .map(|triangle| {
+        let [x, y, z] = cross(mesh, triangle);
+        (x * x + y * y + z * z).sqrt() / 2.0
+      })
+      .sum()
+  }
+
+  #[test]
+  fn simple_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 1, 1, 1);
+
+    chunk.set(0, 0, 0, (0, 1));
+
+    let mesh = bake(&chunk, &Default::default());
+
+    assert_eq!(mesh.get_indices().len(), 36);
+    assert_eq!(mesh.get_positions().len(), 24);
+  }
+
+  #[test]
+  fn merge_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+
+    chunk.fill([0, 0, 0], [4, 1, 4], (1, 1));
+
+    let mesh = bake(&chunk, &Default::default());
+
+    assert_eq!(mesh.get_indices().len(), 36);
+    assert_eq!(mesh.get_positions().len(), 24);
+    assert_eq!(area(&mesh), 48.0);
+
+    // The faces are counter-clockwise, so the triangles normals match the vertex normals
+    let normals = mesh.get_normals();
+    for triangle in mesh.get_indices().chunks(3) {
+      let [x, y, z] = cross(&mesh, triangle);
+      let normal = normals[triangle[0] as usize];
+
+      assert!(x * normal[0] + y * normal[1] + z * normal[2] > 0.0);
+    }
+  }
+
+  #[test]
+  fn materials_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 2, 1, 1);
+
+    chunk.set(0, 0, 0, (1, 1));
+    chunk.set(1, 0, 0, (2, 1));
+
+    let mesh = bake(&chunk, &Default::default());
+
+    // Only the left and right faces can't be merged
+    assert_eq!(mesh.get_indices().len(), 10 * 6);
+    assert_eq!(mesh.get_atlas_indices().len(), mesh.get_positions().len());
+    assert!(mesh.get_atlas_indices().contains(&1));
+    assert!(mesh.get_atlas_indices().contains(&2));
+  }
+
+  #[test]
+  fn neighbor_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 2, 2, 2);
+    let mut neighbor = Chunk::new([2.0, 0.0, 0.0], 2, 2, 2);
+
+    chunk.fill([0, 0, 0], [2, 2, 2], (1, 1));
+    chunk.set(1, 1, 1, (0, 0));
+    neighbor.fill([0, 0, 0], [2, 2, 2], (1, 1));
+    chunk.update_neighbor_data(&neighbor);
+
+    let mesh = bake(&chunk, &Default::default());
+
+    // 7 voxels with 6 faces each, minus the 9 shared faces and the 3 faces facing the neighbor
+    assert_eq!(area(&mesh), (7 * 6 - 9 * 2 - 3) as f32);
+  }
+
+  #[test]
+  fn uv_test() {
+    let options = BakerOptions {
+      texture: Some(TextureAtlas2d::<Texture2d>::new(16)),
+      ..Default::default()
+    };
+    let (start, _, end, _) = options.texture.as_ref().unwrap().get_uv(1);
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+
+    chunk.fill([0, 0, 0], [4, 2, 3], (1, 1));
+
+    let mesh = bake(&chunk, &options);
+    let uvs = mesh.get_uvs();
+
+    assert_eq!(uvs.len(), mesh.get_positions().len());
+    assert_eq!(
+      mesh.get_tile_rects(),
+      vec![[start[0], start[1], end[0], end[1]]; uvs.len()].as_slice()
+    );
+
+    // The top face starts at the tile and repeats it 4 times along x and 3 along z
+    let vertices = mesh.get_positions().iter().zip(mesh.get_normals().iter());
+    for ((position, normal), uv) in vertices.zip(uvs.iter()) {
+      if *normal == [0.0, 1.0, 0.0] {
+        let expected = [
+          start[0] + position[0] * (end[0] - start[0]),
+          start[1] + position[2] * (end[1] - start[1]),
+        ];
+
+        assert!((uv[0] - expected[0]).abs() < 1e-6 && (uv[1] - expected[1]).abs() < 1e-6);
+      }
+    }
+
+    let mesh = bake(&chunk, &Default::default());
+    assert!(mesh.get_uvs().is_empty());
+    assert!(mesh.get_tile_rects().is_empty());
+  }
+
+  #[test]
+  fn single_voxel_uv_test() {
+    let options = BakerOptions {
+      texture: Some(TextureAtlas2d::<Texture2d>::new(16)),
+      ..Default::default()
+    };
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 1, 1, 1);
+
+    // The materials past the last tile use it
+    chunk.set(0, 0, 0, (255, 1));
+
+    let mesh = bake(&chunk, &options);
+    let (start, _, end, _) = options.texture.as_ref().unwrap().get_uv(TILES - 1);
+
+    // A single voxel face maps to the corners of its tile, as in `VoxelBaker`
+    for uv in mesh.get_uvs() {
+      assert!(uv[0] == start[0] || uv[0] == end[0]);
+      assert!(uv[1] == start[1] || uv[1] == end[1]);
+    }
+    assert!(mesh
+      .get_atlas_indices()
+      .iter()
+      .all(|index| *index == TILES - 1));
+  }
+
+  #[test]
+  fn ambient_occlusion_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 2, 4);
+
+    chunk.fill([0, 0, 0], [4, 1, 4], (1, 1));
+    chunk.set(0, 1, 0, (1, 1));
+
+    let mesh = bake(&chunk, &Default::default());
+    assert!(mesh.get_ambient_occlusion().is_empty());
+    let merged = mesh.get_indices().len();
+
+    let options = BakerOptions {
+      ambient_occlusion: true,
+      ..Default::default()
+    };
+    let mesh = bake(&chunk, &options);
+    let occlusion = mesh.get_ambient_occlusion();
+
+    assert_eq!(occlusion.len(), mesh.get_positions().len());
+    // The faces next to the block aren't merged with the rest of the top
+    assert!(mesh.get_indices().len() > merged);
+
+    let top_occlusion = |position: [f32; 3]| {
+      let index = mesh
+        .get_positions()
+        .iter()
+        .zip(mesh.get_normals().iter())
+        .position(|(vertex, normal)| *vertex == position && *normal == [0.0, 1.0, 0.0])
+        .unwrap();
+      occlusion[index]
+    };
+
+    assert_eq!(top_occlusion([1.0, 1.0, 1.0]), 2);
+    assert_eq!(top_occlusion([4.0, 1.0, 4.0]), 3);
+  }
+}
@@ -1,14 +1,17 @@
-use gaiku_common::{prelude::*, Result};
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  prelude::*,
+  Result,
+};
 
 /// Implementation of a naive heightmap terrain generation.
 pub struct HeightMapBaker;
 
-impl Baker for HeightMapBaker {
-  type Value = (u8, u8);
-
-  fn bake<C, T, M>(chunk: &C, _options: &BakerOptions<T>) -> Result<Option<M>>
+impl HeightMapBaker {
+  fn bake_chunk<V, C, T, M>(chunk: &C, _options: &BakerOptions<T>) -> Result<Option<M>>
   where
-    C: Chunkify<Self::Value> + Sizable,
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
     T: Texturify2d,
     M: Meshify,
   {
@@ -22,19 +25,31 @@ impl Baker for HeightMapBaker {
       [chunk.width() as f32, height as f32, chunk.height() as f32],
     );
 
-    for x in 0..chunk.width() as usize - 1 {
-      for y in 0..chunk.height() as usize - 1 {
+    let x_limit = chunk.width() as isize - 1;
+    let y_limit = chunk.height() as isize - 1;
+
+    for x in 0..chunk.width() as isize {
+      for y in 0..chunk.height() as isize {
         if chunk.is_air(x, y, 0) {
           continue;
         }
 
+        // The last row and column use the neighbor data, skip them if there is none
+        if (x == x_limit || y == y_limit)
+          && (chunk.is_air(x + 1, y, 0)
+            || chunk.is_air(x, y + 1, 0)
+            || chunk.is_air(x + 1, y + 1, 0))
+        {
+          continue;
+        }
+
         let fx = x as f32;
         let fz = y as f32;
 
-        let lb = (chunk.get(x, y, 0).0 as u32 * height) as f32 / 255.0;
-        let lf = (chunk.get(x, y + 1, 0).0 as u32 * height) as f32 / 255.0;
-        let rb = (chunk.get(x + 1, y, 0).0 as u32 * height) as f32 / 255.0;
-        let rf = (chunk.get(x + 1, y + 1, 0).0 as u32 * height) as f32 / 255.0;
+        let lb = chunk.get(x, y, 0).density() * height as f32;
+        let lf = chunk.get(x, y + 1, 0).density() * height as f32;
+        let rb = chunk.get(x + 1, y, 0).density() * height as f32;
+        let rf = chunk.get(x + 1, y + 1, 0).density() * height as f32;
 
         let left_back = [fx, lb, fz];
         let right_back = [fx + 1.0, rb, fz];
@@ -49,3 +64,21 @@ impl Baker for HeightMapBaker {
     Ok(builder.build::<M>())
   }
 }
+
+impl Baker for HeightMapBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::AverageDensity,
+      [Some(0), None, Some(1)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
@@ -1,18 +1,34 @@
-use gaiku_common::{mint::Vector3, prelude::*, Result};
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  mint::Vector3,
+  prelude::*,
+  texture::triplanar_uv,
+  Result,
+};
 use glam::Vec3;
 
 mod tables;
+mod tetrahedra;
+mod transition_tables;
+mod transvoxel;
+mod trilinear;
 
 use self::tables::{EDGE_TABLE, TRIANGLE_TABLE};
+pub use self::{
+  tetrahedra::MarchingTetrahedraBaker,
+  transvoxel::{TransitionFaces, TransvoxelBaker},
+};
 
 #[derive(Debug)]
 struct GridCell {
-  pub value: [u8; 8],
+  pub value: [f32; 8],
   pub point: [Vector3<f32>; 8],
+  pub gradient: [Vec3; 8],
 }
 
 impl GridCell {
-  fn lerp(&self, index1: usize, index2: usize, isolevel: u8) -> [f32; 3] {
+  /// Returns the point of the edge where the surface crosses and the normal at that point.
+  fn lerp(&self, index1: usize, index2: usize, isolevel: f32) -> ([f32; 3], [f32; 3]) {
     let mut index1 = index1;
     let mut index2 = index2;
 
@@ -20,40 +36,68 @@ impl GridCell {
       std::mem::swap(&mut index1, &mut index2);
     }
 
-    let isolevel = isolevel as f32 / 255.0;
-
-    let point1: Vec3 = self.point[index1].into();
-    let point2: Vec3 = self.point[index2].into();
-
-    if (point1 - point2).abs() > [0.0001, 0.0001, 0.0001].into() {
-      let value1: Vec3 = [
-        self.value[index1] as f32 / 255.0,
-        self.value[index1] as f32 / 255.0,
-        self.value[index1] as f32 / 255.0,
-      ]
-      .into();
-      let value2: Vec3 = [
-        self.value[index2] as f32 / 255.0,
-        self.value[index2] as f32 / 255.0,
-        self.value[index2] as f32 / 255.0,
-      ]
-      .into();
-      let value: Vec3 = [isolevel, isolevel, isolevel].into();
-
-      (point1 + (point2 - point1) / (value2 - value1) * (value - value1)).into()
-    } else {
-      self.point[index1].into()
-    }
+    interpolate(
+      (
+        self.point[index1].into(),
+        self.value[index1],
+        self.gradient[index1],
+      ),
+      (
+        self.point[index2].into(),
+        self.value[index2],
+        self.gradient[index2],
+      ),
+      isolevel,
+    )
   }
 }
 
+/// Returns the point of the edge from `point1` to `point2` where the surface crosses and the
+/// normal at that point, `point1` must be the lowest point so the cells sharing the edge get
+/// the same point.
+pub(crate) fn interpolate(
+  (point1, value1, gradient1): (Vec3, f32, Vec3),
+  (point2, value2, gradient2): (Vec3, f32, Vec3),
+  isolevel: f32,
+) -> ([f32; 3], [f32; 3]) {
+  let (point, gradient) = if (point1 - point2).abs().max_element() > 0.0001 {
+    let t = (isolevel - value1) / (value2 - value1);
+
+    (
+      point1 + (point2 - point1) * t,
+      gradient1 + (gradient2 - gradient1) * t,
+    )
+  } else {
+    (point1, gradient1)
+  };
+
+  // The density grows towards the inside of the surface
+  let normal = if gradient.length_squared() > 0.0 {
+    -gradient.normalize()
+  } else {
+    Vec3::zero()
+  };
+
+  (point.into(), normal.into())
+}
+
 /// Implementation of the marching cubes terrain generation.
 pub struct MarchingCubesBaker;
 
 impl MarchingCubesBaker {
-  fn polygonize(grid: &GridCell, isolevel: u8, triangles: &mut Vec<[[f32; 3]; 3]>) {
+  fn polygonize(
+    grid: &GridCell,
+    isolevel: f32,
+    topology: Topology,
+    triangles: &mut Vec<[([f32; 3], [f32; 3]); 3]>,
+  ) {
+    if topology == Topology::Trilinear {
+      trilinear::polygonize(grid, isolevel, triangles);
+      return;
+    }
+
     let mut cube_index = 0;
-    let mut vertex_list = [[0.0, 0.0, 0.0]; 12];
+    let mut vertex_list = [([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]); 12];
 
     if grid.value[0] < isolevel {
       cube_index |= 1;
@@ -139,23 +183,21 @@ impl MarchingCubesBaker {
         break;
       }
 
+      // Counter-clockwise seen from the outside of the surface, as the normals
       triangles.push([
-        vertex_list[TRIANGLE_TABLE[cube_index][i + 2] as usize],
-        vertex_list[TRIANGLE_TABLE[cube_index][i + 1] as usize],
         vertex_list[TRIANGLE_TABLE[cube_index][i] as usize],
+        vertex_list[TRIANGLE_TABLE[cube_index][i + 1] as usize],
+        vertex_list[TRIANGLE_TABLE[cube_index][i + 2] as usize],
       ]);
 
       i += 3;
     }
   }
-}
-
-impl Baker for MarchingCubesBaker {
-  type Value = (u8, u8);
 
-  fn bake<C, T, M>(chunk: &C, _options: &BakerOptions<T>) -> Result<Option<M>>
+  fn bake_chunk<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
   where
-    C: Chunkify<Self::Value> + Sizable,
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
     T: Texturify2d,
     M: Meshify,
   {
@@ -172,47 +214,586 @@ impl Baker for MarchingCubesBaker {
       ],
     );
 
-    // TODO: Solve issue where data of next chunk is needed to bake the chunk
-    for x in 0..chunk.width() as usize - 1 {
-      let fx = x as f32;
-      for y in 0..chunk.height() as usize - 1 {
-        let fy = y as f32;
-        for z in 0..chunk.depth() as usize - 1 {
-          let fz = z as f32;
-
-          let grid = GridCell {
-            value: [
-              chunk.get(x, y, z).1,
-              chunk.get(x + 1, y, z).1,
-              chunk.get(x + 1, y + 1, z).1,
-              chunk.get(x, y + 1, z).1,
-              chunk.get(x, y, z + 1).1,
-              chunk.get(x + 1, y, z + 1).1,
-              chunk.get(x + 1, y + 1, z + 1).1,
-              chunk.get(x, y + 1, z + 1).1,
-            ],
-            point: [
-              [fx + 0.0, fy + 0.0, fz + 0.0].into(),
-              [fx + 1.0, fy + 0.0, fz + 0.0].into(),
-              [fx + 1.0, fy + 1.0, fz + 0.0].into(),
-              [fx + 0.0, fy + 1.0, fz + 0.0].into(),
-              [fx + 0.0, fy + 0.0, fz + 1.0].into(),
-              [fx + 1.0, fy + 0.0, fz + 1.0].into(),
-              [fx + 1.0, fy + 1.0, fz + 1.0].into(),
-              [fx + 0.0, fy + 1.0, fz + 1.0].into(),
-            ],
-          };
-
-          let mut triangles = vec![];
-          Self::polygonize(&grid, 1, &mut triangles);
-
-          for vertex in triangles {
-            builder.add_triangle(vertex, None, None, 0);
-          }
+    // The last cell on each axis reads the neighbor data, so the cells between chunks
+    // are baked only once, by the chunk at its left, bottom or back.
+    for x in 0..chunk.width() as isize {
+      for y in 0..chunk.height() as isize {
+        for z in 0..chunk.depth() as isize {
+          Self::bake_cell(chunk, options, &mut builder, [x, y, z]);
         }
       }
     }
 
     Ok(builder.build::<M>())
   }
+
+  /// Bakes the cell between the voxel at `[x, y, z]` and the voxel at `[x + 1, y + 1, z + 1]`.
+  pub(crate) fn bake_cell<V, C, T>(
+    chunk: &C,
+    options: &BakerOptions<T>,
+    builder: &mut MeshBuilder,
+    [x, y, z]: [isize; 3],
+  ) where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+  {
+    let smooth = options.shading == Shading::Smooth;
+    let isosurface = &options.isosurface;
+    let (fx, fy, fz) = (x as f32, y as f32, z as f32);
+
+    let voxels = [
+      chunk.get(x, y, z),
+      chunk.get(x + 1, y, z),
+      chunk.get(x + 1, y + 1, z),
+      chunk.get(x, y + 1, z),
+      chunk.get(x, y, z + 1),
+      chunk.get(x + 1, y, z + 1),
+      chunk.get(x + 1, y + 1, z + 1),
+      chunk.get(x, y + 1, z + 1),
+    ];
+
+    let grid = GridCell {
+      value: voxels.map(|voxel| isosurface.sample(voxel)),
+      point: [
+        [fx + 0.0, fy + 0.0, fz + 0.0].into(),
+        [fx + 1.0, fy + 0.0, fz + 0.0].into(),
+        [fx + 1.0, fy + 1.0, fz + 0.0].into(),
+        [fx + 0.0, fy + 1.0, fz + 0.0].into(),
+        [fx + 0.0, fy + 0.0, fz + 1.0].into(),
+        [fx + 1.0, fy + 0.0, fz + 1.0].into(),
+        [fx + 1.0, fy + 1.0, fz + 1.0].into(),
+        [fx + 0.0, fy + 1.0, fz + 1.0].into(),
+      ],
+      gradient: if smooth {
+        [
+          gradient(chunk, isosurface, [x, y, z]),
+          gradient(chunk, isosurface, [x + 1, y, z]),
+          gradient(chunk, isosurface, [x + 1, y + 1, z]),
+          gradient(chunk, isosurface, [x, y + 1, z]),
+          gradient(chunk, isosurface, [x, y, z + 1]),
+          gradient(chunk, isosurface, [x + 1, y, z + 1]),
+          gradient(chunk, isosurface, [x + 1, y + 1, z + 1]),
+          gradient(chunk, isosurface, [x, y + 1, z + 1]),
+        ]
+      } else {
+        [Vec3::zero(); 8]
+      },
+    };
+
+    let mut triangles = vec![];
+    Self::polygonize(&grid, 0.0, options.topology, &mut triangles);
+
+    if triangles.is_empty() {
+      return;
+    }
+
+    let material = dominant_material(&voxels, &grid.value);
+
+    for triangle in triangles {
+      add_triangle(
+        builder,
+        options,
+        triangle,
+        material,
+        [fx, fy, fz],
+        [1.0, 1.0, 1.0],
+      );
+    }
+  }
+}
+
+impl Baker for MarchingCubesBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Subsample,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
+
+/// Central difference gradient of the signed density at the voxel `position`, the chunk only
+/// knows one voxel around it so a one sided difference is used past that border.
+pub(crate) fn gradient<V, C>(chunk: &C, isosurface: &Isosurface, position: [isize; 3]) -> Vec3
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+{
+  let size = [
+    chunk.width() as isize,
+    chunk.height() as isize,
+    chunk.depth() as isize,
+  ];
+  let mut gradient = [0.0; 3];
+
+  for axis in 0..3 {
+    let mut low = position;
+    low[axis] = (low[axis] - 1).max(-1);
+    let mut high = position;
+    high[axis] = (high[axis] + 1).min(size[axis]);
+
+    if high[axis] > low[axis] {
+      gradient[axis] = (isosurface.sample(chunk.get(high[0], high[1], high[2]))
+        - isosurface.sample(chunk.get(low[0], low[1], low[2])))
+        / (high[axis] - low[axis]) as f32;
+    }
+  }
+
+  gradient.into()
+}
+
+/// Adds the triangle of the cell starting at `cell` with the texture of `material` and the
+/// normals of `options.shading`, the vertices are given with its smooth normal.
+pub(crate) fn add_triangle<T>(
+  builder: &mut MeshBuilder,
+  options: &BakerOptions<T>,
+  triangle: [([f32; 3], [f32; 3]); 3],
+  material: u16,
+  cell: [f32; 3],
+  cell_size: [f32; 3],
+) where
+  T: Texturify2d,
+{
+  let [(a, a_normal), (b, b_normal), (c, c_normal)] = triangle;
+  let face_normal = face_normal([a, b, c]);
+  let uv = options.texture.as_ref().map(|texture| {
+    let tile = texture.get_uv(material);
+    [a, b, c].map(|position| triplanar_uv(tile, face_normal, position, cell, cell_size))
+  });
+
+  if options.shading == Shading::Smooth {
+    let vertices = [(a, a_normal), (b, b_normal), (c, c_normal)];
+
+    for (index, (position, normal)) in vertices.iter().enumerate() {
+      // Flat areas of the density have no gradient
+      let normal = if *normal == [0.0, 0.0, 0.0] {
+        face_normal
+      } else {
+        *normal
+      };
+
+      builder.add(*position, Some(normal), uv.map(|uv| uv[index]), material);
+    }
+  } else {
+    builder.add_triangle([a, b, c], Some(face_normal), uv, material);
+  }
+}
+
+/// Most common material of the corners inside the surface, the lowest material wins the ties.
+pub(crate) fn dominant_material<V>(voxels: &[V], values: &[f32]) -> u16
+where
+  V: Voxel,
+{
+  let mut counts: Vec<(u16, usize)> = vec![];
+
+  for (voxel, value) in voxels.iter().zip(values.iter()) {
+    if *value < 0.0 {
+      continue;
+    }
+
+    let material = voxel.material();
+    match counts.iter_mut().find(|(other, _)| *other == material) {
+      Some((_, count)) => *count += 1,
+      None => counts.push((material, 1)),
+    }
+  }
+
+  counts
+    .iter()
+    .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+    .map(|(material, _)| *material)
+    .unwrap_or(0)
+}
+
+/// Normal of the counter-clockwise triangle, zero if the triangle has no area.
+fn face_normal([a, b, c]: [[f32; 3]; 3]) -> [f32; 3] {
+  let (a, b, c): (Vec3, Vec3, Vec3) = (a.into(), b.into(), c.into());
+  let normal = (b - a).cross(c - a);
+
+  if normal.length_squared() > 0.0 {
+    normal.normalize().into()
+  } else {
+    Vec3::zero().into()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{
+    chunk::Chunk,
+    mesh::Mesh,
+    texture::{Texture2d, TextureAtlas2d},
+  };
+  use std::collections::HashMap;
+
+  fn sphere() -> Chunk {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 10, 10, 10);
+
+    for x in 0..10 {
+      for y in 0..10 {
+        for z in 0..10 {
+          let distance =
+            ((x as f32 - 5.0).powi(2) + (y as f32 - 5.0).powi(2) + (z as f32 - 5.0).powi(2)).sqrt();
+          let density = ((4.0 - distance) * 60.0).clamp(0.0, 255.0);
+
+          chunk.set(x, y, z, (1, density as u8));
+        }
+      }
+    }
+
+    chunk
+  }
+
+  fn bake(shading: Shading) -> Mesh {
+    let options = BakerOptions::<Texture2d> {
+      shading,
+      ..Default::default()
+    };
+
+    MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&sphere(), &options)
+      .unwrap()
+      .unwrap()
+  }
+
+  fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+  }
+
+  #[test]
+  fn interpolate_test() {
+    let grid = GridCell {
+      value: [0.75, -0.25, 0.0, -0.25, -0.25, 0.0, 0.0, 0.0],
+      point: [
+        [0.0, 0.0, 0.0].into(),
+        [1.0, 0.0, 0.0].into(),
+        [1.0, 1.0, 0.0].into(),
+        [0.0, 1.0, 0.0].into(),
+        [0.0, 0.0, 1.0].into(),
+        [1.0, 0.0, 1.0].into(),
+        [1.0, 1.0, 1.0].into(),
+        [0.0, 1.0, 1.0].into(),
+      ],
+      gradient: [Vec3::zero(); 8],
+    };
+
+    // Every axis gets the crossing between its points, not only the `x` axis
+    assert_eq!(grid.lerp(0, 1, 0.0).0, [0.75, 0.0, 0.0]);
+    assert_eq!(grid.lerp(0, 3, 0.0).0, [0.0, 0.75, 0.0]);
+    assert_eq!(grid.lerp(0, 4, 0.0).0, [0.0, 0.0, 0.75]);
+  }
+
+  #[test]
+  fn level_of_detail_test() {
+    // A density ramp along `y` crossing the isolevel at `y = 4.3`, the width isn't a multiple
+    // of the downsampled blocks
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 9, 12, 8);
+    for x in 0..9 {
+      for y in 0..12 {
+        for z in 0..8 {
+          let density = (0.5 + (4.3 - y as f32) * 0.1).max(0.0) * 255.0;
+          chunk.set(x, y, z, (1, density.round() as u8));
+        }
+      }
+    }
+
+    let bake = |level_of_detail: usize| {
+      let options = BakerOptions::<Texture2d> {
+        level_of_detail,
+        isosurface: Isosurface {
+          isolevel: 0.5,
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+
+      MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+        .unwrap()
+        .unwrap()
+    };
+    let max = |mesh: &Mesh| {
+      mesh
+        .get_positions()
+        .iter()
+        .fold(0.0f32, |max, position| max.max(position[0]))
+    };
+    let full = max(&bake(1));
+
+    for level_of_detail in 2..=3 {
+      let mesh = bake(level_of_detail);
+
+      // The surface stays at the same height, away from the walls that close it at the
+      // borders without neighbor data, and the walls stay at the chunk border
+      for position in mesh
+        .get_positions()
+        .iter()
+        .filter(|[x, y, z]| *x >= 0.0 && *x <= 4.0 && *y > 0.0 && *z >= 0.0 && *z <= 4.0)
+      {
+        assert!((position[1] - 4.3).abs() < 0.05, "{:?}", position);
+      }
+
+      assert!((max(&mesh) - full).abs() < 1e-4);
+    }
+  }
+
+  #[test]
+  fn smooth_normals_test() {
+    let mesh = bake(Shading::Smooth);
+    let positions = mesh.get_positions();
+    let normals = mesh.get_normals();
+
+    assert_eq!(normals.len(), positions.len());
+
+    for (position, normal) in positions.iter().zip(normals.iter()) {
+      let outwards = [position[0] - 5.0, position[1] - 5.0, position[2] - 5.0];
+      let length = dot(outwards, outwards).sqrt();
+
+      assert!((dot(*normal, *normal) - 1.0).abs() < 1e-4);
+      assert!(dot(*normal, outwards) / length > 0.8);
+    }
+
+    // The triangles winding agrees with the normals
+    for triangle in mesh.get_indices().chunks(3) {
+      let face = face_normal([
+        positions[triangle[0] as usize],
+        positions[triangle[1] as usize],
+        positions[triangle[2] as usize],
+      ]);
+
+      // The slivers on the lattice planes are perpendicular to the normals, but never opposite
+      assert!(dot(face, normals[triangle[0] as usize]) >= 0.0);
+    }
+  }
+
+  #[test]
+  fn flat_normals_test() {
+    let smooth = bake(Shading::Smooth);
+    let mesh = bake(Shading::Flat);
+    let positions = mesh.get_positions();
+    let normals = mesh.get_normals();
+
+    assert_eq!(mesh.get_indices().len(), smooth.get_indices().len());
+    assert!(positions.len() > smooth.get_positions().len());
+
+    for triangle in mesh.get_indices().chunks(3) {
+      let face = face_normal([
+        positions[triangle[0] as usize],
+        positions[triangle[1] as usize],
+        positions[triangle[2] as usize],
+      ]);
+
+      if face != [0.0, 0.0, 0.0] {
+        for index in triangle {
+          assert!(dot(normals[*index as usize], face) > 0.9999);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn isosurface_test() {
+    // Grayscale ramp along x stored as material, without density
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 8, 4, 4);
+    for x in 0..8 {
+      for y in 0..4 {
+        for z in 0..4 {
+          chunk.set(x, y, z, (x as u8 * 32 + 16, 0));
+        }
+      }
+    }
+
+    let mut options = BakerOptions::<Texture2d>::default();
+    let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options).unwrap();
+    assert!(mesh.is_none());
+
+    options.isosurface = Isosurface {
+      isolevel: 128.0 / 255.0,
+      channel: DensityChannel::Material,
+      inside: Inside::Above,
+    };
+    let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let positions = mesh.get_positions();
+
+    // The values are 112 at x = 3 and 144 at x = 4
+    assert!(positions.iter().all(|position| position[0] >= 3.5));
+    assert!(positions.iter().any(|position| position[0] == 3.5));
+
+    options.isosurface.inside = Inside::Below;
+    let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let positions = mesh.get_positions();
+
+    // Now the empty border around the chunk is inside too
+    assert!(positions
+      .iter()
+      .all(|position| position[0] <= 3.0 || position[0] >= 4.0 || position[0] == 3.5));
+    assert!(positions
+      .iter()
+      .zip(mesh.get_normals().iter())
+      .any(|(position, normal)| position[0] == 3.5 && normal[0] > 0.9));
+  }
+
+  /// Directed edges of the triangles of `chunks` baked with `topology`, moved to their chunk
+  /// position, with the number of triangles that have each of them.
+  fn edges(chunks: &[&Chunk], topology: Topology) -> HashMap<[[i32; 3]; 2], usize> {
+    let options = BakerOptions::<Texture2d> {
+      topology,
+      isosurface: Isosurface {
+        isolevel: 0.5,
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let mut edges = HashMap::new();
+
+    for chunk in chunks {
+      let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(*chunk, &options)
+        .unwrap()
+        .unwrap();
+      let positions = mesh.get_positions();
+      let offset = chunk.position();
+
+      for triangle in mesh.get_indices().chunks(3) {
+        let points = [0, 1, 2].map(|index| {
+          let position = positions[triangle[index] as usize];
+          [0, 1, 2].map(|axis| ((position[axis] + offset[axis]) * 1000.0).round() as i32)
+        });
+
+        for index in 0..3 {
+          *edges
+            .entry([points[index], points[(index + 1) % 3]])
+            .or_insert(0) += 1;
+        }
+      }
+    }
+
+    edges
+  }
+
+  /// Each edge is crossed once in each direction by the triangles at both of its sides.
+  fn is_closed(edges: &HashMap<[[i32; 3]; 2], usize>) -> bool {
+    !edges.is_empty()
+      && edges
+        .iter()
+        .all(|(&[a, b], count)| *count == 1 && edges.get(&[b, a]) == Some(&1))
+  }
+
+  /// Number of pieces of the surface made by the `edges` of its triangles.
+  fn pieces(edges: &HashMap<[[i32; 3]; 2], usize>) -> usize {
+    let mut groups: HashMap<[i32; 3], [i32; 3]> = HashMap::new();
+    let find = |groups: &HashMap<[i32; 3], [i32; 3]>, mut point: [i32; 3]| {
+      while let Some(parent) = groups.get(&point).filter(|parent| **parent != point) {
+        point = *parent;
+      }
+      point
+    };
+
+    for [a, b] in edges.keys() {
+      groups.entry(*a).or_insert(*a);
+      groups.entry(*b).or_insert(*b);
+
+      let (a, b) = (find(&groups, *a), find(&groups, *b));
+      groups.insert(a, b);
+    }
+
+    groups
+      .iter()
+      .filter(|(point, parent)| point == parent)
+      .count()
+  }
+
+  #[test]
+  fn topology_test() {
+    // Two voxels facing each other on a face, the cases table always joins them
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 6, 6, 6);
+    chunk.set(2, 2, 2, (1, 140));
+    chunk.set(3, 3, 2, (1, 140));
+
+    let classic = edges(&[&chunk], Topology::Classic);
+    assert!(is_closed(&classic));
+    assert_eq!(pieces(&classic), 1);
+
+    // The saddle of the face is outside, they are apart
+    let trilinear = edges(&[&chunk], Topology::Trilinear);
+    assert!(is_closed(&trilinear));
+    assert_eq!(pieces(&trilinear), 2);
+
+    // With the other corners of the face barely outside the saddle is inside
+    chunk.set(3, 2, 2, (1, 120));
+    chunk.set(2, 3, 2, (1, 120));
+
+    let trilinear = edges(&[&chunk], Topology::Trilinear);
+    assert!(is_closed(&trilinear));
+    assert_eq!(pieces(&trilinear), 1);
+
+    // A checkerboard of solid voxels across two chunks, every face of its cells is ambiguous
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 6, 6, 6);
+    let mut neighbor = Chunk::new([6.0, 0.0, 0.0], 6, 6, 6);
+
+    for x in 1..11 {
+      for y in 1..5 {
+        for z in 1..5 {
+          if (x + y + z) % 2 == 0 {
+            let target = if x < 6 { &mut chunk } else { &mut neighbor };
+            target.set(x % 6, y, z, (1, 255));
+          }
+        }
+      }
+    }
+
+    chunk.update_neighbor_data(&neighbor);
+    neighbor.update_neighbor_data(&chunk);
+
+    assert!(is_closed(&edges(&[&chunk, &neighbor], Topology::Trilinear)));
+  }
+
+  #[test]
+  fn materials_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 6, 4, 4);
+    chunk.fill([1, 1, 1], [3, 3, 3], (1, 255));
+    chunk.fill([3, 1, 1], [5, 3, 3], (2, 255));
+
+    let options = BakerOptions::<Texture2d>::default();
+    let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let atlas_indices = mesh.get_atlas_indices();
+
+    assert!(mesh.get_uvs().is_empty());
+    assert!(atlas_indices.contains(&1));
+    assert!(atlas_indices.contains(&2));
+    assert!(atlas_indices.iter().all(|index| *index == 1 || *index == 2));
+
+    let texture = TextureAtlas2d::<Texture2d>::new(16);
+    let options = BakerOptions {
+      texture: Some(texture.clone()),
+      ..Default::default()
+    };
+    let mesh = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let uvs = mesh.get_uvs();
+
+    assert_eq!(uvs.len(), mesh.get_positions().len());
+
+    // Every uv is inside the tile of its material
+    for (uv, atlas_index) in uvs.iter().zip(mesh.get_atlas_indices().iter()) {
+      let (start, _, end, _) = texture.get_uv(*atlas_index);
+
+      assert!(start[0] <= uv[0] && uv[0] <= end[0]);
+      assert!(start[1] <= uv[1] && uv[1] <= end[1]);
+    }
+  }
 }
@@ -0,0 +1,318 @@
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  prelude::*,
+  Result,
+};
+use glam::Vec3;
+
+use crate::{add_triangle, dominant_material, gradient, interpolate};
+
+/// Corners of a cell in the order of `MarchingCubesBaker`.
+const CORNERS: [[isize; 3]; 8] = [
+  [0, 0, 0],
+  [1, 0, 0],
+  [1, 1, 0],
+  [0, 1, 0],
+  [0, 0, 1],
+  [1, 0, 1],
+  [1, 1, 1],
+  [0, 1, 1],
+];
+
+/// Tetrahedra of a cell as corners, all of them share the diagonal from the corner 0 to the
+/// corner 6 so every cell splits its faces through the same diagonals as its neighbors.
+const TETRAHEDRA: [[usize; 4]; 6] = [
+  [0, 6, 1, 2],
+  [0, 6, 2, 3],
+  [0, 6, 3, 7],
+  [0, 6, 7, 4],
+  [0, 6, 4, 5],
+  [0, 6, 5, 1],
+];
+
+/// Marching tetrahedra terrain generation, it splits each cell in six tetrahedra and crosses
+/// each of them with one triangle or a quad.
+///
+/// A tetrahedron has only one way to split its corners, so unlike marching cubes there are no
+/// ambiguous cases and the surface is always closed, at the cost of about twice the triangles.
+/// The isosurface, materials, normals and uvs are handled as `MarchingCubesBaker` does.
+pub struct MarchingTetrahedraBaker;
+
+impl MarchingTetrahedraBaker {
+  fn bake_chunk<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    let mut builder = MeshBuilder::create(
+      [
+        chunk.width() as f32 / 2.0,
+        chunk.height() as f32 / 2.0,
+        chunk.depth() as f32 / 2.0,
+      ],
+      [
+        chunk.width() as f32,
+        chunk.height() as f32,
+        chunk.depth() as f32,
+      ],
+    );
+
+    for x in 0..chunk.width() as isize {
+      for y in 0..chunk.height() as isize {
+        for z in 0..chunk.depth() as isize {
+          Self::bake_cell(chunk, options, &mut builder, [x, y, z]);
+        }
+      }
+    }
+
+    Ok(builder.build::<M>())
+  }
+
+  /// Bakes the tetrahedra of the cell between the voxel at `[x, y, z]` and the voxel at
+  /// `[x + 1, y + 1, z + 1]`.
+  fn bake_cell<V, C, T>(
+    chunk: &C,
+    options: &BakerOptions<T>,
+    builder: &mut MeshBuilder,
+    [x, y, z]: [isize; 3],
+  ) where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+  {
+    let smooth = options.shading == Shading::Smooth;
+    let isosurface = &options.isosurface;
+    let positions = CORNERS.map(|[cx, cy, cz]| [x + cx, y + cy, z + cz]);
+    let voxels = positions.map(|[x, y, z]| chunk.get(x, y, z));
+    let values = voxels.map(|voxel| isosurface.sample(voxel));
+
+    if values.iter().all(|value| *value >= 0.0) || values.iter().all(|value| *value < 0.0) {
+      return;
+    }
+
+    let gradients = positions.map(|position| {
+      if smooth {
+        gradient(chunk, isosurface, position)
+      } else {
+        Vec3::zero()
+      }
+    });
+    let points = positions.map(|[x, y, z]| Vec3::new(x as f32, y as f32, z as f32));
+
+    for tetrahedron in TETRAHEDRA.iter() {
+      let mut triangles = vec![];
+      polygonize(
+        tetrahedron.map(|corner| (points[corner], values[corner], gradients[corner])),
+        &mut triangles,
+      );
+
+      if triangles.is_empty() {
+        continue;
+      }
+
+      let material = dominant_material(
+        &tetrahedron.map(|corner| voxels[corner]),
+        &tetrahedron.map(|corner| values[corner]),
+      );
+
+      for triangle in triangles {
+        add_triangle(
+          builder,
+          options,
+          triangle,
+          material,
+          [x as f32, y as f32, z as f32],
+          [1.0, 1.0, 1.0],
+        );
+      }
+    }
+  }
+}
+
+impl Baker for MarchingTetrahedraBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Subsample,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
+
+/// Adds the triangles where the surface crosses the tetrahedron of `corners` (point, signed
+/// value and gradient), counter-clockwise seen from the outside of the surface.
+fn polygonize(corners: [(Vec3, f32, Vec3); 4], triangles: &mut Vec<[([f32; 3], [f32; 3]); 3]>) {
+  let (inside, outside): (Vec<usize>, Vec<usize>) =
+    (0..4).partition(|index| corners[*index].1 >= 0.0);
+
+  let crossing = |a: usize, b: usize| {
+    let (a, b) = (corners[a], corners[b]);
+
+    // The lowest point first, so the tetrahedra sharing the edge get the same point
+    if <[f32; 3]>::from(b.0) < a.0.into() {
+      interpolate(b, a, 0.0)
+    } else {
+      interpolate(a, b, 0.0)
+    }
+  };
+
+  let polygon = match (inside.len(), outside.len()) {
+    (1, 3) => outside.iter().map(|b| crossing(inside[0], *b)).collect(),
+    (3, 1) => inside.iter().map(|a| crossing(*a, outside[0])).collect(),
+    (2, 2) => vec![
+      crossing(inside[0], outside[0]),
+      crossing(inside[0], outside[1]),
+      crossing(inside[1], outside[1]),
+      crossing(inside[1], outside[0]),
+    ],
+    _ => vec![],
+  };
+
+  if polygon.is_empty() {
+    return;
+  }
+
+  let center = |indices: &[usize]| {
+    indices
+      .iter()
+      .fold(Vec3::zero(), |sum, index| sum + corners[*index].0)
+      / indices.len() as f32
+  };
+  let outwards = center(&outside) - center(&inside);
+
+  for index in 1..polygon.len() - 1 {
+    let mut triangle = [polygon[0], polygon[index], polygon[index + 1]];
+    let [a, b, c] = triangle.map(|(position, _)| Vec3::from(position));
+
+    if (b - a).cross(c - a).dot(outwards) < 0.0 {
+      triangle.swap(1, 2);
+    }
+
+    triangles.push(triangle);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{chunk::Chunk, mesh::Mesh, texture::Texture2d};
+  use std::collections::HashMap;
+
+  fn bake(chunk: &Chunk, options: &BakerOptions<Texture2d>) -> Mesh {
+    MarchingTetrahedraBaker::bake::<_, Chunk, Texture2d, Mesh>(chunk, options)
+      .unwrap()
+      .unwrap()
+  }
+
+  /// Directed edges of the triangles of `mesh` moved by `offset` and rounded, with the number
+  /// of triangles that have each of them.
+  fn edges(mesh: &Mesh, offset: f32, edges: &mut HashMap<[[i32; 3]; 2], usize>) {
+    let positions = mesh.get_positions();
+
+    for triangle in mesh.get_indices().chunks(3) {
+      let points = [0, 1, 2].map(|index| {
+        let [x, y, z] = positions[triangle[index] as usize];
+        [x + offset, y, z].map(|value| (value * 1000.0).round() as i32)
+      });
+
+      for index in 0..3 {
+        *edges
+          .entry([points[index], points[(index + 1) % 3]])
+          .or_insert(0) += 1;
+      }
+    }
+  }
+
+  #[test]
+  fn ambiguous_test() {
+    // A checkerboard of solid voxels across two chunks, every face of its cells is ambiguous
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 6, 6, 6);
+    let mut neighbor = Chunk::new([6.0, 0.0, 0.0], 6, 6, 6);
+
+    for x in 1..11 {
+      for y in 1..5 {
+        for z in 1..5 {
+          if (x + y + z) % 2 == 0 {
+            let target = if x < 6 { &mut chunk } else { &mut neighbor };
+            target.set(x % 6, y, z, (1, 255));
+          }
+        }
+      }
+    }
+
+    chunk.update_neighbor_data(&neighbor);
+    neighbor.update_neighbor_data(&chunk);
+
+    let options = BakerOptions::<Texture2d>::default();
+    let mut all = HashMap::new();
+    edges(&bake(&chunk, &options), 0.0, &mut all);
+    edges(&bake(&neighbor, &options), 6.0, &mut all);
+
+    // The surface is closed and consistently wound, each edge is crossed once in each
+    // direction by the triangles at both of its sides
+    assert!(!all.is_empty());
+    for (&[a, b], count) in all.iter() {
+      assert_eq!(*count, 1);
+      assert_eq!(all.get(&[b, a]), Some(&1));
+    }
+  }
+
+  #[test]
+  fn sphere_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 10, 10, 10);
+
+    for x in 0..10 {
+      for y in 0..10 {
+        for z in 0..10 {
+          let distance = (Vec3::new(x as f32, y as f32, z as f32) - Vec3::splat(5.0)).length();
+          let density = ((4.0 - distance) * 60.0).clamp(0.0, 255.0);
+
+          chunk.set(x, y, z, (1, density as u8));
+        }
+      }
+    }
+
+    let mesh = bake(&chunk, &BakerOptions::default());
+    let positions = mesh.get_positions();
+    let normals = mesh.get_normals();
+
+    for (position, normal) in positions.iter().zip(normals.iter()) {
+      let outwards = (Vec3::from(*position) - Vec3::splat(5.0)).normalize();
+
+      assert!(Vec3::from(*normal).dot(outwards) > 0.8);
+    }
+
+    // The triangles face outwards
+    for triangle in mesh.get_indices().chunks(3) {
+      let [a, b, c] = [0, 1, 2].map(|index| Vec3::from(positions[triangle[index] as usize]));
+
+      assert!(
+        (b - a)
+          .cross(c - a)
+          .dot((a + b + c) / 3.0 - Vec3::splat(5.0))
+          > 0.0
+      );
+    }
+
+    // The downsampled mesh is scaled back to the chunk size
+    let options = BakerOptions::<Texture2d> {
+      level_of_detail: 2,
+      ..Default::default()
+    };
+    let max = bake(&chunk, &options)
+      .get_positions()
+      .iter()
+      .fold(0.0f32, |max, position| max.max(position[0]));
+    assert!(max > 6.0 && max <= 10.0);
+  }
+}
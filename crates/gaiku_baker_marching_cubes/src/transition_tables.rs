@@ -0,0 +1,1312 @@
+/// Class of each transition cell case, the case bits are the inside samples of the full
+/// resolution face (`i + j * 3`). The high bit is set when the class triangles must be reversed.
+pub const TRANSITION_CELL_CLASS: [u8; 512] = [
+  0x00, 0x01, 0x02, 0x03, 0x01, 0x04, 0x83, 0x05, 0x02, 0x83, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b,
+  0x0c, 0x0d, 0x0e, 0x0f, 0x0d, 0x10, 0x8f, 0x11, 0x0e, 0x8f, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+  0x02, 0x88, 0x06, 0x8a, 0x03, 0x89, 0x07, 0x8b, 0x18, 0x19, 0x1a, 0x1b, 0x99, 0x1c, 0x9b, 0x1d,
+  0x0e, 0x94, 0x12, 0x96, 0x0f, 0x95, 0x13, 0x97, 0x1e, 0x1f, 0x20, 0x21, 0x9f, 0x22, 0xa1, 0x23,
+  0x01, 0x04, 0x88, 0x89, 0x24, 0x25, 0x26, 0x27, 0x03, 0x05, 0x8a, 0x8b, 0xa6, 0xa7, 0x28, 0x29,
+  0x0d, 0x10, 0x94, 0x95, 0x2a, 0x2b, 0x2c, 0x2d, 0x0f, 0x11, 0x96, 0x97, 0xac, 0xad, 0x2e, 0x2f,
+  0x08, 0x30, 0x31, 0x32, 0xa6, 0x33, 0x34, 0x35, 0x99, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c,
+  0x14, 0x3d, 0x3e, 0x3f, 0xac, 0x40, 0x41, 0x42, 0x9f, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+  0x02, 0x08, 0x18, 0x99, 0x88, 0x30, 0x19, 0x36, 0x06, 0x0a, 0x1a, 0x9b, 0x31, 0xb2, 0xb7, 0xb8,
+  0x0e, 0x14, 0x1e, 0x9f, 0x94, 0x3d, 0x1f, 0x43, 0x12, 0x16, 0x20, 0xa1, 0x3e, 0xbf, 0xc4, 0xc5,
+  0x06, 0x31, 0x1a, 0x37, 0x8a, 0x32, 0x1b, 0x38, 0x1a, 0xb7, 0x4a, 0x4b, 0x37, 0x4c, 0x4b, 0x4d,
+  0x12, 0x3e, 0x20, 0x44, 0x96, 0x3f, 0x21, 0x45, 0x20, 0xc4, 0x4e, 0x4f, 0x44, 0x50, 0x4f, 0x51,
+  0x83, 0x09, 0x19, 0x1c, 0x26, 0xb3, 0xb9, 0xba, 0x07, 0x0b, 0x1b, 0x1d, 0x34, 0xb5, 0xbb, 0xbc,
+  0x8f, 0x15, 0x1f, 0x22, 0x2c, 0xc0, 0xc6, 0xc7, 0x13, 0x17, 0x21, 0x23, 0x41, 0xc2, 0xc8, 0xc9,
+  0x0a, 0xb2, 0xb7, 0x4c, 0x28, 0x52, 0xbb, 0x53, 0x9b, 0xb8, 0x4b, 0x4d, 0x3b, 0xd3, 0x54, 0x55,
+  0x16, 0xbf, 0xc4, 0x50, 0x2e, 0x56, 0xc8, 0x57, 0xa1, 0xc5, 0x4f, 0x51, 0x48, 0xd7, 0x58, 0x59,
+  0x01, 0x24, 0x08, 0xa6, 0x04, 0x25, 0x09, 0xa7, 0x88, 0x26, 0x31, 0x34, 0x30, 0xb3, 0xb2, 0xb5,
+  0x0d, 0x2a, 0x14, 0xac, 0x10, 0x2b, 0x15, 0xad, 0x94, 0x2c, 0x3e, 0x41, 0x3d, 0xc0, 0xbf, 0xc2,
+  0x83, 0x26, 0x0a, 0x28, 0x05, 0x27, 0x0b, 0x29, 0x19, 0xb9, 0xb7, 0xbb, 0x36, 0xba, 0xb8, 0xbc,
+  0x8f, 0x2c, 0x16, 0x2e, 0x11, 0x2d, 0x17, 0x2f, 0x1f, 0xc6, 0xc4, 0xc8, 0x43, 0xc7, 0xc5, 0xc9,
+  0x04, 0x25, 0x30, 0x33, 0x25, 0x5a, 0xb3, 0x5b, 0x89, 0x27, 0x32, 0x35, 0x33, 0x5b, 0x52, 0x5c,
+  0x10, 0x2b, 0x3d, 0x40, 0x2b, 0x5d, 0xc0, 0x5e, 0x95, 0x2d, 0x3f, 0x42, 0x40, 0x5e, 0x56, 0x5f,
+  0x09, 0xb3, 0xb2, 0x52, 0xa7, 0x5b, 0xb5, 0x5c, 0x1c, 0xba, 0x4c, 0x53, 0x3a, 0x60, 0xd3, 0x61,
+  0x15, 0xc0, 0xbf, 0x56, 0xad, 0x5e, 0xc2, 0x5f, 0x22, 0xc7, 0x50, 0x57, 0x47, 0x62, 0xd7, 0x63,
+  0x03, 0xa6, 0x99, 0x39, 0x89, 0x33, 0x1c, 0x3a, 0x8a, 0x28, 0x37, 0x3b, 0x32, 0x52, 0x4c, 0xd3,
+  0x0f, 0xac, 0x9f, 0x46, 0x95, 0x40, 0x22, 0x47, 0x96, 0x2e, 0x44, 0x48, 0x3f, 0x56, 0x50, 0xd7,
+  0x07, 0x34, 0x9b, 0x3b, 0x8b, 0x35, 0x1d, 0x3c, 0x1b, 0xbb, 0x4b, 0x54, 0x38, 0x53, 0x4d, 0x55,
+  0x13, 0x41, 0xa1, 0x48, 0x97, 0x42, 0x23, 0x49, 0x21, 0xc8, 0x4f, 0x58, 0x45, 0x57, 0x51, 0x59,
+  0x05, 0xa7, 0x36, 0x3a, 0x27, 0x5b, 0xba, 0x60, 0x8b, 0x29, 0x38, 0x3c, 0x35, 0x5c, 0x53, 0x61,
+  0x11, 0xad, 0x43, 0x47, 0x2d, 0x5e, 0xc7, 0x62, 0x97, 0x2f, 0x45, 0x49, 0x42, 0x5f, 0x57, 0x63,
+  0x0b, 0xb5, 0xb8, 0xd3, 0x29, 0x5c, 0xbc, 0x61, 0x1d, 0xbc, 0x4d, 0x55, 0x3c, 0x61, 0x55, 0x64,
+  0x17, 0xc2, 0xc5, 0xd7, 0x2f, 0x5f, 0xc9, 0x63, 0x23, 0xc9, 0x51, 0x59, 0x49, 0x63, 0x59, 0x65,
+];
+
+/// Triangles of each class, as indices into the vertices of the case, `-1` terminated.
+pub const TRANSITION_CELL_DATA: [[i8; 27]; 102] = [
+  [
+    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 2, 5, 0, 1, 2, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 6, 1, 5, 6, 1, 4, 5, 1, 3, 4, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 4, 5, 0, 1, 4, 1, 3, 4, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 4, 6, 2, 3, 4, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 2, 5, 0, 1, 2, 2, 3, 5, 3, 4, 5, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 4, 6, 2, 3, 4, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 4, 5, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 8, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 7, 8, 2, 6, 7, 2, 5, 6, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 4, 7, 0, 1, 4, 1, 2, 4, 2, 3, 4, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 4, 5, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 6, 7, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 6, 7, 1, 4, 6, 1, 2, 4, 2, 3, 4, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 3, 6, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 3, 6, 0, 1, 3, 1, 2, 3, 3, 5, 6, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 5, 7, 0, 1, 5, 1, 4, 5, 1, 2, 4, 2, 3, 4, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 8, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 3, 6, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 9, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 4, 5, 0, 1, 4, 1, 3, 4, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, 4, 5, 7, 5, 6, 7, 8, 9, 11, 9, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 5, 7, 0, 1, 5, 1, 4, 5, 1, 2, 4, 2, 3, 4, 5, 6, 7, 8, 9, 11, 9, 10, 11, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 4, 6, 1, 2, 4, 2, 3, 4, 4, 5, 6, 7, 8, 10, 8, 9, 10, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 7, 8, 2, 3, 7, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 4, 9, 0, 1, 4, 1, 2, 4, 2, 3, 4, 4, 5, 9, 5, 7, 9, 5, 6, 7, 7, 8, 9, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 4, 5, 2, 3, 4, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 3, 6, 3, 4, 6, 4, 5, 6, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 3, 8, 3, 7, 8, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 6, 7, 2, 3, 6, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 7, 4, 5, 7, 5, 6, 7, 8, 9, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 4, 5, 1, 2, 4, 2, 3, 4, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, 5, 6, 9, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 6, 7, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 10, 7, 8, 10, 8, 9, 10, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 4, 5, 2, 3, 4, 6, 7, 10, 7, 8, 10, 8, 9, 10, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 9, 1, 2, 9, 2, 8, 9, 2, 3, 8, 3, 4, 8, 4, 5, 8, 5, 6, 8, 6, 7, 8, -1, -1, -1,
+  ],
+  [
+    0, 1, 10, 1, 2, 10, 2, 8, 10, 2, 3, 8, 3, 7, 8, 3, 4, 7, 4, 5, 7, 5, 6, 7, 8, 9, 10,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 4, 5, 2, 3, 4, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 6, 7, 2, 3, 6, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 4, 7, 1, 2, 4, 2, 3, 4, 4, 6, 7, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 3, 8, 3, 4, 8, 4, 6, 8, 4, 5, 6, 6, 7, 8, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 3, 6, 0, 1, 3, 1, 2, 3, 3, 5, 6, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 9, 1, 6, 9, 1, 4, 6, 1, 3, 4, 1, 2, 3, 4, 5, 6, 6, 8, 9, 6, 7, 8, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 5, 7, 3, 4, 5, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 4, 8, 0, 1, 4, 1, 2, 4, 2, 3, 4, 4, 6, 8, 4, 5, 6, 6, 7, 8, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 2, 4, 0, 1, 2, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 8, 9, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 6, 7, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, 8, 9, 10, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 3, 6, 1, 2, 3, 3, 4, 6, 4, 5, 6, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 3, 7, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 3, 7, 3, 6, 7, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 8, 4, 5, 8, 5, 7, 8, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 4, 5, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 9, 1, 2, 9, 2, 3, 9, 3, 8, 9, 3, 5, 8, 3, 4, 5, 5, 6, 8, 6, 7, 8, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 7, 8, 2, 4, 7, 2, 3, 4, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 11, 7, 8, 11, 8, 9, 11, 9, 10, 11, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 4, 7, 2, 3, 4, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, 6, 7, 9, 7, 8, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 7, 1, 6, 7, 1, 2, 6, 2, 5, 6, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 3, 7, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 6, 1, 2, 6, 2, 3, 6, 3, 4, 6, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 7, 1, 2, 7, 2, 3, 7, 3, 4, 7, 4, 5, 7, 5, 6, 7, 8, 9, 11, 9, 10, 11, -1, -1, -1,
+  ],
+  [
+    0, 1, 8, 1, 2, 8, 2, 3, 8, 3, 7, 8, 3, 4, 7, 4, 5, 7, 5, 6, 7, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 5, 1, 2, 5, 2, 3, 5, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+  ],
+  [
+    0, 1, 4, 1, 2, 4, 2, 3, 4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1,
+  ],
+  [
+    0, 1, 2, 3, 4, 5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    0, 1, 2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1, -1,
+  ],
+  [
+    0, 1, 3, 1, 2, 3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1,
+  ],
+  [
+    -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    -1, -1, -1,
+  ],
+];
+
+/// Vertices of each case as the samples of its edge (`low << 4 | high`), `0` terminated. The
+/// samples `0x9` to `0xC` are the half resolution copies of the corners `0`, `2`, `6` and `8`.
+pub const TRANSITION_VERTEX_DATA: [[u8; 12]; 512] = [
+  [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x03, 0x9b, 0x9a, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x12, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0x9a, 0x12, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [0x25, 0x12, 0x9a, 0xac, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x03, 0x9b, 0xac, 0x25, 0x12, 0, 0, 0, 0, 0, 0],
+  [0x25, 0xac, 0x9a, 0x01, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0xac, 0x25, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x34, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x9a, 0x9b, 0x36, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x12, 0x14, 0x03, 0x34, 0x36, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x14, 0x34, 0x36, 0x9b, 0x9a, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x34, 0x36, 0x12, 0x9a, 0xac, 0x25, 0, 0, 0, 0, 0],
+  [0x01, 0x34, 0x36, 0x9b, 0xac, 0x25, 0x12, 0, 0, 0, 0, 0],
+  [0x01, 0x9a, 0xac, 0x25, 0x14, 0x03, 0x34, 0x36, 0, 0, 0, 0],
+  [0x14, 0x34, 0x36, 0x9b, 0xac, 0x25, 0, 0, 0, 0, 0, 0],
+  [0x14, 0x45, 0x47, 0x34, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x03, 0x9b, 0x9a, 0x14, 0x45, 0x47, 0x34, 0, 0, 0, 0],
+  [0x01, 0x12, 0x45, 0x47, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0x9a, 0x12, 0x45, 0x47, 0x34, 0, 0, 0, 0, 0],
+  [0x25, 0x12, 0x9a, 0xac, 0x45, 0x47, 0x34, 0x14, 0, 0, 0, 0],
+  [
+    0x01, 0x03, 0x9b, 0xac, 0x25, 0x12, 0x14, 0x45, 0x47, 0x34, 0, 0,
+  ],
+  [0x25, 0xac, 0x9a, 0x01, 0x34, 0x47, 0x45, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0xac, 0x25, 0x45, 0x47, 0x34, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x14, 0x45, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x9a, 0x9b, 0x36, 0x47, 0x45, 0x14, 0, 0, 0, 0, 0],
+  [0x01, 0x12, 0x45, 0x47, 0x36, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x45, 0x47, 0x36, 0x9b, 0x9a, 0, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x14, 0x45, 0x47, 0x36, 0x12, 0x9a, 0xac, 0x25, 0, 0, 0,
+  ],
+  [
+    0x01, 0x14, 0x45, 0x47, 0x36, 0x9b, 0xac, 0x25, 0x12, 0, 0, 0,
+  ],
+  [0x01, 0x9a, 0xac, 0x25, 0x45, 0x47, 0x36, 0x03, 0, 0, 0, 0],
+  [0x25, 0x45, 0x47, 0x36, 0x9b, 0xac, 0, 0, 0, 0, 0, 0],
+  [0x25, 0x58, 0x45, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x25, 0x45, 0x58, 0x01, 0x9a, 0x9b, 0x03, 0, 0, 0, 0, 0],
+  [0x25, 0x58, 0x45, 0x12, 0x14, 0x01, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x9a, 0x9b, 0x03, 0x14, 0x25, 0x45, 0x58, 0, 0, 0, 0],
+  [0x12, 0x9a, 0xac, 0x58, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x45, 0x58, 0xac, 0x9b, 0x03, 0x01, 0, 0, 0, 0, 0],
+  [0x58, 0x45, 0x14, 0x01, 0x9a, 0xac, 0, 0, 0, 0, 0, 0],
+  [0x14, 0x45, 0x58, 0xac, 0x9b, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x34, 0x36, 0x25, 0x58, 0x45, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x34, 0x36, 0x9b, 0x9a, 0x25, 0x58, 0x45, 0, 0, 0, 0],
+  [
+    0x01, 0x12, 0x14, 0x03, 0x34, 0x36, 0x25, 0x58, 0x45, 0, 0, 0,
+  ],
+  [
+    0x12, 0x14, 0x34, 0x36, 0x9b, 0x9a, 0x25, 0x58, 0x45, 0, 0, 0,
+  ],
+  [0x12, 0x45, 0x58, 0xac, 0x9a, 0x03, 0x36, 0x34, 0, 0, 0, 0],
+  [0x01, 0x34, 0x36, 0x9b, 0xac, 0x58, 0x45, 0x12, 0, 0, 0, 0],
+  [
+    0x01, 0x14, 0x45, 0x58, 0xac, 0x9a, 0x03, 0x36, 0x34, 0, 0, 0,
+  ],
+  [0x14, 0x34, 0x36, 0x9b, 0xac, 0x58, 0x45, 0, 0, 0, 0, 0],
+  [0x25, 0x58, 0x47, 0x34, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x14, 0x34, 0x47, 0x58, 0x01, 0x9a, 0x9b, 0x03, 0, 0, 0,
+  ],
+  [0x25, 0x58, 0x47, 0x34, 0x01, 0x12, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x9a, 0x9b, 0x03, 0x34, 0x47, 0x58, 0x25, 0, 0, 0, 0],
+  [0x12, 0x9a, 0xac, 0x58, 0x47, 0x34, 0x14, 0, 0, 0, 0, 0],
+  [
+    0x12, 0x14, 0x34, 0x47, 0x58, 0xac, 0x9b, 0x03, 0x01, 0, 0, 0,
+  ],
+  [0x58, 0x47, 0x34, 0x01, 0x9a, 0xac, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x34, 0x47, 0x58, 0xac, 0x9b, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x14, 0x25, 0x58, 0x47, 0x36, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x14, 0x25, 0x58, 0x47, 0x36, 0x9b, 0x9a, 0, 0, 0, 0],
+  [0x01, 0x12, 0x25, 0x58, 0x47, 0x36, 0x03, 0, 0, 0, 0, 0],
+  [0x12, 0x25, 0x58, 0x47, 0x36, 0x9b, 0x9a, 0, 0, 0, 0, 0],
+  [0x12, 0x14, 0x03, 0x36, 0x47, 0x58, 0xac, 0x9a, 0, 0, 0, 0],
+  [0x01, 0x14, 0x12, 0x36, 0x9b, 0xac, 0x58, 0x47, 0, 0, 0, 0],
+  [0x01, 0x03, 0x36, 0x47, 0x58, 0xac, 0x9a, 0, 0, 0, 0, 0],
+  [0x36, 0x9b, 0xac, 0x58, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x67, 0xbc, 0x9b, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x67, 0xbc, 0x9a, 0x01, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x14, 0x12, 0x36, 0x9b, 0xbc, 0x67, 0, 0, 0, 0, 0],
+  [0x03, 0x14, 0x12, 0x9a, 0xbc, 0x67, 0x36, 0, 0, 0, 0, 0],
+  [0x12, 0x9a, 0xac, 0x25, 0x36, 0x67, 0xbc, 0x9b, 0, 0, 0, 0],
+  [0x01, 0x03, 0x36, 0x67, 0xbc, 0xac, 0x25, 0x12, 0, 0, 0, 0],
+  [
+    0x01, 0x9a, 0xac, 0x25, 0x14, 0x36, 0x67, 0xbc, 0x9b, 0, 0, 0,
+  ],
+  [0x03, 0x36, 0x67, 0xbc, 0xac, 0x25, 0x14, 0, 0, 0, 0, 0],
+  [0x67, 0xbc, 0x9b, 0x03, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x67, 0xbc, 0x9a, 0x01, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0xbc, 0x67, 0x34, 0x01, 0x14, 0x12, 0, 0, 0, 0],
+  [0x34, 0x14, 0x12, 0x9a, 0xbc, 0x67, 0, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x9b, 0xbc, 0x67, 0x34, 0x12, 0x25, 0xac, 0x9a, 0, 0, 0,
+  ],
+  [0x01, 0x12, 0x25, 0xac, 0xbc, 0x67, 0x34, 0, 0, 0, 0, 0],
+  [
+    0x01, 0x9a, 0xac, 0x25, 0x14, 0x03, 0x34, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [0x14, 0x34, 0x67, 0xbc, 0xac, 0x25, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x67, 0xbc, 0x9b, 0x34, 0x14, 0x45, 0x47, 0, 0, 0, 0],
+  [
+    0x36, 0x67, 0xbc, 0x9a, 0x01, 0x03, 0x34, 0x14, 0x45, 0x47, 0, 0,
+  ],
+  [
+    0x01, 0x34, 0x47, 0x45, 0x12, 0x36, 0x9b, 0xbc, 0x67, 0, 0, 0,
+  ],
+  [
+    0x03, 0x34, 0x47, 0x45, 0x12, 0x9a, 0xbc, 0x67, 0x36, 0, 0, 0,
+  ],
+  [
+    0x12, 0x9a, 0xac, 0x25, 0x14, 0x45, 0x47, 0x34, 0x36, 0x67, 0xbc, 0x9b,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x67, 0xbc, 0xac, 0x25, 0x12, 0x14, 0x45, 0x47, 0x34,
+  ],
+  [
+    0x01, 0x9a, 0xac, 0x25, 0x45, 0x47, 0x34, 0x36, 0x67, 0xbc, 0x9b, 0,
+  ],
+  [
+    0x03, 0x36, 0x67, 0xbc, 0xac, 0x25, 0x45, 0x47, 0x34, 0, 0, 0,
+  ],
+  [0x67, 0xbc, 0x9b, 0x03, 0x14, 0x45, 0x47, 0, 0, 0, 0, 0],
+  [0x67, 0xbc, 0x9a, 0x01, 0x14, 0x45, 0x47, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0xbc, 0x67, 0x47, 0x45, 0x12, 0x01, 0, 0, 0, 0],
+  [0x67, 0x47, 0x45, 0x12, 0x9a, 0xbc, 0, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x9b, 0xbc, 0x67, 0x47, 0x45, 0x14, 0x12, 0x25, 0xac, 0x9a, 0,
+  ],
+  [
+    0x01, 0x12, 0x25, 0xac, 0xbc, 0x67, 0x47, 0x45, 0x14, 0, 0, 0,
+  ],
+  [
+    0x01, 0x9a, 0xac, 0x25, 0x45, 0x47, 0x67, 0xbc, 0x9b, 0x03, 0, 0,
+  ],
+  [0x25, 0x45, 0x47, 0x67, 0xbc, 0xac, 0, 0, 0, 0, 0, 0],
+  [0x58, 0x45, 0x25, 0x67, 0xbc, 0x9b, 0x36, 0, 0, 0, 0, 0],
+  [
+    0x01, 0x03, 0x36, 0x67, 0xbc, 0x9a, 0x25, 0x58, 0x45, 0, 0, 0,
+  ],
+  [
+    0x01, 0x12, 0x14, 0x25, 0x58, 0x45, 0x36, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [
+    0x03, 0x36, 0x67, 0xbc, 0x9a, 0x12, 0x14, 0x25, 0x58, 0x45, 0, 0,
+  ],
+  [
+    0x58, 0xac, 0x9a, 0x12, 0x45, 0x67, 0x36, 0x9b, 0xbc, 0, 0, 0,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x67, 0xbc, 0xac, 0x58, 0x45, 0x12, 0, 0, 0,
+  ],
+  [
+    0x01, 0x9a, 0xac, 0x58, 0x45, 0x14, 0x36, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [0x03, 0x36, 0x67, 0xbc, 0xac, 0x58, 0x45, 0x14, 0, 0, 0, 0],
+  [0x67, 0x34, 0x03, 0x9b, 0xbc, 0x58, 0x25, 0x45, 0, 0, 0, 0],
+  [0x01, 0x34, 0x67, 0xbc, 0x9a, 0x25, 0x58, 0x45, 0, 0, 0, 0],
+  [
+    0x01, 0x12, 0x14, 0x03, 0x34, 0x67, 0xbc, 0x9b, 0x25, 0x58, 0x45, 0,
+  ],
+  [
+    0x12, 0x14, 0x34, 0x67, 0xbc, 0x9a, 0x25, 0x58, 0x45, 0, 0, 0,
+  ],
+  [
+    0x03, 0x34, 0x67, 0xbc, 0x9b, 0x12, 0x9a, 0xac, 0x58, 0x45, 0, 0,
+  ],
+  [0x01, 0x34, 0x67, 0xbc, 0xac, 0x58, 0x45, 0x12, 0, 0, 0, 0],
+  [
+    0x01, 0x9a, 0xac, 0x58, 0x45, 0x14, 0x03, 0x34, 0x67, 0xbc, 0x9b, 0,
+  ],
+  [0x14, 0x34, 0x67, 0xbc, 0xac, 0x58, 0x45, 0, 0, 0, 0, 0],
+  [
+    0x58, 0x47, 0x34, 0x14, 0x25, 0x67, 0xbc, 0x9b, 0x36, 0, 0, 0,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x67, 0xbc, 0x9a, 0x14, 0x25, 0x58, 0x47, 0x34, 0,
+  ],
+  [
+    0x01, 0x12, 0x25, 0x58, 0x47, 0x34, 0x36, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [
+    0x03, 0x36, 0x67, 0xbc, 0x9a, 0x12, 0x25, 0x58, 0x47, 0x34, 0, 0,
+  ],
+  [
+    0x58, 0xac, 0x9a, 0x12, 0x14, 0x34, 0x47, 0x67, 0x36, 0x9b, 0xbc, 0,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x67, 0xbc, 0xac, 0x58, 0x47, 0x34, 0x14, 0x12, 0,
+  ],
+  [
+    0x01, 0x9a, 0xac, 0x58, 0x47, 0x34, 0x36, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [0x03, 0x36, 0x67, 0xbc, 0xac, 0x58, 0x47, 0x34, 0, 0, 0, 0],
+  [0x67, 0x47, 0x58, 0x25, 0x14, 0x03, 0x9b, 0xbc, 0, 0, 0, 0],
+  [0x01, 0x14, 0x25, 0x58, 0x47, 0x67, 0xbc, 0x9a, 0, 0, 0, 0],
+  [
+    0x01, 0x12, 0x25, 0x58, 0x47, 0x67, 0xbc, 0x9b, 0x03, 0, 0, 0,
+  ],
+  [0x12, 0x25, 0x58, 0x47, 0x67, 0xbc, 0x9a, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x14, 0x12, 0x9a, 0xac, 0x58, 0x47, 0x67, 0xbc, 0x9b, 0, 0,
+  ],
+  [0x01, 0x14, 0x12, 0x47, 0x67, 0xbc, 0xac, 0x58, 0, 0, 0, 0],
+  [
+    0x01, 0x9a, 0xac, 0x58, 0x47, 0x67, 0xbc, 0x9b, 0x03, 0, 0, 0,
+  ],
+  [0x47, 0x67, 0xbc, 0xac, 0x58, 0, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x67, 0x47, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x67, 0x47, 0x78, 0x03, 0x9b, 0x9a, 0x01, 0, 0, 0, 0, 0],
+  [0x67, 0x47, 0x78, 0x01, 0x12, 0x14, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x14, 0x12, 0x9a, 0x9b, 0x67, 0x78, 0x47, 0, 0, 0, 0],
+  [0x78, 0x47, 0x67, 0x25, 0xac, 0x9a, 0x12, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x12, 0x01, 0x03, 0x9b, 0xac, 0x78, 0x67, 0x47, 0, 0, 0,
+  ],
+  [0x25, 0x14, 0x01, 0x9a, 0xac, 0x78, 0x67, 0x47, 0, 0, 0, 0],
+  [0x25, 0x14, 0x03, 0x9b, 0xac, 0x78, 0x67, 0x47, 0, 0, 0, 0],
+  [0x36, 0x03, 0x34, 0x67, 0x47, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x9b, 0x9a, 0x01, 0x34, 0x67, 0x47, 0x78, 0, 0, 0, 0],
+  [
+    0x36, 0x03, 0x34, 0x67, 0x47, 0x78, 0x01, 0x12, 0x14, 0, 0, 0,
+  ],
+  [
+    0x36, 0x34, 0x14, 0x12, 0x9a, 0x9b, 0x67, 0x78, 0x47, 0, 0, 0,
+  ],
+  [
+    0x78, 0x67, 0x47, 0x36, 0x03, 0x34, 0x25, 0x12, 0x9a, 0xac, 0, 0,
+  ],
+  [
+    0x01, 0x12, 0x25, 0xac, 0x9b, 0x36, 0x34, 0x67, 0x78, 0x47, 0, 0,
+  ],
+  [
+    0x03, 0x36, 0x34, 0x01, 0x14, 0x25, 0xac, 0x9a, 0x67, 0x78, 0x47, 0,
+  ],
+  [
+    0x36, 0x34, 0x14, 0x25, 0xac, 0x9b, 0x67, 0x78, 0x47, 0, 0, 0,
+  ],
+  [0x78, 0x67, 0x34, 0x14, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x67, 0x34, 0x14, 0x45, 0x78, 0x03, 0x9b, 0x9a, 0x01, 0, 0, 0,
+  ],
+  [0x67, 0x34, 0x01, 0x12, 0x45, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x34, 0x67, 0x78, 0x45, 0x12, 0x9a, 0x9b, 0, 0, 0, 0],
+  [
+    0x78, 0x45, 0x14, 0x34, 0x67, 0x25, 0xac, 0x9a, 0x12, 0, 0, 0,
+  ],
+  [
+    0x25, 0x12, 0x01, 0x03, 0x9b, 0xac, 0x45, 0x78, 0x67, 0x34, 0x14, 0,
+  ],
+  [0x25, 0x45, 0x78, 0x67, 0x34, 0x01, 0x9a, 0xac, 0, 0, 0, 0],
+  [0x25, 0x45, 0x78, 0x67, 0x34, 0x03, 0x9b, 0xac, 0, 0, 0, 0],
+  [0x36, 0x03, 0x14, 0x45, 0x78, 0x67, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x9b, 0x9a, 0x01, 0x14, 0x45, 0x78, 0x67, 0, 0, 0, 0],
+  [0x36, 0x03, 0x01, 0x12, 0x45, 0x78, 0x67, 0, 0, 0, 0, 0],
+  [0x36, 0x67, 0x78, 0x45, 0x12, 0x9a, 0x9b, 0, 0, 0, 0, 0],
+  [
+    0x78, 0x67, 0x36, 0x03, 0x14, 0x45, 0x25, 0x12, 0x9a, 0xac, 0, 0,
+  ],
+  [
+    0x01, 0x12, 0x25, 0xac, 0x9b, 0x36, 0x67, 0x78, 0x45, 0x14, 0, 0,
+  ],
+  [
+    0x03, 0x36, 0x67, 0x78, 0x45, 0x25, 0xac, 0x9a, 0x01, 0, 0, 0,
+  ],
+  [0x36, 0x67, 0x78, 0x45, 0x25, 0xac, 0x9b, 0, 0, 0, 0, 0],
+  [0x78, 0x67, 0x47, 0x58, 0x45, 0x25, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x58, 0x45, 0x78, 0x67, 0x47, 0x01, 0x03, 0x9b, 0x9a, 0, 0,
+  ],
+  [
+    0x25, 0x58, 0x45, 0x12, 0x14, 0x01, 0x78, 0x67, 0x47, 0, 0, 0,
+  ],
+  [
+    0x25, 0x58, 0x45, 0x12, 0x14, 0x03, 0x9b, 0x9a, 0x78, 0x67, 0x47, 0,
+  ],
+  [0x58, 0xac, 0x9a, 0x12, 0x45, 0x78, 0x47, 0x67, 0, 0, 0, 0],
+  [
+    0x12, 0x01, 0x03, 0x9b, 0xac, 0x58, 0x45, 0x78, 0x67, 0x47, 0, 0,
+  ],
+  [
+    0x58, 0x45, 0x14, 0x01, 0x9a, 0xac, 0x78, 0x67, 0x47, 0, 0, 0,
+  ],
+  [
+    0x58, 0x45, 0x14, 0x03, 0x9b, 0xac, 0x78, 0x67, 0x47, 0, 0, 0,
+  ],
+  [
+    0x78, 0x67, 0x47, 0x58, 0x45, 0x25, 0x36, 0x03, 0x34, 0, 0, 0,
+  ],
+  [
+    0x67, 0x78, 0x47, 0x36, 0x34, 0x01, 0x9a, 0x9b, 0x58, 0x25, 0x45, 0,
+  ],
+  [
+    0x01, 0x12, 0x14, 0x03, 0x34, 0x36, 0x25, 0x58, 0x45, 0x47, 0x78, 0x67,
+  ],
+  [
+    0x12, 0x14, 0x34, 0x36, 0x9b, 0x9a, 0x25, 0x58, 0x45, 0x47, 0x78, 0x67,
+  ],
+  [
+    0x78, 0x67, 0x47, 0x58, 0x45, 0x12, 0x9a, 0xac, 0x36, 0x03, 0x34, 0,
+  ],
+  [
+    0x01, 0x34, 0x36, 0x9b, 0xac, 0x58, 0x45, 0x12, 0x47, 0x78, 0x67, 0,
+  ],
+  [
+    0x58, 0x45, 0x14, 0x01, 0x9a, 0xac, 0x78, 0x67, 0x47, 0x34, 0x36, 0x03,
+  ],
+  [
+    0x14, 0x34, 0x36, 0x9b, 0xac, 0x58, 0x45, 0x47, 0x78, 0x67, 0, 0,
+  ],
+  [0x78, 0x67, 0x34, 0x14, 0x25, 0x58, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x58, 0x78, 0x67, 0x34, 0x14, 0x01, 0x03, 0x9b, 0x9a, 0, 0,
+  ],
+  [0x25, 0x58, 0x78, 0x67, 0x34, 0x01, 0x12, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x58, 0x78, 0x67, 0x34, 0x03, 0x9b, 0x9a, 0x12, 0, 0, 0,
+  ],
+  [0x58, 0xac, 0x9a, 0x12, 0x14, 0x34, 0x67, 0x78, 0, 0, 0, 0],
+  [
+    0x12, 0x01, 0x03, 0x9b, 0xac, 0x58, 0x78, 0x67, 0x34, 0x14, 0, 0,
+  ],
+  [0x58, 0x78, 0x67, 0x34, 0x01, 0x9a, 0xac, 0, 0, 0, 0, 0],
+  [0x58, 0x78, 0x67, 0x34, 0x03, 0x9b, 0xac, 0, 0, 0, 0, 0],
+  [0x78, 0x67, 0x36, 0x03, 0x14, 0x25, 0x58, 0, 0, 0, 0, 0],
+  [
+    0x67, 0x78, 0x58, 0x25, 0x14, 0x01, 0x9a, 0x9b, 0x36, 0, 0, 0,
+  ],
+  [0x01, 0x12, 0x25, 0x58, 0x78, 0x67, 0x36, 0x03, 0, 0, 0, 0],
+  [0x12, 0x25, 0x58, 0x78, 0x67, 0x36, 0x9b, 0x9a, 0, 0, 0, 0],
+  [
+    0x78, 0x67, 0x36, 0x03, 0x14, 0x12, 0x9a, 0xac, 0x58, 0, 0, 0,
+  ],
+  [
+    0x01, 0x14, 0x12, 0x36, 0x9b, 0xac, 0x58, 0x78, 0x67, 0, 0, 0,
+  ],
+  [0x58, 0x78, 0x67, 0x36, 0x03, 0x01, 0x9a, 0xac, 0, 0, 0, 0],
+  [0x36, 0x9b, 0xac, 0x58, 0x78, 0x67, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x9b, 0xbc, 0x78, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x47, 0x78, 0xbc, 0x9a, 0x01, 0x03, 0, 0, 0, 0, 0],
+  [0x36, 0x47, 0x78, 0xbc, 0x9b, 0x01, 0x12, 0x14, 0, 0, 0, 0],
+  [0x36, 0x47, 0x78, 0xbc, 0x9a, 0x12, 0x14, 0x03, 0, 0, 0, 0],
+  [
+    0x78, 0xbc, 0x9b, 0x36, 0x47, 0x25, 0x12, 0x9a, 0xac, 0, 0, 0,
+  ],
+  [
+    0x03, 0x01, 0x12, 0x25, 0xac, 0xbc, 0x78, 0x47, 0x36, 0, 0, 0,
+  ],
+  [
+    0x01, 0x14, 0x25, 0xac, 0x9a, 0x36, 0x9b, 0xbc, 0x78, 0x47, 0, 0,
+  ],
+  [0x03, 0x14, 0x25, 0xac, 0xbc, 0x78, 0x47, 0x36, 0, 0, 0, 0],
+  [0x03, 0x34, 0x47, 0x78, 0xbc, 0x9b, 0, 0, 0, 0, 0, 0],
+  [0x34, 0x47, 0x78, 0xbc, 0x9a, 0x01, 0, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x34, 0x47, 0x78, 0xbc, 0x9b, 0x01, 0x12, 0x14, 0, 0, 0,
+  ],
+  [0x34, 0x47, 0x78, 0xbc, 0x9a, 0x12, 0x14, 0, 0, 0, 0, 0],
+  [
+    0x78, 0xbc, 0x9b, 0x03, 0x34, 0x47, 0x25, 0x12, 0x9a, 0xac, 0, 0,
+  ],
+  [0x01, 0x12, 0x25, 0xac, 0xbc, 0x78, 0x47, 0x34, 0, 0, 0, 0],
+  [
+    0x03, 0x9b, 0xbc, 0x78, 0x47, 0x34, 0x01, 0x14, 0x25, 0xac, 0x9a, 0,
+  ],
+  [0x34, 0x14, 0x25, 0xac, 0xbc, 0x78, 0x47, 0, 0, 0, 0, 0],
+  [0x36, 0x9b, 0xbc, 0x78, 0x45, 0x14, 0x34, 0, 0, 0, 0, 0],
+  [
+    0x36, 0x34, 0x14, 0x45, 0x78, 0xbc, 0x9a, 0x01, 0x03, 0, 0, 0,
+  ],
+  [0x36, 0x34, 0x01, 0x12, 0x45, 0x78, 0xbc, 0x9b, 0, 0, 0, 0],
+  [0x36, 0x34, 0x03, 0x78, 0xbc, 0x9a, 0x12, 0x45, 0, 0, 0, 0],
+  [
+    0x78, 0xbc, 0x9b, 0x36, 0x34, 0x14, 0x45, 0x25, 0x12, 0x9a, 0xac, 0,
+  ],
+  [
+    0x03, 0x01, 0x12, 0x25, 0xac, 0xbc, 0x78, 0x45, 0x14, 0x34, 0x36, 0,
+  ],
+  [
+    0x01, 0x34, 0x36, 0x9b, 0xbc, 0x78, 0x45, 0x25, 0xac, 0x9a, 0, 0,
+  ],
+  [0x03, 0x34, 0x36, 0x45, 0x25, 0xac, 0xbc, 0x78, 0, 0, 0, 0],
+  [0x03, 0x14, 0x45, 0x78, 0xbc, 0x9b, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x14, 0x45, 0x78, 0xbc, 0x9a, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x01, 0x12, 0x45, 0x78, 0xbc, 0x9b, 0, 0, 0, 0, 0],
+  [0x78, 0xbc, 0x9a, 0x12, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x78, 0xbc, 0x9b, 0x03, 0x14, 0x45, 0x25, 0x12, 0x9a, 0xac, 0, 0,
+  ],
+  [0x01, 0x12, 0x25, 0xac, 0xbc, 0x78, 0x45, 0x14, 0, 0, 0, 0],
+  [
+    0x03, 0x9b, 0xbc, 0x78, 0x45, 0x25, 0xac, 0x9a, 0x01, 0, 0, 0,
+  ],
+  [0x45, 0x25, 0xac, 0xbc, 0x78, 0, 0, 0, 0, 0, 0, 0],
+  [0x78, 0xbc, 0x9b, 0x36, 0x47, 0x58, 0x45, 0x25, 0, 0, 0, 0],
+  [
+    0x36, 0x03, 0x01, 0x9a, 0xbc, 0x78, 0x47, 0x58, 0x25, 0x45, 0, 0,
+  ],
+  [
+    0x58, 0x25, 0x45, 0x78, 0x47, 0x36, 0x9b, 0xbc, 0x12, 0x01, 0x14, 0,
+  ],
+  [
+    0x36, 0x47, 0x78, 0xbc, 0x9a, 0x12, 0x14, 0x03, 0x45, 0x25, 0x58, 0,
+  ],
+  [
+    0x78, 0xbc, 0x9b, 0x36, 0x47, 0x58, 0x45, 0x12, 0x9a, 0xac, 0, 0,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x47, 0x78, 0xbc, 0xac, 0x58, 0x45, 0x12, 0, 0,
+  ],
+  [
+    0x58, 0xac, 0x9a, 0x01, 0x14, 0x45, 0x78, 0x47, 0x36, 0x9b, 0xbc, 0,
+  ],
+  [
+    0x03, 0x36, 0x47, 0x78, 0xbc, 0xac, 0x58, 0x45, 0x14, 0, 0, 0,
+  ],
+  [
+    0x78, 0x47, 0x34, 0x03, 0x9b, 0xbc, 0x58, 0x25, 0x45, 0, 0, 0,
+  ],
+  [
+    0x78, 0x47, 0x34, 0x01, 0x9a, 0xbc, 0x58, 0x25, 0x45, 0, 0, 0,
+  ],
+  [
+    0x03, 0x34, 0x47, 0x78, 0xbc, 0x9b, 0x01, 0x12, 0x14, 0x45, 0x25, 0x58,
+  ],
+  [
+    0x34, 0x47, 0x78, 0xbc, 0x9a, 0x12, 0x14, 0x45, 0x25, 0x58, 0, 0,
+  ],
+  [
+    0x78, 0xbc, 0x9b, 0x03, 0x34, 0x47, 0x58, 0x45, 0x12, 0x9a, 0xac, 0,
+  ],
+  [
+    0x01, 0x12, 0x45, 0x58, 0xac, 0xbc, 0x78, 0x47, 0x34, 0, 0, 0,
+  ],
+  [
+    0x01, 0x9a, 0xac, 0x58, 0x45, 0x14, 0x03, 0x34, 0x47, 0x78, 0xbc, 0x9b,
+  ],
+  [0x14, 0x34, 0x47, 0x78, 0xbc, 0xac, 0x58, 0x45, 0, 0, 0, 0],
+  [0x78, 0xbc, 0x9b, 0x36, 0x34, 0x14, 0x25, 0x58, 0, 0, 0, 0],
+  [
+    0x36, 0x03, 0x01, 0x9a, 0xbc, 0x78, 0x58, 0x25, 0x14, 0x34, 0, 0,
+  ],
+  [
+    0x58, 0x25, 0x12, 0x01, 0x34, 0x36, 0x9b, 0xbc, 0x78, 0, 0, 0,
+  ],
+  [
+    0x36, 0x34, 0x03, 0x78, 0xbc, 0x9a, 0x12, 0x25, 0x58, 0, 0, 0,
+  ],
+  [
+    0x78, 0xbc, 0x9b, 0x36, 0x34, 0x14, 0x12, 0x9a, 0xac, 0x58, 0, 0,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x34, 0x14, 0x12, 0x58, 0x78, 0xbc, 0xac, 0, 0,
+  ],
+  [
+    0x58, 0xac, 0x9a, 0x01, 0x34, 0x36, 0x9b, 0xbc, 0x78, 0, 0, 0,
+  ],
+  [0x03, 0x36, 0x34, 0x58, 0x78, 0xbc, 0xac, 0, 0, 0, 0, 0],
+  [0x78, 0x58, 0x25, 0x14, 0x03, 0x9b, 0xbc, 0, 0, 0, 0, 0],
+  [0x78, 0x58, 0x25, 0x14, 0x01, 0x9a, 0xbc, 0, 0, 0, 0, 0],
+  [0x03, 0x01, 0x12, 0x25, 0x58, 0x78, 0xbc, 0x9b, 0, 0, 0, 0],
+  [0x78, 0xbc, 0x9a, 0x12, 0x25, 0x58, 0, 0, 0, 0, 0, 0],
+  [
+    0x78, 0xbc, 0x9b, 0x03, 0x14, 0x12, 0x9a, 0xac, 0x58, 0, 0, 0,
+  ],
+  [0x01, 0x12, 0x14, 0x78, 0x58, 0xac, 0xbc, 0, 0, 0, 0, 0],
+  [0x01, 0x9a, 0xac, 0x58, 0x78, 0xbc, 0x9b, 0x03, 0, 0, 0, 0],
+  [0x58, 0x78, 0xbc, 0xac, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x58, 0xac, 0xbc, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x9b, 0x9a, 0x01, 0x78, 0x58, 0xac, 0xbc, 0, 0, 0, 0],
+  [0x12, 0x14, 0x01, 0x58, 0xac, 0xbc, 0x78, 0, 0, 0, 0, 0],
+  [
+    0x12, 0x9a, 0x9b, 0x03, 0x14, 0x58, 0x78, 0xbc, 0xac, 0, 0, 0,
+  ],
+  [0x25, 0x12, 0x9a, 0xbc, 0x78, 0x58, 0, 0, 0, 0, 0, 0],
+  [0x25, 0x12, 0x01, 0x03, 0x9b, 0xbc, 0x78, 0x58, 0, 0, 0, 0],
+  [0x25, 0x14, 0x01, 0x9a, 0xbc, 0x78, 0x58, 0, 0, 0, 0, 0],
+  [0x25, 0x58, 0x78, 0xbc, 0x9b, 0x03, 0x14, 0, 0, 0, 0, 0],
+  [0x36, 0x34, 0x03, 0x78, 0xbc, 0xac, 0x58, 0, 0, 0, 0, 0],
+  [
+    0x36, 0x9b, 0x9a, 0x01, 0x34, 0x78, 0x58, 0xac, 0xbc, 0, 0, 0,
+  ],
+  [
+    0x36, 0x03, 0x34, 0x01, 0x12, 0x14, 0x78, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x12, 0x14, 0x34, 0x78, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x78, 0x58, 0x25, 0x12, 0x9a, 0xbc, 0x36, 0x03, 0x34, 0, 0, 0,
+  ],
+  [
+    0x12, 0x25, 0x58, 0x78, 0xbc, 0x9b, 0x36, 0x34, 0x01, 0, 0, 0,
+  ],
+  [
+    0x25, 0x58, 0x78, 0xbc, 0x9a, 0x01, 0x14, 0x03, 0x36, 0x34, 0, 0,
+  ],
+  [0x25, 0x58, 0x78, 0xbc, 0x9b, 0x36, 0x34, 0x14, 0, 0, 0, 0],
+  [0x78, 0x58, 0xac, 0xbc, 0x47, 0x34, 0x14, 0x45, 0, 0, 0, 0],
+  [
+    0x03, 0x9b, 0x9a, 0x01, 0x34, 0x14, 0x45, 0x47, 0x78, 0x58, 0xac, 0xbc,
+  ],
+  [
+    0x12, 0x45, 0x47, 0x34, 0x01, 0x58, 0xac, 0xbc, 0x78, 0, 0, 0,
+  ],
+  [
+    0x12, 0x9a, 0x9b, 0x03, 0x34, 0x47, 0x45, 0x58, 0x78, 0xbc, 0xac, 0,
+  ],
+  [
+    0x25, 0x12, 0x9a, 0xbc, 0x78, 0x58, 0x45, 0x47, 0x34, 0x14, 0, 0,
+  ],
+  [
+    0x25, 0x12, 0x01, 0x03, 0x9b, 0xbc, 0x78, 0x58, 0x45, 0x47, 0x34, 0x14,
+  ],
+  [
+    0x25, 0x45, 0x47, 0x34, 0x01, 0x9a, 0xbc, 0x78, 0x58, 0, 0, 0,
+  ],
+  [
+    0x25, 0x58, 0x78, 0xbc, 0x9b, 0x03, 0x34, 0x47, 0x45, 0, 0, 0,
+  ],
+  [
+    0x36, 0x47, 0x45, 0x14, 0x03, 0x78, 0xbc, 0xac, 0x58, 0, 0, 0,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x01, 0x14, 0x45, 0x47, 0x78, 0x58, 0xac, 0xbc, 0,
+  ],
+  [
+    0x36, 0x03, 0x01, 0x12, 0x45, 0x47, 0x78, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x12, 0x45, 0x47, 0x78, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x78, 0x58, 0x25, 0x12, 0x9a, 0xbc, 0x47, 0x36, 0x03, 0x14, 0x45, 0,
+  ],
+  [
+    0x12, 0x25, 0x58, 0x78, 0xbc, 0x9b, 0x36, 0x47, 0x45, 0x14, 0x01, 0,
+  ],
+  [
+    0x25, 0x58, 0x78, 0xbc, 0x9a, 0x01, 0x03, 0x36, 0x47, 0x45, 0, 0,
+  ],
+  [0x25, 0x58, 0x78, 0xbc, 0x9b, 0x36, 0x47, 0x45, 0, 0, 0, 0],
+  [0x78, 0xbc, 0xac, 0x25, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x78, 0x45, 0x01, 0x03, 0x9b, 0x9a, 0, 0, 0,
+  ],
+  [0x25, 0xac, 0xbc, 0x78, 0x45, 0x12, 0x14, 0x01, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x78, 0x45, 0x12, 0x14, 0x03, 0x9b, 0x9a, 0, 0,
+  ],
+  [0x12, 0x9a, 0xbc, 0x78, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x01, 0x03, 0x9b, 0xbc, 0x78, 0x45, 0, 0, 0, 0, 0],
+  [0x45, 0x14, 0x01, 0x9a, 0xbc, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x45, 0x14, 0x03, 0x9b, 0xbc, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x45, 0x25, 0xac, 0xbc, 0x36, 0x03, 0x34, 0, 0, 0, 0],
+  [
+    0x36, 0x34, 0x01, 0x9a, 0x9b, 0x78, 0xbc, 0xac, 0x25, 0x45, 0, 0,
+  ],
+  [
+    0x12, 0x01, 0x14, 0x25, 0x45, 0x78, 0xbc, 0xac, 0x03, 0x36, 0x34, 0,
+  ],
+  [
+    0x12, 0x9a, 0x9b, 0x36, 0x34, 0x14, 0x25, 0x45, 0x78, 0xbc, 0xac, 0,
+  ],
+  [0x78, 0x45, 0x12, 0x9a, 0xbc, 0x36, 0x03, 0x34, 0, 0, 0, 0],
+  [0x12, 0x45, 0x78, 0xbc, 0x9b, 0x36, 0x34, 0x01, 0, 0, 0, 0],
+  [
+    0x01, 0x14, 0x45, 0x78, 0xbc, 0x9a, 0x03, 0x36, 0x34, 0, 0, 0,
+  ],
+  [0x14, 0x45, 0x78, 0xbc, 0x9b, 0x36, 0x34, 0, 0, 0, 0, 0],
+  [0x78, 0xbc, 0xac, 0x25, 0x14, 0x34, 0x47, 0, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x78, 0x47, 0x34, 0x14, 0x01, 0x03, 0x9b, 0x9a, 0,
+  ],
+  [0x25, 0xac, 0xbc, 0x78, 0x47, 0x34, 0x01, 0x12, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x78, 0x47, 0x34, 0x03, 0x9b, 0x9a, 0x12, 0, 0,
+  ],
+  [0x12, 0x9a, 0xbc, 0x78, 0x47, 0x34, 0x14, 0, 0, 0, 0, 0],
+  [
+    0x12, 0x01, 0x03, 0x9b, 0xbc, 0x78, 0x47, 0x34, 0x14, 0, 0, 0,
+  ],
+  [0x78, 0x47, 0x34, 0x01, 0x9a, 0xbc, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x47, 0x34, 0x03, 0x9b, 0xbc, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x47, 0x36, 0x03, 0x14, 0x25, 0xac, 0xbc, 0, 0, 0, 0],
+  [
+    0x36, 0x47, 0x78, 0xbc, 0xac, 0x25, 0x14, 0x01, 0x9a, 0x9b, 0, 0,
+  ],
+  [
+    0x12, 0x01, 0x03, 0x36, 0x47, 0x78, 0xbc, 0xac, 0x25, 0, 0, 0,
+  ],
+  [
+    0x12, 0x9a, 0x9b, 0x36, 0x47, 0x78, 0xbc, 0xac, 0x25, 0, 0, 0,
+  ],
+  [0x78, 0x47, 0x36, 0x03, 0x14, 0x12, 0x9a, 0xbc, 0, 0, 0, 0],
+  [0x12, 0x14, 0x01, 0x47, 0x78, 0xbc, 0x9b, 0x36, 0, 0, 0, 0],
+  [0x01, 0x03, 0x36, 0x47, 0x78, 0xbc, 0x9a, 0, 0, 0, 0, 0],
+  [0x47, 0x78, 0xbc, 0x9b, 0x36, 0, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x58, 0xac, 0x9b, 0x36, 0x67, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x67, 0x78, 0x58, 0xac, 0x9a, 0x01, 0x03, 0, 0, 0, 0],
+  [
+    0x36, 0x67, 0x78, 0x58, 0xac, 0x9b, 0x01, 0x12, 0x14, 0, 0, 0,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x58, 0xac, 0x9a, 0x12, 0x14, 0x03, 0, 0, 0,
+  ],
+  [0x78, 0x58, 0x25, 0x12, 0x9a, 0x9b, 0x36, 0x67, 0, 0, 0, 0],
+  [0x01, 0x03, 0x36, 0x67, 0x78, 0x58, 0x25, 0x12, 0, 0, 0, 0],
+  [
+    0x58, 0x78, 0x67, 0x36, 0x9b, 0x9a, 0x01, 0x14, 0x25, 0, 0, 0,
+  ],
+  [0x03, 0x36, 0x67, 0x78, 0x58, 0x25, 0x14, 0, 0, 0, 0, 0],
+  [0x67, 0x34, 0x03, 0x9b, 0xac, 0x58, 0x78, 0, 0, 0, 0, 0],
+  [0x67, 0x78, 0x58, 0xac, 0x9a, 0x01, 0x34, 0, 0, 0, 0, 0],
+  [
+    0x67, 0x78, 0x58, 0xac, 0x9b, 0x03, 0x34, 0x01, 0x12, 0x14, 0, 0,
+  ],
+  [0x67, 0x78, 0x58, 0xac, 0x9a, 0x12, 0x14, 0x34, 0, 0, 0, 0],
+  [
+    0x78, 0x58, 0x25, 0x12, 0x9a, 0x9b, 0x03, 0x34, 0x67, 0, 0, 0,
+  ],
+  [0x67, 0x78, 0x58, 0x25, 0x12, 0x01, 0x34, 0, 0, 0, 0, 0],
+  [
+    0x78, 0x58, 0x25, 0x14, 0x01, 0x9a, 0x9b, 0x03, 0x34, 0x67, 0, 0,
+  ],
+  [0x14, 0x34, 0x67, 0x78, 0x58, 0x25, 0, 0, 0, 0, 0, 0],
+  [
+    0x78, 0x58, 0xac, 0x9b, 0x36, 0x67, 0x47, 0x34, 0x14, 0x45, 0, 0,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x58, 0xac, 0x9a, 0x01, 0x03, 0x34, 0x14, 0x45, 0x47,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x58, 0xac, 0x9b, 0x34, 0x01, 0x12, 0x45, 0x47, 0,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x58, 0xac, 0x9a, 0x12, 0x45, 0x47, 0x34, 0x03, 0,
+  ],
+  [
+    0x78, 0x58, 0x25, 0x12, 0x9a, 0x9b, 0x36, 0x67, 0x47, 0x34, 0x14, 0x45,
+  ],
+  [
+    0x01, 0x03, 0x36, 0x67, 0x78, 0x58, 0x25, 0x12, 0x14, 0x45, 0x47, 0x34,
+  ],
+  [
+    0x58, 0x78, 0x67, 0x36, 0x9b, 0x9a, 0x01, 0x34, 0x47, 0x45, 0x25, 0,
+  ],
+  [
+    0x03, 0x36, 0x67, 0x78, 0x58, 0x25, 0x45, 0x47, 0x34, 0, 0, 0,
+  ],
+  [
+    0x67, 0x47, 0x45, 0x14, 0x03, 0x9b, 0xac, 0x58, 0x78, 0, 0, 0,
+  ],
+  [
+    0x67, 0x78, 0x58, 0xac, 0x9a, 0x01, 0x14, 0x45, 0x47, 0, 0, 0,
+  ],
+  [
+    0x67, 0x78, 0x58, 0xac, 0x9b, 0x03, 0x01, 0x12, 0x45, 0x47, 0, 0,
+  ],
+  [0x67, 0x78, 0x58, 0xac, 0x9a, 0x12, 0x45, 0x47, 0, 0, 0, 0],
+  [
+    0x78, 0x58, 0x25, 0x12, 0x9a, 0x9b, 0x03, 0x14, 0x45, 0x47, 0x67, 0,
+  ],
+  [
+    0x67, 0x78, 0x58, 0x25, 0x12, 0x01, 0x14, 0x45, 0x47, 0, 0, 0,
+  ],
+  [
+    0x78, 0x58, 0x25, 0x45, 0x47, 0x67, 0x03, 0x01, 0x9a, 0x9b, 0, 0,
+  ],
+  [0x25, 0x45, 0x47, 0x67, 0x78, 0x58, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x45, 0x25, 0xac, 0x9b, 0x36, 0x67, 0, 0, 0, 0, 0],
+  [
+    0x67, 0x36, 0x03, 0x01, 0x9a, 0xac, 0x25, 0x45, 0x78, 0, 0, 0,
+  ],
+  [
+    0x78, 0x67, 0x36, 0x9b, 0xac, 0x25, 0x45, 0x12, 0x01, 0x14, 0, 0,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x45, 0x25, 0xac, 0x9a, 0x12, 0x14, 0x03, 0, 0,
+  ],
+  [0x78, 0x67, 0x36, 0x9b, 0x9a, 0x12, 0x45, 0, 0, 0, 0, 0],
+  [0x12, 0x01, 0x03, 0x36, 0x67, 0x78, 0x45, 0, 0, 0, 0, 0],
+  [0x78, 0x67, 0x36, 0x9b, 0x9a, 0x01, 0x14, 0x45, 0, 0, 0, 0],
+  [0x45, 0x14, 0x03, 0x36, 0x67, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x45, 0x25, 0xac, 0x9b, 0x03, 0x34, 0x67, 0, 0, 0, 0],
+  [0x67, 0x34, 0x01, 0x9a, 0xac, 0x25, 0x45, 0x78, 0, 0, 0, 0],
+  [
+    0x78, 0x45, 0x25, 0xac, 0x9b, 0x03, 0x34, 0x67, 0x14, 0x01, 0x12, 0,
+  ],
+  [
+    0x67, 0x78, 0x45, 0x25, 0xac, 0x9a, 0x12, 0x14, 0x34, 0, 0, 0,
+  ],
+  [0x78, 0x45, 0x12, 0x9a, 0x9b, 0x03, 0x34, 0x67, 0, 0, 0, 0],
+  [0x01, 0x34, 0x67, 0x78, 0x45, 0x12, 0, 0, 0, 0, 0, 0],
+  [
+    0x78, 0x67, 0x34, 0x03, 0x9b, 0x9a, 0x01, 0x14, 0x45, 0, 0, 0,
+  ],
+  [0x14, 0x34, 0x67, 0x78, 0x45, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x78, 0x47, 0x34, 0x14, 0x25, 0xac, 0x9b, 0x36, 0x67, 0, 0, 0,
+  ],
+  [
+    0x67, 0x36, 0x03, 0x01, 0x9a, 0xac, 0x25, 0x14, 0x34, 0x47, 0x78, 0,
+  ],
+  [
+    0x78, 0x67, 0x36, 0x9b, 0xac, 0x25, 0x12, 0x01, 0x34, 0x47, 0, 0,
+  ],
+  [
+    0x36, 0x67, 0x78, 0x47, 0x34, 0x03, 0x12, 0x25, 0xac, 0x9a, 0, 0,
+  ],
+  [
+    0x78, 0x67, 0x36, 0x9b, 0x9a, 0x12, 0x14, 0x34, 0x47, 0, 0, 0,
+  ],
+  [
+    0x12, 0x01, 0x03, 0x36, 0x67, 0x78, 0x47, 0x34, 0x14, 0, 0, 0,
+  ],
+  [0x78, 0x67, 0x36, 0x9b, 0x9a, 0x01, 0x34, 0x47, 0, 0, 0, 0],
+  [0x78, 0x47, 0x34, 0x03, 0x36, 0x67, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x47, 0x67, 0x25, 0xac, 0x9b, 0x03, 0x14, 0, 0, 0, 0],
+  [0x67, 0x47, 0x78, 0x14, 0x01, 0x9a, 0xac, 0x25, 0, 0, 0, 0],
+  [
+    0x78, 0x47, 0x67, 0x25, 0xac, 0x9b, 0x03, 0x01, 0x12, 0, 0, 0,
+  ],
+  [0x67, 0x78, 0x47, 0x12, 0x25, 0xac, 0x9a, 0, 0, 0, 0, 0],
+  [0x78, 0x47, 0x67, 0x14, 0x12, 0x9a, 0x9b, 0x03, 0, 0, 0, 0],
+  [0x01, 0x14, 0x12, 0x47, 0x67, 0x78, 0, 0, 0, 0, 0, 0],
+  [0x78, 0x67, 0x47, 0x01, 0x03, 0x9b, 0x9a, 0, 0, 0, 0, 0],
+  [0x47, 0x67, 0x78, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x58, 0xac, 0xbc, 0x67, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [
+    0x67, 0xbc, 0xac, 0x58, 0x47, 0x03, 0x01, 0x9a, 0x9b, 0, 0, 0,
+  ],
+  [0x58, 0x47, 0x67, 0xbc, 0xac, 0x12, 0x01, 0x14, 0, 0, 0, 0],
+  [
+    0x67, 0x47, 0x58, 0xac, 0xbc, 0x03, 0x9b, 0x9a, 0x12, 0x14, 0, 0,
+  ],
+  [0x58, 0x47, 0x67, 0xbc, 0x9a, 0x12, 0x25, 0, 0, 0, 0, 0],
+  [
+    0x25, 0x12, 0x01, 0x03, 0x9b, 0xbc, 0x67, 0x47, 0x58, 0, 0, 0,
+  ],
+  [0x25, 0x14, 0x01, 0x9a, 0xbc, 0x67, 0x47, 0x58, 0, 0, 0, 0],
+  [0x25, 0x14, 0x03, 0x9b, 0xbc, 0x67, 0x47, 0x58, 0, 0, 0, 0],
+  [0x67, 0xbc, 0xac, 0x58, 0x47, 0x36, 0x34, 0x03, 0, 0, 0, 0],
+  [
+    0x36, 0x9b, 0x9a, 0x01, 0x34, 0x67, 0x47, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x36, 0x03, 0x34, 0x67, 0x47, 0x58, 0xac, 0xbc, 0x01, 0x12, 0x14, 0,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x12, 0x14, 0x34, 0x67, 0x47, 0x58, 0xac, 0xbc, 0,
+  ],
+  [
+    0x58, 0x25, 0x12, 0x9a, 0xbc, 0x67, 0x47, 0x36, 0x03, 0x34, 0, 0,
+  ],
+  [
+    0x25, 0x12, 0x01, 0x34, 0x36, 0x9b, 0xbc, 0x67, 0x47, 0x58, 0, 0,
+  ],
+  [
+    0x25, 0x14, 0x01, 0x9a, 0xbc, 0x67, 0x47, 0x58, 0x34, 0x36, 0x03, 0,
+  ],
+  [
+    0x25, 0x58, 0x47, 0x67, 0xbc, 0x9b, 0x36, 0x34, 0x14, 0, 0, 0,
+  ],
+  [0x58, 0xac, 0xbc, 0x67, 0x34, 0x14, 0x45, 0, 0, 0, 0, 0],
+  [
+    0x67, 0xbc, 0xac, 0x58, 0x45, 0x14, 0x34, 0x03, 0x01, 0x9a, 0x9b, 0,
+  ],
+  [0x58, 0x45, 0x12, 0x01, 0x34, 0x67, 0xbc, 0xac, 0, 0, 0, 0],
+  [
+    0x67, 0x34, 0x03, 0x9b, 0x9a, 0x12, 0x45, 0x58, 0xac, 0xbc, 0, 0,
+  ],
+  [
+    0x58, 0x45, 0x14, 0x34, 0x67, 0xbc, 0x9a, 0x12, 0x25, 0, 0, 0,
+  ],
+  [
+    0x25, 0x12, 0x01, 0x03, 0x9b, 0xbc, 0x67, 0x34, 0x14, 0x45, 0x58, 0,
+  ],
+  [0x25, 0x45, 0x58, 0x01, 0x9a, 0xbc, 0x67, 0x34, 0, 0, 0, 0],
+  [0x25, 0x45, 0x58, 0x34, 0x03, 0x9b, 0xbc, 0x67, 0, 0, 0, 0],
+  [0x67, 0xbc, 0xac, 0x58, 0x45, 0x14, 0x03, 0x36, 0, 0, 0, 0],
+  [
+    0x36, 0x9b, 0x9a, 0x01, 0x14, 0x45, 0x58, 0xac, 0xbc, 0x67, 0, 0,
+  ],
+  [
+    0x36, 0x03, 0x01, 0x12, 0x45, 0x58, 0xac, 0xbc, 0x67, 0, 0, 0,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x12, 0x45, 0x58, 0xac, 0xbc, 0x67, 0, 0, 0,
+  ],
+  [
+    0x58, 0x25, 0x12, 0x9a, 0xbc, 0x67, 0x36, 0x03, 0x14, 0x45, 0, 0,
+  ],
+  [
+    0x25, 0x12, 0x01, 0x14, 0x45, 0x58, 0x67, 0x36, 0x9b, 0xbc, 0, 0,
+  ],
+  [
+    0x25, 0x45, 0x58, 0x01, 0x9a, 0xbc, 0x67, 0x36, 0x03, 0, 0, 0,
+  ],
+  [0x25, 0x58, 0x45, 0x36, 0x67, 0xbc, 0x9b, 0, 0, 0, 0, 0],
+  [0x67, 0x47, 0x45, 0x25, 0xac, 0xbc, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x67, 0x47, 0x45, 0x01, 0x03, 0x9b, 0x9a, 0, 0,
+  ],
+  [
+    0x25, 0x45, 0x47, 0x67, 0xbc, 0xac, 0x12, 0x01, 0x14, 0, 0, 0,
+  ],
+  [
+    0x25, 0xac, 0xbc, 0x67, 0x47, 0x45, 0x12, 0x14, 0x03, 0x9b, 0x9a, 0,
+  ],
+  [0x45, 0x47, 0x67, 0xbc, 0x9a, 0x12, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x01, 0x03, 0x9b, 0xbc, 0x67, 0x47, 0x45, 0, 0, 0, 0],
+  [0x45, 0x14, 0x01, 0x9a, 0xbc, 0x67, 0x47, 0, 0, 0, 0, 0],
+  [0x45, 0x14, 0x03, 0x9b, 0xbc, 0x67, 0x47, 0, 0, 0, 0, 0],
+  [
+    0x67, 0x47, 0x45, 0x25, 0xac, 0xbc, 0x36, 0x03, 0x34, 0, 0, 0,
+  ],
+  [
+    0x67, 0xbc, 0xac, 0x25, 0x45, 0x47, 0x36, 0x34, 0x01, 0x9a, 0x9b, 0,
+  ],
+  [
+    0x67, 0x47, 0x45, 0x25, 0xac, 0xbc, 0x36, 0x03, 0x34, 0x14, 0x01, 0x12,
+  ],
+  [
+    0x36, 0x9b, 0x9a, 0x12, 0x14, 0x34, 0x67, 0x47, 0x45, 0x25, 0xac, 0xbc,
+  ],
+  [
+    0x67, 0x47, 0x45, 0x12, 0x9a, 0xbc, 0x36, 0x03, 0x34, 0, 0, 0,
+  ],
+  [
+    0x12, 0x01, 0x34, 0x36, 0x9b, 0xbc, 0x67, 0x47, 0x45, 0, 0, 0,
+  ],
+  [
+    0x45, 0x14, 0x01, 0x9a, 0xbc, 0x67, 0x47, 0x34, 0x36, 0x03, 0, 0,
+  ],
+  [0x45, 0x14, 0x34, 0x36, 0x9b, 0xbc, 0x67, 0x47, 0, 0, 0, 0],
+  [0x67, 0x34, 0x14, 0x25, 0xac, 0xbc, 0, 0, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x67, 0x34, 0x14, 0x01, 0x03, 0x9b, 0x9a, 0, 0,
+  ],
+  [0x25, 0x12, 0x01, 0x34, 0x67, 0xbc, 0xac, 0, 0, 0, 0, 0],
+  [
+    0x25, 0xac, 0xbc, 0x67, 0x34, 0x03, 0x9b, 0x9a, 0x12, 0, 0, 0,
+  ],
+  [0x12, 0x14, 0x34, 0x67, 0xbc, 0x9a, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x01, 0x03, 0x9b, 0xbc, 0x67, 0x34, 0x14, 0, 0, 0, 0],
+  [0x01, 0x9a, 0xbc, 0x67, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x34, 0x03, 0x9b, 0xbc, 0x67, 0, 0, 0, 0, 0, 0, 0],
+  [0x67, 0x36, 0x03, 0x14, 0x25, 0xac, 0xbc, 0, 0, 0, 0, 0],
+  [
+    0x67, 0xbc, 0xac, 0x25, 0x14, 0x01, 0x9a, 0x9b, 0x36, 0, 0, 0,
+  ],
+  [0x67, 0x36, 0x03, 0x01, 0x12, 0x25, 0xac, 0xbc, 0, 0, 0, 0],
+  [0x36, 0x9b, 0x9a, 0x12, 0x25, 0xac, 0xbc, 0x67, 0, 0, 0, 0],
+  [0x67, 0x36, 0x03, 0x14, 0x12, 0x9a, 0xbc, 0, 0, 0, 0, 0],
+  [0x12, 0x01, 0x14, 0x67, 0x36, 0x9b, 0xbc, 0, 0, 0, 0, 0],
+  [0x01, 0x9a, 0xbc, 0x67, 0x36, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x67, 0x36, 0x9b, 0xbc, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x58, 0xac, 0x9b, 0x36, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x01, 0x9a, 0xac, 0x58, 0x47, 0, 0, 0, 0, 0],
+  [0x36, 0x47, 0x58, 0xac, 0x9b, 0x01, 0x12, 0x14, 0, 0, 0, 0],
+  [0x36, 0x47, 0x58, 0xac, 0x9a, 0x12, 0x14, 0x03, 0, 0, 0, 0],
+  [0x58, 0x25, 0x12, 0x9a, 0x9b, 0x36, 0x47, 0, 0, 0, 0, 0],
+  [0x58, 0x25, 0x12, 0x01, 0x03, 0x36, 0x47, 0, 0, 0, 0, 0],
+  [0x58, 0x47, 0x36, 0x9b, 0x9a, 0x01, 0x14, 0x25, 0, 0, 0, 0],
+  [0x36, 0x47, 0x58, 0x25, 0x14, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x47, 0x34, 0x03, 0x9b, 0xac, 0x58, 0, 0, 0, 0, 0, 0],
+  [0x34, 0x47, 0x58, 0xac, 0x9a, 0x01, 0, 0, 0, 0, 0, 0],
+  [
+    0x03, 0x34, 0x47, 0x58, 0xac, 0x9b, 0x01, 0x12, 0x14, 0, 0, 0,
+  ],
+  [0x34, 0x47, 0x58, 0xac, 0x9a, 0x12, 0x14, 0, 0, 0, 0, 0],
+  [0x58, 0x25, 0x12, 0x9a, 0x9b, 0x03, 0x34, 0x47, 0, 0, 0, 0],
+  [0x34, 0x47, 0x58, 0x25, 0x12, 0x01, 0, 0, 0, 0, 0, 0],
+  [
+    0x58, 0x25, 0x14, 0x01, 0x9a, 0x9b, 0x03, 0x34, 0x47, 0, 0, 0,
+  ],
+  [0x34, 0x47, 0x58, 0x25, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [0x58, 0xac, 0x9b, 0x36, 0x34, 0x14, 0x45, 0, 0, 0, 0, 0],
+  [
+    0x36, 0x03, 0x01, 0x9a, 0xac, 0x58, 0x45, 0x14, 0x34, 0, 0, 0,
+  ],
+  [0x36, 0x34, 0x01, 0x12, 0x45, 0x58, 0xac, 0x9b, 0, 0, 0, 0],
+  [0x36, 0x34, 0x03, 0x45, 0x58, 0xac, 0x9a, 0x12, 0, 0, 0, 0],
+  [
+    0x58, 0x25, 0x12, 0x9a, 0x9b, 0x36, 0x34, 0x14, 0x45, 0, 0, 0,
+  ],
+  [
+    0x58, 0x25, 0x12, 0x01, 0x03, 0x36, 0x34, 0x14, 0x45, 0, 0, 0,
+  ],
+  [0x58, 0x45, 0x25, 0x34, 0x36, 0x9b, 0x9a, 0x01, 0, 0, 0, 0],
+  [0x36, 0x34, 0x03, 0x45, 0x58, 0x25, 0, 0, 0, 0, 0, 0],
+  [0x58, 0x45, 0x14, 0x03, 0x9b, 0xac, 0, 0, 0, 0, 0, 0],
+  [0x01, 0x14, 0x45, 0x58, 0xac, 0x9a, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x01, 0x12, 0x45, 0x58, 0xac, 0x9b, 0, 0, 0, 0, 0],
+  [0x45, 0x58, 0xac, 0x9a, 0x12, 0, 0, 0, 0, 0, 0, 0],
+  [0x58, 0x25, 0x12, 0x9a, 0x9b, 0x03, 0x14, 0x45, 0, 0, 0, 0],
+  [0x01, 0x14, 0x45, 0x58, 0x25, 0x12, 0, 0, 0, 0, 0, 0],
+  [0x58, 0x25, 0x45, 0x03, 0x01, 0x9a, 0x9b, 0, 0, 0, 0, 0],
+  [0x45, 0x58, 0x25, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x47, 0x45, 0x25, 0xac, 0x9b, 0x36, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x01, 0x9a, 0xac, 0x25, 0x45, 0x47, 0, 0, 0, 0],
+  [
+    0x25, 0x45, 0x47, 0x36, 0x9b, 0xac, 0x12, 0x01, 0x14, 0, 0, 0,
+  ],
+  [
+    0x36, 0x03, 0x14, 0x12, 0x9a, 0xac, 0x25, 0x45, 0x47, 0, 0, 0,
+  ],
+  [0x47, 0x45, 0x12, 0x9a, 0x9b, 0x36, 0, 0, 0, 0, 0, 0],
+  [0x47, 0x45, 0x12, 0x01, 0x03, 0x36, 0, 0, 0, 0, 0, 0],
+  [0x45, 0x47, 0x36, 0x9b, 0x9a, 0x01, 0x14, 0, 0, 0, 0, 0],
+  [0x45, 0x14, 0x03, 0x36, 0x47, 0, 0, 0, 0, 0, 0, 0],
+  [0x47, 0x45, 0x25, 0xac, 0x9b, 0x03, 0x34, 0, 0, 0, 0, 0],
+  [0x47, 0x34, 0x01, 0x9a, 0xac, 0x25, 0x45, 0, 0, 0, 0, 0],
+  [
+    0x47, 0x45, 0x25, 0xac, 0x9b, 0x03, 0x34, 0x14, 0x01, 0x12, 0, 0,
+  ],
+  [0x34, 0x47, 0x45, 0x25, 0xac, 0x9a, 0x12, 0x14, 0, 0, 0, 0],
+  [0x47, 0x45, 0x12, 0x9a, 0x9b, 0x03, 0x34, 0, 0, 0, 0, 0],
+  [0x47, 0x45, 0x12, 0x01, 0x34, 0, 0, 0, 0, 0, 0, 0],
+  [0x47, 0x45, 0x14, 0x01, 0x9a, 0x9b, 0x03, 0x34, 0, 0, 0, 0],
+  [0x14, 0x34, 0x47, 0x45, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x34, 0x14, 0x25, 0xac, 0x9b, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x01, 0x9a, 0xac, 0x25, 0x14, 0x34, 0, 0, 0, 0],
+  [0x25, 0x12, 0x01, 0x34, 0x36, 0x9b, 0xac, 0, 0, 0, 0, 0],
+  [0x36, 0x03, 0x34, 0x25, 0x12, 0x9a, 0xac, 0, 0, 0, 0, 0],
+  [0x36, 0x34, 0x14, 0x12, 0x9a, 0x9b, 0, 0, 0, 0, 0, 0],
+  [0x36, 0x34, 0x14, 0x12, 0x01, 0x03, 0, 0, 0, 0, 0, 0],
+  [0x34, 0x36, 0x9b, 0x9a, 0x01, 0, 0, 0, 0, 0, 0, 0],
+  [0x34, 0x03, 0x36, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x25, 0xac, 0x9b, 0x03, 0x14, 0, 0, 0, 0, 0, 0, 0],
+  [0x14, 0x01, 0x9a, 0xac, 0x25, 0, 0, 0, 0, 0, 0, 0],
+  [0x25, 0xac, 0x9b, 0x03, 0x01, 0x12, 0, 0, 0, 0, 0, 0],
+  [0x12, 0x25, 0xac, 0x9a, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x14, 0x12, 0x9a, 0x9b, 0x03, 0, 0, 0, 0, 0, 0, 0],
+  [0x14, 0x12, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0x03, 0x01, 0x9a, 0x9b, 0, 0, 0, 0, 0, 0, 0, 0],
+  [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+];
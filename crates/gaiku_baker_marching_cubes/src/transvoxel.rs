@@ -0,0 +1,487 @@
+use gaiku_common::{
+  bail,
+  chunk::{bake_with_lod, Reduction},
+  prelude::*,
+  Result,
+};
+use glam::Vec3;
+
+use crate::{
+  add_triangle, dominant_material, gradient, interpolate,
+  transition_tables::{TRANSITION_CELL_CLASS, TRANSITION_CELL_DATA, TRANSITION_VERTEX_DATA},
+  MarchingCubesBaker,
+};
+
+/// Faces of a chunk that border a neighbor baked with the next level of detail (half of the
+/// resolution), in the `[-x, +x, -y, +y, -z, +z]` order.
+pub type TransitionFaces = [bool; 6];
+
+/// Width of the transition cells, as a fraction of a cell.
+const TRANSITION_WIDTH: f32 = 0.5;
+
+/// Marching cubes terrain generation that stitches the chunk to its lower resolution neighbors
+/// with the transition cells of Transvoxel.
+///
+/// Each 2x2 block of cells on a transition face gets a thin transition cell between the full
+/// resolution samples of the face and the half resolution ones of the neighbor, so its surface
+/// meets the one baked by the neighbor without cracks, the cells next to the face are squeezed
+/// to make room for it and the rest are baked as `MarchingCubesBaker` does.
+///
+/// Every level of detail must be baked with this baker, it downsamples the chunk with
+/// `Reduction::Subsample` so the samples of a level lie on the samples of the previous one, and
+/// the chunk size (once downsampled) must be even to use transition faces.
+pub struct TransvoxelBaker;
+
+impl TransvoxelBaker {
+  /// Bakes the chunk with transition cells on the `transitions` faces, the neighbors on those
+  /// faces must be baked with `options.level_of_detail + 1`.
+  pub fn bake_with_transitions<V, C, T, M>(
+    chunk: &C,
+    options: &BakerOptions<T>,
+    transitions: TransitionFaces,
+  ) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Subsample,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options, transitions),
+    )
+  }
+
+  fn bake_chunk<V, C, T, M>(
+    chunk: &C,
+    options: &BakerOptions<T>,
+    transitions: TransitionFaces,
+  ) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    let size = [
+      chunk.width() as isize,
+      chunk.height() as isize,
+      chunk.depth() as isize,
+    ];
+
+    if transitions.contains(&true) && size.iter().any(|size| size % 2 != 0) {
+      bail!(
+        "Transition faces need an even chunk size, got {}x{}x{}",
+        size[0],
+        size[1],
+        size[2]
+      );
+    }
+
+    // The transition cells are baked one cell outside the chunk and moved in once built
+    let mut builder = MeshBuilder::create(
+      [
+        size[0] as f32 / 2.0,
+        size[1] as f32 / 2.0,
+        size[2] as f32 / 2.0,
+      ],
+      [
+        size[0] as f32 + 2.0,
+        size[1] as f32 + 2.0,
+        size[2] as f32 + 2.0,
+      ],
+    );
+
+    for x in 0..size[0] {
+      for y in 0..size[1] {
+        for z in 0..size[2] {
+          MarchingCubesBaker::bake_cell(chunk, options, &mut builder, [x, y, z]);
+        }
+      }
+    }
+
+    for face in 0..6 {
+      if !transitions[face] {
+        continue;
+      }
+
+      let axis = face / 2;
+      let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+
+      for i in (0..size[u]).step_by(2) {
+        for j in (0..size[v]).step_by(2) {
+          Self::bake_transition(chunk, options, &mut builder, face, [i, j], &transitions);
+        }
+      }
+    }
+
+    Ok(builder.build::<M>().map(|mut mesh| {
+      let positions = mesh
+        .get_positions()
+        .iter()
+        .map(|position| squeeze(*position, size, &transitions))
+        .collect();
+      mesh.set_positions(positions);
+      mesh
+    }))
+  }
+
+  /// Bakes the transition cell of the 2x2 block at `[i, j]` of the `face`, from the full
+  /// resolution samples of the face to their half resolution copies one cell outside the chunk.
+  ///
+  /// The axes `[u, v, w]` of the tables are the two axes that follow the face axis and the
+  /// direction out of the chunk.
+  fn bake_transition<V, C, T>(
+    chunk: &C,
+    options: &BakerOptions<T>,
+    builder: &mut MeshBuilder,
+    face: usize,
+    [i, j]: [isize; 2],
+    transitions: &TransitionFaces,
+  ) where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+  {
+    let size = [
+      chunk.width() as isize,
+      chunk.height() as isize,
+      chunk.depth() as isize,
+    ];
+    let isosurface = &options.isosurface;
+    let smooth = options.shading == Shading::Smooth;
+    let axis = face / 2;
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let (plane, outward) = match face % 2 {
+      0 => (0, -1),
+      _ => (size[axis], 1),
+    };
+
+    let mut voxels = Vec::with_capacity(9);
+    let mut values = Vec::with_capacity(9);
+    let mut samples = Vec::with_capacity(13);
+
+    for index in 0..9 {
+      let mut position = [0; 3];
+      position[axis] = plane;
+      position[u] = i + index % 3;
+      position[v] = j + index / 3;
+
+      let voxel = chunk.get(position[0], position[1], position[2]);
+      let value = isosurface.sample(voxel);
+
+      voxels.push(voxel);
+      values.push(value);
+      samples.push((
+        position.map(|coordinate| coordinate as f32),
+        value,
+        if smooth {
+          gradient(chunk, isosurface, position)
+        } else {
+          Vec3::zero()
+        },
+      ));
+    }
+
+    // The half resolution copies of the corners, the ones on another transition face are also
+    // moved out of the chunk on that axis so the transition cells of the chunk edges meet
+    for &corner in [0, 2, 6, 8].iter() {
+      let (mut position, value, gradient) = samples[corner];
+      position[axis] += outward as f32;
+
+      for &other in [u, v].iter() {
+        if transitions[other * 2] && position[other] == 0.0 {
+          position[other] = -1.0;
+        } else if transitions[other * 2 + 1] && position[other] == size[other] as f32 {
+          position[other] += 1.0;
+        }
+      }
+
+      samples.push((position, value, gradient));
+    }
+
+    let case = (0..9)
+      .filter(|index| values[*index] >= 0.0)
+      .fold(0, |case, index| case | 1 << index);
+    let class = TRANSITION_CELL_CLASS[case];
+
+    let vertices = TRANSITION_VERTEX_DATA[case]
+      .iter()
+      .take_while(|edge| **edge != 0)
+      .map(|edge| {
+        let (position1, value1, gradient1) = samples[(edge >> 4) as usize];
+        let (position2, value2, gradient2) = samples[(edge & 0xf) as usize];
+        let sample1 = (Vec3::from(position1), value1, gradient1);
+        let sample2 = (Vec3::from(position2), value2, gradient2);
+
+        if position1 < position2 {
+          interpolate(sample1, sample2, 0.0)
+        } else {
+          interpolate(sample2, sample1, 0.0)
+        }
+      })
+      .collect::<Vec<_>>();
+
+    if vertices.is_empty() {
+      return;
+    }
+
+    // `[u, v, w]` is left handed on the low faces, as the reflections of the class
+    let reverse = (outward < 0) != (class & 0x80 != 0);
+    let material = dominant_material(&voxels, &values);
+    let mut cell = [0.0; 3];
+    cell[axis] = plane.min(plane + outward) as f32;
+    cell[u] = i as f32;
+    cell[v] = j as f32;
+    let mut cell_size = [2.0; 3];
+    cell_size[axis] = 1.0;
+
+    for triangle in TRANSITION_CELL_DATA[(class & 0x7f) as usize]
+      .chunks(3)
+      .take_while(|triangle| triangle[0] >= 0)
+    {
+      let [a, b, c] = [0, 1, 2].map(|index| vertices[triangle[index] as usize]);
+      let triangle = if reverse { [a, c, b] } else { [a, b, c] };
+
+      add_triangle(builder, options, triangle, material, cell, cell_size);
+    }
+  }
+}
+
+impl Baker for TransvoxelBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    Self::bake_with_transitions(chunk, options, [false; 6])
+  }
+}
+
+/// Moves the `position` baked with the transition cells out of the chunk back in, the cells
+/// next to each transition face are squeezed to make room for the transition cells.
+fn squeeze(mut position: [f32; 3], size: [isize; 3], transitions: &TransitionFaces) -> [f32; 3] {
+  for axis in 0..3 {
+    let coordinate = position[axis];
+    let high = size[axis] as f32;
+
+    if transitions[axis * 2] {
+      if coordinate < 0.0 {
+        position[axis] = (coordinate + 1.0) * TRANSITION_WIDTH;
+      } else if coordinate <= 1.0 {
+        position[axis] = TRANSITION_WIDTH + coordinate * (1.0 - TRANSITION_WIDTH);
+      }
+    }
+
+    if transitions[axis * 2 + 1] {
+      if coordinate > high {
+        position[axis] = high - TRANSITION_WIDTH + (coordinate - high) * TRANSITION_WIDTH;
+      } else if coordinate >= high - 1.0 {
+        position[axis] = high - 1.0 + (coordinate - high + 1.0) * (1.0 - TRANSITION_WIDTH);
+      }
+    }
+  }
+
+  position
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{chunk::Chunk, mesh::Mesh, texture::Texture2d};
+  use std::collections::{HashMap, HashSet};
+
+  /// Chunk of 16 voxels with the density of a sphere of radius 6 centered at `[16, 8, 8]`.
+  fn sphere(position: [f32; 3]) -> Chunk {
+    let mut chunk = Chunk::new(position, 16, 16, 16);
+
+    for x in 0..16 {
+      for y in 0..16 {
+        for z in 0..16 {
+          let distance = ((position[0] + x as f32 - 16.0).powi(2)
+            + (y as f32 - 8.0).powi(2)
+            + (z as f32 - 8.0).powi(2))
+          .sqrt();
+          let density = ((6.0 - distance) * 40.0 + 128.0).clamp(0.0, 255.0);
+
+          chunk.set(x, y, z, (1, density as u8));
+        }
+      }
+    }
+
+    chunk
+  }
+
+  fn options(level_of_detail: usize) -> BakerOptions<Texture2d> {
+    BakerOptions {
+      level_of_detail,
+      isosurface: Isosurface {
+        isolevel: 0.5,
+        ..Default::default()
+      },
+      ..Default::default()
+    }
+  }
+
+  /// Edges of the triangles that lie on the plane `x == plane`, moved by `offset` and rounded.
+  fn plane_edges(mesh: &Mesh, plane: f32, offset: f32) -> HashSet<[[i32; 3]; 2]> {
+    let positions = mesh.get_positions();
+    let mut edges = HashSet::new();
+
+    for triangle in mesh.get_indices().chunks(3) {
+      for index in 0..3 {
+        let a = positions[triangle[index] as usize];
+        let b = positions[triangle[(index + 1) % 3] as usize];
+
+        if (a[0] - plane).abs() > 0.0001 || (b[0] - plane).abs() > 0.0001 || a == b {
+          continue;
+        }
+
+        let round = |point: [f32; 3]| {
+          [
+            ((point[0] + offset) * 1000.0).round() as i32,
+            (point[1] * 1000.0).round() as i32,
+            (point[2] * 1000.0).round() as i32,
+          ]
+        };
+
+        let (a, b) = (round(a), round(b));
+        edges.insert(if a < b { [a, b] } else { [b, a] });
+      }
+    }
+
+    edges
+  }
+
+  #[test]
+  fn closed_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 16, 16, 16);
+
+    for x in 0..16 {
+      for y in 0..16 {
+        for z in 0..16 {
+          let distance =
+            ((x as f32 - 8.0).powi(2) + (y as f32 - 8.0).powi(2) + (z as f32 - 8.0).powi(2)).sqrt();
+          let density = ((9.0 - distance) * 40.0 + 128.0).clamp(0.0, 255.0);
+
+          chunk.set(x, y, z, (1, density as u8));
+        }
+      }
+    }
+
+    let mesh = TransvoxelBaker::bake_with_transitions::<_, Chunk, Texture2d, Mesh>(
+      &chunk,
+      &options(1),
+      [true; 6],
+    )
+    .unwrap()
+    .unwrap();
+
+    // Every edge inside the chunk is shared by two triangles with opposite directions
+    let positions = mesh.get_positions();
+    let round =
+      |index: u32| positions[index as usize].map(|coordinate| (coordinate * 1000.0).round() as i32);
+    let mut edges = HashMap::new();
+
+    for triangle in mesh.get_indices().chunks(3) {
+      for index in 0..3 {
+        let (a, b) = (round(triangle[index]), round(triangle[(index + 1) % 3]));
+        *edges.entry((a, b)).or_insert(0) += 1;
+      }
+    }
+
+    let on_boundary = |point: [i32; 3]| {
+      point
+        .iter()
+        .any(|coordinate| *coordinate == 0 || *coordinate == 16000)
+    };
+    for ((a, b), count) in edges.iter() {
+      if a == b || (on_boundary(*a) && on_boundary(*b)) {
+        continue;
+      }
+
+      assert_eq!(*count, 1, "{:?} {:?}", a, b);
+      assert_eq!(edges.get(&(*b, *a)), Some(&1), "{:?} {:?}", a, b);
+    }
+  }
+
+  #[test]
+  fn regular_test() {
+    let chunk = sphere([8.0, 0.0, 0.0]);
+    let options = options(1);
+
+    let mesh = TransvoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let expected = MarchingCubesBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(mesh.get_positions(), expected.get_positions());
+    assert_eq!(mesh.get_indices(), expected.get_indices());
+  }
+
+  #[test]
+  fn transition_test() {
+    let mut chunk = sphere([0.0, 0.0, 0.0]);
+    let mut neighbor = sphere([16.0, 0.0, 0.0]);
+    chunk.update_neighbor_data(&neighbor);
+    neighbor.update_neighbor_data(&chunk);
+
+    let bake = |chunk: &Chunk, level_of_detail, transitions| {
+      TransvoxelBaker::bake_with_transitions::<_, Chunk, Texture2d, Mesh>(
+        chunk,
+        &options(level_of_detail),
+        transitions,
+      )
+      .unwrap()
+      .unwrap()
+    };
+
+    let mesh = bake(&chunk, 1, [false, true, false, false, false, false]);
+    let neighbor_edges = plane_edges(&bake(&neighbor, 2, [false; 6]), 0.0, 16.0);
+
+    assert!(!neighbor_edges.is_empty());
+    assert_eq!(plane_edges(&mesh, 16.0, 0.0), neighbor_edges);
+    assert_ne!(
+      plane_edges(&bake(&chunk, 1, [false; 6]), 16.0, 0.0),
+      neighbor_edges
+    );
+
+    // The transition cells keep the winding of the regular cells
+    let positions = mesh.get_positions();
+    let normals = mesh.get_normals();
+    let (mut agree, mut total) = (0, 0);
+    for triangle in mesh.get_indices().chunks(3) {
+      let [a, b, c] = [0, 1, 2].map(|index| Vec3::from(positions[triangle[index] as usize]));
+      let normal: Vec3 = triangle
+        .iter()
+        .map(|index| Vec3::from(normals[*index as usize]))
+        .fold(Vec3::zero(), |sum, normal| sum + normal);
+
+      if a.x >= 14.0 && b.x >= 14.0 && c.x >= 14.0 {
+        total += 1;
+        if (b - a).cross(c - a).dot(normal) >= 0.0 {
+          agree += 1;
+        }
+      }
+    }
+    assert!(total > 0);
+    assert_eq!(agree, total);
+
+    let odd = Chunk::new([0.0, 0.0, 0.0], 5, 4, 4);
+    assert!(
+      TransvoxelBaker::bake_with_transitions::<_, Chunk, Texture2d, Mesh>(
+        &odd,
+        &options(1),
+        [true, false, false, false, false, false],
+      )
+      .is_err()
+    );
+  }
+}
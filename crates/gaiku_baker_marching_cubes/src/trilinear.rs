@@ -0,0 +1,458 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::Vec3;
+
+use crate::GridCell;
+
+/// Crossing point of an edge and the normal at that point.
+type Vertex = ([f32; 3], [f32; 3]);
+
+/// Edge of the cell as its corners, the lowest corner first.
+type Edge = (usize, usize);
+
+/// Corners of the cell in the order of `GridCell`.
+#[cfg(test)]
+const CORNERS: [[f32; 3]; 8] = [
+  [0.0, 0.0, 0.0],
+  [1.0, 0.0, 0.0],
+  [1.0, 1.0, 0.0],
+  [0.0, 1.0, 0.0],
+  [0.0, 0.0, 1.0],
+  [1.0, 0.0, 1.0],
+  [1.0, 1.0, 1.0],
+  [0.0, 1.0, 1.0],
+];
+
+/// Faces of the cell as corners, counter-clockwise seen from outside the cell.
+const FACES: [[usize; 4]; 6] = [
+  [0, 3, 2, 1],
+  [4, 5, 6, 7],
+  [0, 1, 5, 4],
+  [3, 7, 6, 2],
+  [0, 4, 7, 3],
+  [1, 2, 6, 5],
+];
+
+/// Adds the triangles of the cell with the topology of its trilinear interpolation,
+/// counter-clockwise seen from the outside of the surface.
+///
+/// The surface crosses each face in segments, the ambiguous faces (two inside corners facing
+/// each other) join the inside corners when the asymptotic decider, the value at the saddle
+/// point of the face, is inside. The cells that share a face decide the same, so the segments
+/// always chain into closed loops.
+///
+/// Each loop is capped with a disc, except when the interior of the cell joins the two loops
+/// of the cell through a tunnel, then they are joined with a tube. Only the cells with two loops
+/// are tested for tunnels, unlike marching cubes 33 the cells with three loops where the
+/// interior joins two of them (its case 13.5.2) get three discs.
+pub(crate) fn polygonize(grid: &GridCell, isolevel: f32, triangles: &mut Vec<[Vertex; 3]>) {
+  let values = grid.value.map(|value| value - isolevel);
+  let inside = values.map(|value| value >= 0.0);
+
+  if inside.iter().all(|inside| *inside) || inside.iter().all(|inside| !*inside) {
+    return;
+  }
+
+  let edge = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+  let mut segments: Vec<(Edge, Edge)> = vec![];
+
+  for face in FACES.iter() {
+    // Each run of inside corners adds a segment from the edge where it starts to the edge
+    // where it ends
+    let mut face_segments = vec![];
+    for start in 0..4 {
+      if inside[face[start]] || !inside[face[(start + 1) % 4]] {
+        continue;
+      }
+
+      let mut end = (start + 1) % 4;
+      while inside[face[(end + 1) % 4]] {
+        end = (end + 1) % 4;
+      }
+
+      face_segments.push((
+        edge(face[start], face[(start + 1) % 4]),
+        edge(face[end], face[(end + 1) % 4]),
+      ));
+    }
+
+    if let [(from1, to1), (from2, to2)] = face_segments[..] {
+      if is_joined(&values, face) {
+        face_segments = vec![(from1, to2), (from2, to1)];
+      }
+    }
+
+    segments.extend(face_segments);
+  }
+
+  let next = segments.iter().copied().collect::<HashMap<_, _>>();
+  let mut visited = HashSet::new();
+  let mut loops = vec![];
+
+  for (first, _) in segments.iter() {
+    if visited.contains(first) {
+      continue;
+    }
+
+    let mut polygon = vec![];
+    let mut current = *first;
+
+    while visited.insert(current) {
+      polygon.push(grid.lerp(current.0, current.1, isolevel));
+      current = next[&current];
+    }
+
+    loops.push(polygon);
+  }
+
+  if loops.len() == 2 && has_tunnel(&values) {
+    tube(&loops[0], &loops[1], triangles);
+  } else {
+    for polygon in loops.iter() {
+      disc(polygon, triangles);
+    }
+  }
+}
+
+/// Asymptotic decider of the ambiguous `face`, true when the inside corners are joined.
+fn is_joined(values: &[f32; 8], face: &[usize; 4]) -> bool {
+  let first = if values[face[0]] >= 0.0 { 0 } else { 1 };
+  let inside = values[face[first]] * values[face[first + 2]];
+  let outside = values[face[first + 1]] * values[face[(first + 3) % 4]];
+
+  // The saddle value is `(inside - outside)` divided by the sum of the inside corners minus
+  // the outside ones, which is positive, so comparing the products is enough and gives the
+  // same answer to both cells of the face
+  inside >= outside
+}
+
+/// Whether the trilinear interpolation of `values` joins the two loops of the cell through its
+/// interior, with a tube instead of two discs.
+///
+/// The loops split the boundary of the cell in two caps of the same sign and the band between
+/// them. The caps are joined when a section of the cell parallel to the `z` faces is an
+/// ambiguous face whose decider joins corners of different caps. The corners of the section
+/// are linear in `z` and the decider quadratic, so the sections between their roots decide the
+/// same and one section of each interval is enough.
+fn has_tunnel(values: &[f32; 8]) -> bool {
+  let inside = values.map(|value| value >= 0.0);
+
+  // Corners joined along the boundary of the cell, through its edges and its joined faces
+  let mut caps = [0, 1, 2, 3, 4, 5, 6, 7];
+  let find = |caps: &[usize; 8], mut corner: usize| {
+    while caps[corner] != corner {
+      corner = caps[corner];
+    }
+    corner
+  };
+  let join = |caps: &mut [usize; 8], a: usize, b: usize| {
+    let (a, b) = (find(caps, a), find(caps, b));
+    caps[a.max(b)] = a.min(b);
+  };
+
+  for face in FACES.iter() {
+    for index in 0..4 {
+      let (a, b) = (face[index], face[(index + 1) % 4]);
+
+      if inside[a] == inside[b] {
+        join(&mut caps, a, b);
+      }
+    }
+
+    if inside[face[0]] == inside[face[2]]
+      && inside[face[1]] == inside[face[3]]
+      && inside[face[0]] != inside[face[1]]
+    {
+      let first = if inside[face[0]] == is_joined(values, face) {
+        0
+      } else {
+        1
+      };
+      join(&mut caps, face[first], face[first + 2]);
+    }
+  }
+
+  // The caps are the sign with two groups of corners
+  let groups = |sign: bool| {
+    (0..8)
+      .filter(|corner| inside[*corner] == sign && find(&caps, *corner) == *corner)
+      .count()
+  };
+  let sign = match (groups(true), groups(false)) {
+    (2, 1) => true,
+    (1, 2) => false,
+    _ => return false,
+  };
+
+  // The section corners in the order of the `z = 0` face, they move up to the `z = 1` face
+  let section = FACES[0];
+  let low = section.map(|corner| values[corner]);
+  let slope = section.map(|corner| values[corner + 4] - values[corner]);
+
+  // The decider `A * C - B * D` of the section as `a * z^2 + b * z + c`
+  let a = slope[0] * slope[2] - slope[1] * slope[3];
+  let b = low[0] * slope[2] + low[2] * slope[0] - low[1] * slope[3] - low[3] * slope[1];
+  let c = low[0] * low[2] - low[1] * low[3];
+
+  let mut roots = vec![0.0, 1.0];
+  for index in 0..4 {
+    if slope[index] != 0.0 {
+      roots.push(-low[index] / slope[index]);
+    }
+  }
+
+  if a != 0.0 {
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant >= 0.0 {
+      roots.push((-b + discriminant.sqrt()) / (2.0 * a));
+      roots.push((-b - discriminant.sqrt()) / (2.0 * a));
+    }
+  } else if b != 0.0 {
+    roots.push(-c / b);
+  }
+
+  roots.retain(|root| (0.0..=1.0).contains(root));
+  roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+  // The corner of the cell, on the edge of the section corner, in the same cap
+  let cap = |index: usize| {
+    let corner = section[index];
+
+    if inside[corner] == sign {
+      find(&caps, corner)
+    } else {
+      find(&caps, corner + 4)
+    }
+  };
+
+  roots.windows(2).any(|interval| {
+    let z = (interval[0] + interval[1]) / 2.0;
+    let corners = [0, 1, 2, 3].map(|index| low[index] + slope[index] * z);
+    let signs = corners.map(|value| (value >= 0.0) == sign);
+
+    (0..2).any(|first| {
+      let (second, others) = (first + 2, [1 - first, 3 - first]);
+
+      signs[first]
+        && signs[second]
+        && !signs[others[0]]
+        && !signs[others[1]]
+        && corners[first] * corners[second] > corners[others[0]] * corners[others[1]]
+        && cap(first) != cap(second)
+    })
+  })
+}
+
+/// Caps the loop `polygon`, the loops of more than four vertices are fanned around their
+/// center as they are rarely planar.
+fn disc(polygon: &[Vertex], triangles: &mut Vec<[Vertex; 3]>) {
+  match polygon.len() {
+    3 => triangles.push([polygon[0], polygon[1], polygon[2]]),
+    4 => {
+      let distance = |a: usize, b: usize| distance(polygon[a], polygon[b]);
+
+      if distance(0, 2) <= distance(1, 3) {
+        triangles.push([polygon[0], polygon[1], polygon[2]]);
+        triangles.push([polygon[0], polygon[2], polygon[3]]);
+      } else {
+        triangles.push([polygon[0], polygon[1], polygon[3]]);
+        triangles.push([polygon[1], polygon[2], polygon[3]]);
+      }
+    }
+    count => {
+      let (position, normal) = polygon.iter().fold(
+        (Vec3::zero(), Vec3::zero()),
+        |(position, normal), vertex| {
+          (
+            position + Vec3::from(vertex.0),
+            normal + Vec3::from(vertex.1),
+          )
+        },
+      );
+      let normal = if normal.length_squared() > 0.0 {
+        normal.normalize()
+      } else {
+        Vec3::zero()
+      };
+      let center = ((position / count as f32).into(), normal.into());
+
+      for index in 0..count {
+        triangles.push([center, polygon[index], polygon[(index + 1) % count]]);
+      }
+    }
+  }
+}
+
+/// Joins the loops `a` and `b` with a strip of triangles, starting from their closest
+/// vertices and advancing through the shortest diagonal.
+///
+/// Both loops bound the tube with the same orientation, so `b` is walked backwards.
+fn tube(a: &[Vertex], b: &[Vertex], triangles: &mut Vec<[Vertex; 3]>) {
+  let (n, m) = (a.len(), b.len());
+  let (mut i, mut j) = (0..n)
+    .flat_map(|i| (0..m).map(move |j| (i, j)))
+    .min_by(|(i1, j1), (i2, j2)| {
+      distance(a[*i1], b[*j1])
+        .partial_cmp(&distance(a[*i2], b[*j2]))
+        .unwrap()
+    })
+    .unwrap();
+  let (mut a_steps, mut b_steps) = (0, 0);
+
+  while a_steps < n || b_steps < m {
+    let previous = (j + m - 1) % m;
+    let advance_a = b_steps == m
+      || (a_steps < n && distance(a[(i + 1) % n], b[j]) <= distance(a[i], b[previous]));
+
+    if advance_a {
+      triangles.push([a[i], a[(i + 1) % n], b[j]]);
+      i = (i + 1) % n;
+      a_steps += 1;
+    } else {
+      triangles.push([b[previous], b[j], a[i]]);
+      j = previous;
+      b_steps += 1;
+    }
+  }
+}
+
+fn distance(a: Vertex, b: Vertex) -> f32 {
+  (Vec3::from(a.0) - Vec3::from(b.0)).length_squared()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn grid(value: [f32; 8]) -> GridCell {
+    GridCell {
+      value,
+      point: CORNERS.map(|corner| corner.into()),
+      gradient: [Vec3::zero(); 8],
+    }
+  }
+
+  /// Number of triangles of the cell with the corner `values`.
+  fn count(values: [f32; 8]) -> usize {
+    let mut triangles = vec![];
+    polygonize(&grid(values), 0.0, &mut triangles);
+
+    triangles.len()
+  }
+
+  /// Directed edges of `triangles` with the number of triangles that have each of them.
+  fn edges(triangles: &[[Vertex; 3]]) -> HashMap<[[i32; 3]; 2], usize> {
+    let mut edges = HashMap::new();
+
+    for triangle in triangles {
+      let points = triangle.map(|(position, _)| position.map(|value| (value * 1000.0) as i32));
+
+      for index in 0..3 {
+        *edges
+          .entry([points[index], points[(index + 1) % 3]])
+          .or_insert(0) += 1;
+      }
+    }
+
+    edges
+  }
+
+  #[test]
+  fn tunnel_test() {
+    // Two opposite corners inside, with the rest of the corners barely outside the interior
+    // of the cell joins them
+    let mut triangles = vec![];
+    polygonize(
+      &grid([1.0, -0.1, -0.1, -0.1, -0.1, -0.1, 1.0, -0.1]),
+      0.0,
+      &mut triangles,
+    );
+    assert_eq!(triangles.len(), 6);
+
+    // The tube is bounded by the two loops, its inner edges are shared in both directions
+    let edges = edges(&triangles);
+    let boundary = edges
+      .keys()
+      .filter(|[a, b]| !edges.contains_key(&[*b, *a]))
+      .count();
+    assert_eq!(boundary, 6);
+
+    // Far outside corners keep the two corners apart
+    let mut triangles = vec![];
+    polygonize(
+      &grid([1.0, -1.0, -1.0, -1.0, -1.0, -1.0, 1.0, -1.0]),
+      0.0,
+      &mut triangles,
+    );
+    assert_eq!(triangles.len(), 2);
+
+    // A tunnel too thin for a lattice of 30 samples per axis
+    assert!(has_tunnel(&[
+      -0.3742029,
+      0.53275275,
+      -0.302427,
+      -0.4684633,
+      0.7434405,
+      -0.75092286,
+      0.72605383,
+      -0.24937904,
+    ]));
+    assert!(!has_tunnel(&[1.0, -1.0, -1.0, -1.0, -1.0, -1.0, 1.0, -1.0]));
+  }
+
+  #[test]
+  fn decider_test() {
+    // The face at `z = 0` has the corners 0 and 2 inside
+    let joined = [1.0, -0.2, 1.0, -0.2, -1.0, -1.0, -1.0, -1.0];
+    let apart = [0.2, -1.0, 0.2, -1.0, -1.0, -1.0, -1.0, -1.0];
+
+    assert!(is_joined(&joined, &FACES[0]));
+    assert!(!is_joined(&apart, &FACES[0]));
+
+    // A single loop across the joined face, a corner cap for each corner otherwise
+    let mut triangles = vec![];
+    polygonize(&grid(joined), 0.0, &mut triangles);
+    assert_eq!(triangles.len(), 6);
+
+    let mut triangles = vec![];
+    polygonize(&grid(apart), 0.0, &mut triangles);
+    assert_eq!(triangles.len(), 2);
+  }
+
+  #[test]
+  fn case_7_test() {
+    // The corners 1, 3 and 4 inside, on the faces around the corner 0, each one with its disc
+    assert_eq!(count([-1.0, 0.2, -1.0, 0.2, 0.2, -1.0, -1.0, -1.0]), 3);
+
+    // The three faces join them, a loop around the corner 0 and a loop around the rest of the
+    // outside corners, capped with two discs
+    let apart = [-0.1, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, -1.0];
+    assert!(!has_tunnel(&apart));
+    assert_eq!(count(apart), 1 + 6);
+
+    // The interior of the cell joins the corner 0 with the corner 6, a tube between the loops
+    let tunnel = [-0.19, 0.16, -0.05, 0.14, 0.55, -0.07, -0.87, -0.11];
+    assert!([0, 2, 4]
+      .iter()
+      .all(|face| is_joined(&tunnel, &FACES[*face])));
+    assert!(has_tunnel(&tunnel));
+    assert_eq!(count(tunnel), 3 + 6);
+  }
+
+  #[test]
+  fn case_13_test() {
+    // The corners 0, 2, 5 and 7 inside, every face is ambiguous and all of them keep the
+    // corners apart, a disc for each corner
+    assert_eq!(count([0.2, -1.0, 0.2, -1.0, -1.0, 0.2, -1.0, 0.2]), 4);
+
+    // The inverse, the inside corners are joined through every face and the outside ones are
+    // apart
+    assert_eq!(count([1.0, -0.2, 1.0, -0.2, -0.2, 1.0, -0.2, 1.0]), 4);
+
+    // The face at `z = 0` joins the corners 0 and 2, three loops are always capped with discs
+    let three = [2.0, -1.0, 2.0, -1.0, -1.0, 0.2, -1.0, 0.2];
+    assert!(!has_tunnel(&three));
+    assert_eq!(count(three), 6 + 1 + 1);
+  }
+}
@@ -0,0 +1,312 @@
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  dual::{add_quad, Diagonal},
+  prelude::*,
+  Result,
+};
+
+mod net;
+
+use self::net::SurfaceNet;
+
+/// Relaxation passes of `SmoothSurfaceNetsBaker`, the vertices settle in a few of them.
+const SMOOTHING_ITERATIONS: usize = 4;
+
+/// Naive surface nets terrain generation, it places a vertex in each cell crossed by the surface
+/// at the average of the points where the surface crosses the cell edges.
+///
+/// Each edge crossed by the surface adds a quad joining the vertices of the 4 cells around it,
+/// with the material of the voxel inside the surface as atlas index. It makes about half the
+/// triangles of marching cubes and it doesn't need cases tables, so it has no ambiguous cells.
+/// The uvs map each quad to the tile of its material, as the dual contouring baker does.
+///
+/// The quads are split into two triangles through their shorter diagonal, the mesh is a
+/// triangle list like the meshes of the other bakers.
+pub struct SurfaceNetsBaker;
+
+/// Surface nets with the vertices relaxed towards their neighbors, constrained to their cells,
+/// it removes the terraces of the naive surface nets on gentle slopes.
+///
+/// The vertices of the cells shared with the neighbor chunks keep their naive position so the
+/// seams stay closed.
+pub struct SmoothSurfaceNetsBaker;
+
+impl Baker for SurfaceNetsBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake::<V, C, T, M>(chunk, options, 0)
+  }
+}
+
+impl Baker for SmoothSurfaceNetsBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake::<V, C, T, M>(chunk, options, SMOOTHING_ITERATIONS)
+  }
+}
+
+fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>, iterations: usize) -> Result<Option<M>>
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+  T: Texturify2d,
+  M: Meshify,
+{
+  bake_with_lod(
+    chunk,
+    options.level_of_detail,
+    Reduction::Subsample,
+    [Some(0), Some(1), Some(2)],
+    |chunk| bake_chunk::<V, _, T, M>(chunk, options, iterations),
+  )
+}
+
+fn bake_chunk<V, C, T, M>(
+  chunk: &C,
+  options: &BakerOptions<T>,
+  iterations: usize,
+) -> Result<Option<M>>
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+  T: Texturify2d,
+  M: Meshify,
+{
+  let size = [
+    chunk.width() as f32,
+    chunk.height() as f32,
+    chunk.depth() as f32,
+  ];
+  let mut net = SurfaceNet::new(chunk, &options.isosurface);
+  net.smooth(iterations);
+
+  // The vertices of the cells in the neighbor data go up to its far side
+  let mut builder = MeshBuilder::create(
+    [size[0] / 2.0, size[1] / 2.0, size[2] / 2.0],
+    [size[0] + 2.0, size[1] + 2.0, size[2] + 2.0],
+  );
+  let normals = net
+    .vertices
+    .iter()
+    .map(|vertex| vertex.normal())
+    .collect::<Vec<_>>();
+
+  for (quad, material) in net.quads.iter() {
+    let quad = quad.map(|index| (net.vertices[index].position.into(), normals[index].into()));
+
+    add_quad(&mut builder, options, quad, Diagonal::Shortest, *material);
+  }
+
+  Ok(builder.build::<M>())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::{chunk::Chunk, mesh::Mesh, texture::Texture2d};
+  use glam::Vec3;
+  use std::collections::HashMap;
+
+  fn bake<B: Baker>(chunk: &Chunk, options: &BakerOptions<Texture2d>) -> Mesh {
+    B::bake::<_, Chunk, Texture2d, Mesh>(chunk, options)
+      .unwrap()
+      .unwrap()
+  }
+
+  fn options(shading: Shading) -> BakerOptions<Texture2d> {
+    BakerOptions {
+      shading,
+      isosurface: Isosurface {
+        isolevel: 0.5,
+        ..Default::default()
+      },
+      ..Default::default()
+    }
+  }
+
+  /// Chunk at `x` of a ball with its center at `center` and radius `radius`.
+  fn ball(x: f32, center: [f32; 3], radius: f32) -> Chunk {
+    let mut chunk = Chunk::new([x, 0.0, 0.0], 8, 8, 8);
+
+    for vx in 0..8 {
+      for vy in 0..8 {
+        for vz in 0..8 {
+          let position = Vec3::new(x + vx as f32, vy as f32, vz as f32);
+          let distance = radius - (position - Vec3::from(center)).length();
+          let density = (127.5 + distance * 60.0).clamp(0.0, 255.0);
+
+          chunk.set(vx, vy, vz, (1, density.round() as u8));
+        }
+      }
+    }
+
+    chunk
+  }
+
+  /// Positions of the triangles of `mesh` moved by `offset` and rounded so the vertices of
+  /// different chunks can be compared.
+  fn triangles(mesh: &Mesh, offset: f32) -> Vec<[[i32; 3]; 3]> {
+    let positions = mesh.get_positions();
+
+    mesh
+      .get_indices()
+      .chunks(3)
+      .map(|triangle| {
+        [0, 1, 2].map(|index| {
+          let [x, y, z] = positions[triangle[index] as usize];
+          [x + offset, y, z].map(|value| (value * 1000.0).round() as i32)
+        })
+      })
+      .collect()
+  }
+
+  #[test]
+  fn seams_test() {
+    // A ball across two chunks, together they make a closed surface
+    let center = [8.0, 3.7, 4.2];
+    let mut chunk = ball(0.0, center, 2.8);
+    let mut neighbor = ball(8.0, center, 2.8);
+    chunk.update_neighbor_data(&neighbor);
+    neighbor.update_neighbor_data(&chunk);
+
+    for smooth in [false, true].iter() {
+      let options = options(Shading::Smooth);
+      let (mesh, neighbor_mesh) = if *smooth {
+        (
+          bake::<SmoothSurfaceNetsBaker>(&chunk, &options),
+          bake::<SmoothSurfaceNetsBaker>(&neighbor, &options),
+        )
+      } else {
+        (
+          bake::<SurfaceNetsBaker>(&chunk, &options),
+          bake::<SurfaceNetsBaker>(&neighbor, &options),
+        )
+      };
+
+      let mut edges = HashMap::new();
+      for triangle in triangles(&mesh, 0.0)
+        .into_iter()
+        .chain(triangles(&neighbor_mesh, 8.0))
+      {
+        for index in 0..3 {
+          *edges
+            .entry((triangle[index], triangle[(index + 1) % 3]))
+            .or_insert(0) += 1;
+        }
+      }
+
+      // Every edge is crossed once in each direction by the triangles at both of its sides
+      for (&(a, b), count) in edges.iter() {
+        assert_eq!(*count, 1);
+        assert_eq!(edges.get(&(b, a)), Some(&1));
+      }
+    }
+  }
+
+  #[test]
+  fn smooth_test() {
+    let center = [3.6, 3.4, 3.5];
+    let chunk = ball(0.0, center, 2.3);
+    let naive = bake::<SurfaceNetsBaker>(&chunk, &options(Shading::Smooth));
+    let smooth = bake::<SmoothSurfaceNetsBaker>(&chunk, &options(Shading::Smooth));
+
+    // The same quads, split through the diagonals of the moved vertices
+    assert_eq!(naive.get_indices().len(), smooth.get_indices().len());
+    assert_ne!(naive.get_positions(), smooth.get_positions());
+
+    // The triangles face outwards
+    let positions = smooth.get_positions();
+    for triangle in smooth.get_indices().chunks(3) {
+      let [a, b, c] = [0, 1, 2].map(|index| Vec3::from(positions[triangle[index] as usize]));
+      let normal = (b - a).cross(c - a);
+
+      assert!(normal.dot((a + b + c) / 3.0 - Vec3::from(center)) > 0.0);
+    }
+  }
+
+  #[test]
+  fn iterations_test() {
+    let chunk = ball(0.0, [3.6, 3.4, 3.5], 2.3);
+    let options = options(Shading::Smooth);
+    let positions = |mesh: Mesh| mesh.get_positions().clone();
+    let bake_iterations = |iterations| {
+      bake_chunk::<_, Chunk, Texture2d, Mesh>(&chunk, &options, iterations)
+        .unwrap()
+        .unwrap()
+    };
+
+    // The bakers are the net without relaxation and with `SMOOTHING_ITERATIONS` passes
+    assert_eq!(
+      positions(bake_iterations(0)),
+      positions(bake::<SurfaceNetsBaker>(&chunk, &options))
+    );
+    assert_eq!(
+      positions(bake_iterations(SMOOTHING_ITERATIONS)),
+      positions(bake::<SmoothSurfaceNetsBaker>(&chunk, &options))
+    );
+
+    // Each pass moves the vertices less than the previous ones, the net settles
+    let mut net = SurfaceNet::new(&chunk, &options.isosurface);
+    let mut moved = vec![];
+    for _ in 0..8 {
+      let previous = net.clone();
+      net.smooth(1);
+
+      moved.push(
+        previous
+          .vertices
+          .iter()
+          .zip(net.vertices.iter())
+          .map(|(a, b)| (a.position - b.position).length())
+          .fold(0.0f32, f32::max),
+      );
+    }
+    assert!(moved[0] > 0.0);
+    assert!(moved[7] < moved[0] / 2.0, "{:?}", moved);
+  }
+
+  #[test]
+  fn frozen_border_test() {
+    // A ball across the border with the neighbor at `x = 8`
+    let center = [8.0, 3.7, 4.2];
+    let mut chunk = ball(0.0, center, 2.8);
+    chunk.update_neighbor_data(&ball(8.0, center, 2.8));
+
+    let isosurface = options(Shading::Smooth).isosurface;
+    let naive = SurfaceNet::new(&chunk, &isosurface);
+    let mut smooth = naive.clone();
+    smooth.smooth(SMOOTHING_ITERATIONS);
+
+    // Only the vertices of the cells inside the chunk move
+    let (mut shared, mut moved) = (0, 0);
+    for (a, b) in naive.vertices.iter().zip(smooth.vertices.iter()) {
+      if a.cell.iter().any(|value| !(0..7).contains(value)) {
+        assert_eq!(a.position, b.position);
+        shared += 1;
+      } else if a.position != b.position {
+        moved += 1;
+      }
+    }
+    assert!(shared > 0 && moved > 0);
+  }
+
+  #[test]
+  fn empty_test() {
+    let empty = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    assert!(
+      SmoothSurfaceNetsBaker::bake::<_, Chunk, Texture2d, Mesh>(&empty, &Default::default())
+        .unwrap()
+        .is_none()
+    );
+  }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use gaiku_common::{
+  dual::{self, corner, corner_values, normal, EDGES},
+  prelude::*,
+};
+use glam::Vec3;
+
+/// Vertex of a cell crossed by the surface, the cell `[x, y, z]` goes from the voxel `[x, y, z]`
+/// to the voxel `[x + 1, y + 1, z + 1]`.
+#[derive(Clone, Debug)]
+pub(crate) struct NetVertex {
+  pub cell: [isize; 3],
+  pub position: Vec3,
+  values: [f32; 8],
+}
+
+impl NetVertex {
+  /// Normal of the surface at the vertex, the gradient of the trilinear interpolation of the
+  /// cell corners, zero if the density is flat.
+  pub fn normal(&self) -> Vec3 {
+    let origin = Vec3::new(
+      self.cell[0] as f32,
+      self.cell[1] as f32,
+      self.cell[2] as f32,
+    );
+
+    normal(&self.values, (self.position - origin).into()).into()
+  }
+}
+
+/// Vertices and quads of the surface nets of a chunk, the quads join the vertices of the cells
+/// from `dual::quads`.
+#[derive(Clone, Debug)]
+pub(crate) struct SurfaceNet {
+  size: [isize; 3],
+  pub vertices: Vec<NetVertex>,
+  pub quads: Vec<([usize; 4], u16)>,
+}
+
+impl SurfaceNet {
+  pub fn new<V, C>(chunk: &C, isosurface: &Isosurface) -> Self
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+  {
+    let size = [
+      chunk.width() as isize,
+      chunk.height() as isize,
+      chunk.depth() as isize,
+    ];
+    let mut vertices = vec![];
+    let mut quads = vec![];
+    let mut indices = HashMap::new();
+
+    dual::quads(chunk, isosurface, |cells, material| {
+      let quad = cells.map(|cell| {
+        *indices.entry(cell).or_insert_with(|| {
+          vertices.push(cell_vertex(chunk, isosurface, cell));
+          vertices.len() - 1
+        })
+      });
+
+      quads.push((quad, material));
+    });
+
+    Self {
+      size,
+      vertices,
+      quads,
+    }
+  }
+
+  /// Moves each vertex `iterations` times to the average of the vertices it shares a quad
+  /// edge with, constrained to its cell so the net keeps the shape of the density.
+  ///
+  /// The vertices of the cells shared with the neighbors don't move, the other chunk doesn't
+  /// know the same vertices around them and the seams would open.
+  pub fn smooth(&mut self, iterations: usize) {
+    let mut neighbors = vec![vec![]; self.vertices.len()];
+
+    for (quad, _) in self.quads.iter() {
+      for index in 0..4 {
+        let (a, b) = (quad[index], quad[(index + 1) % 4]);
+
+        neighbors[a].push(b);
+        neighbors[b].push(a);
+      }
+    }
+
+    for neighbors in neighbors.iter_mut() {
+      neighbors.sort_unstable();
+      neighbors.dedup();
+    }
+
+    let size = self.size;
+
+    for _ in 0..iterations {
+      let positions = self
+        .vertices
+        .iter()
+        .map(|vertex| vertex.position)
+        .collect::<Vec<_>>();
+
+      for (vertex, neighbors) in self.vertices.iter_mut().zip(neighbors.iter()) {
+        let cell = vertex.cell;
+        let shared = (0..3).any(|axis| cell[axis] < 0 || cell[axis] >= size[axis] - 1);
+
+        if shared || neighbors.is_empty() {
+          continue;
+        }
+
+        let start = Vec3::new(cell[0] as f32, cell[1] as f32, cell[2] as f32);
+        let average = neighbors
+          .iter()
+          .fold(Vec3::zero(), |sum, index| sum + positions[*index])
+          / neighbors.len() as f32;
+
+        vertex.position = average.max(start).min(start + Vec3::one());
+      }
+    }
+  }
+}
+
+/// Vertex of the cell at `cell`, the average of the points where the surface crosses its edges.
+fn cell_vertex<V, C>(chunk: &C, isosurface: &Isosurface, cell: [isize; 3]) -> NetVertex
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+{
+  let values = corner_values(chunk, isosurface, cell);
+  let (sum, count) = EDGES
+    .iter()
+    .filter(|(start, end)| (values[*start] >= 0.0) != (values[*end] >= 0.0))
+    .fold((Vec3::zero(), 0), |(sum, count), (start, end)| {
+      let t = values[*start] / (values[*start] - values[*end]);
+      let start = Vec3::from(corner(*start));
+
+      (
+        sum + start + (Vec3::from(corner(*end)) - start) * t,
+        count + 1,
+      )
+    });
+
+  let origin = Vec3::new(cell[0] as f32, cell[1] as f32, cell[2] as f32);
+  let local = if count > 0 {
+    sum / count as f32
+  } else {
+    Vec3::splat(0.5)
+  };
+
+  NetVertex {
+    cell,
+    position: origin + local,
+    values,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gaiku_common::chunk::Chunk;
+
+  #[test]
+  fn smooth_test() {
+    let center = Vec3::new(3.6, 3.4, 3.5);
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 8, 8, 8);
+
+    for x in 0..8 {
+      for y in 0..8 {
+        for z in 0..8 {
+          // Blocky voxels, the naive surface nets make terraces on them
+          if (Vec3::new(x as f32, y as f32, z as f32) - center).length() < 2.9 {
+            chunk.set(x, y, z, (1, 255));
+          }
+        }
+      }
+    }
+
+    let naive = SurfaceNet::new(&chunk, &Isosurface::default());
+    let mut smooth = naive.clone();
+    smooth.smooth(4);
+
+    assert_eq!(naive.quads, smooth.quads);
+
+    // The vertices stay in their cells and the ball gets rounder
+    let mut moved = false;
+    for (a, b) in naive.vertices.iter().zip(smooth.vertices.iter()) {
+      let start = Vec3::new(b.cell[0] as f32, b.cell[1] as f32, b.cell[2] as f32);
+
+      assert!(b.position.cmpge(start).all() && b.position.cmple(start + Vec3::one()).all());
+      moved |= (a.position - b.position).length() > 1e-3;
+    }
+    assert!(moved);
+
+    let deviation = |net: &SurfaceNet| {
+      let radii = net
+        .vertices
+        .iter()
+        .map(|vertex| (vertex.position - center).length())
+        .collect::<Vec<_>>();
+      let mean = radii.iter().sum::<f32>() / radii.len() as f32;
+
+      radii
+        .iter()
+        .map(|radius| (radius - mean).powi(2))
+        .sum::<f32>()
+        / radii.len() as f32
+    };
+    assert!(deviation(&smooth) < deviation(&naive));
+  }
+}
@@ -1,15 +1,18 @@
-use gaiku_common::{prelude::*, Result};
+use gaiku_common::{
+  chunk::{bake_with_lod, Reduction},
+  mesh::face_occlusion,
+  prelude::*,
+  Result,
+};
 
 /// Implementation of a naive cubical voxel terrain generation.
 pub struct VoxelBaker;
 
-// TODO: Optimize, don't create faces between chunks if there's a non empty voxel
-impl Baker for VoxelBaker {
-  type Value = (u8, u8);
-
-  fn bake<C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+impl VoxelBaker {
+  fn bake_chunk<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
   where
-    C: Chunkify<Self::Value> + Sizable,
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
     T: Texturify2d,
     M: Meshify,
   {
@@ -26,23 +29,18 @@ impl Baker for VoxelBaker {
       ],
     );
 
-    let x_limit = chunk.width() as usize - 1;
-    let y_limit = chunk.height() as usize - 1;
-    let z_limit = chunk.depth() as usize - 1;
-
-    for x in 0..chunk.width() as usize {
-      for y in 0..chunk.height() as usize {
-        for z in 0..chunk.depth() as usize {
+    for x in 0..chunk.width() as isize {
+      for y in 0..chunk.height() as isize {
+        for z in 0..chunk.depth() as isize {
           if chunk.is_air(x, y, z) {
             continue;
           }
 
-          let (atlas_index, _) = chunk.get(x, y, z);
-          let uv = if let Some(texture) = &options.texture {
-            Some(texture.get_uv(atlas_index))
-          } else {
-            None
-          };
+          let atlas_index = chunk.get(x, y, z).material();
+          let uv = options
+            .texture
+            .as_ref()
+            .map(|texture| texture.get_uv(atlas_index));
 
           let (fx, fy, fz) = (x as f32, y as f32, z as f32);
 
@@ -55,117 +53,92 @@ impl Baker for VoxelBaker {
           let bottom_right_front = [fx + 1.0, fy, fz + 1.0];
           let bottom_left_front = [fx, fy, fz + 1.0];
 
+          let mut add_face = |face: [[f32; 3]; 4], normal: [f32; 3]| {
+            let uv = uv.map(|uv| [uv.0, uv.1, uv.2, uv.3]);
+
+            if options.ambient_occlusion {
+              let occlusion = face_occlusion(chunk, [x, y, z], normal, &face);
+              builder.add_face_with_occlusion(face, Some(normal), uv, atlas_index, occlusion);
+            } else {
+              builder.add_face(face, Some(normal), uv, atlas_index);
+            }
+          };
+
           // Top
-          if y == y_limit || chunk.is_air(x, y + 1, z) {
-            builder.add_face(
+          if chunk.is_air(x, y + 1, z) {
+            add_face(
               [
                 top_right_back,
                 top_left_back,
                 top_left_front,
                 top_right_front,
               ],
-              Some([0.0, 1.0, 0.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [0.0, 1.0, 0.0],
             );
           }
 
           // Bottom
-          if y == 0 || (y > 0 && chunk.is_air(x, y - 1, z)) {
-            builder.add_face(
+          if chunk.is_air(x, y - 1, z) {
+            add_face(
               [
                 bottom_left_back,
                 bottom_right_back,
                 bottom_right_front,
                 bottom_left_front,
               ],
-              Some([0.0, -1.0, 0.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [0.0, -1.0, 0.0],
             );
           }
 
           // Left
-          if x == 0 || (x > 0 && chunk.is_air(x - 1, y, z)) {
-            builder.add_face(
+          if chunk.is_air(x - 1, y, z) {
+            add_face(
               [
                 top_left_front,
                 top_left_back,
                 bottom_left_back,
                 bottom_left_front,
               ],
-              Some([-1.0, 0.0, 0.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [-1.0, 0.0, 0.0],
             );
           }
 
           // Right
-          if x == x_limit || chunk.is_air(x + 1, y, z) {
-            builder.add_face(
+          if chunk.is_air(x + 1, y, z) {
+            add_face(
               [
                 top_right_back,
                 top_right_front,
                 bottom_right_front,
                 bottom_right_back,
               ],
-              Some([1.0, 0.0, 0.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [1.0, 0.0, 0.0],
             );
           }
 
           // Front
-          if z == z_limit || chunk.is_air(x, y, z + 1) {
-            builder.add_face(
+          if chunk.is_air(x, y, z + 1) {
+            add_face(
               [
                 top_right_front,
                 top_left_front,
                 bottom_left_front,
                 bottom_right_front,
               ],
-              Some([0.0, 0.0, 1.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [0.0, 0.0, 1.0],
             );
           }
 
           // Back
-          if z == 0 || chunk.is_air(x, y, z - 1) {
-            builder.add_face(
+          if chunk.is_air(x, y, z - 1) {
+            add_face(
               [
                 top_left_back,
                 top_right_back,
                 bottom_right_back,
                 bottom_left_back,
               ],
-              Some([0.0, 0.0, -1.0]),
-              if let Some(uv) = uv {
-                Some([uv.0, uv.1, uv.2, uv.3])
-              } else {
-                None
-              },
-              atlas_index as u16,
+              [0.0, 0.0, -1.0],
             );
           }
         }
@@ -176,10 +149,32 @@ impl Baker for VoxelBaker {
   }
 }
 
+impl Baker for VoxelBaker {
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  where
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    bake_with_lod(
+      chunk,
+      options.level_of_detail,
+      Reduction::Majority,
+      [Some(0), Some(1), Some(2)],
+      |chunk| Self::bake_chunk::<V, _, T, M>(chunk, options),
+    )
+  }
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
-  use gaiku_common::{chunk::Chunk, mesh::Mesh, texture::Texture2d};
+  use gaiku_common::{
+    chunk::{Chunk, OctreeChunk, PalettedChunk, SparseChunk},
+    mesh::Mesh,
+    texture::Texture2d,
+  };
 
   #[test]
   fn simple_test() {
@@ -188,7 +183,7 @@ mod test {
 
     chunk.set(0, 0, 0, (0, 1));
 
-    let mesh = VoxelBaker::bake::<Chunk, Texture2d, Mesh>(&chunk, &options)
+    let mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
       .unwrap()
       .unwrap();
 
@@ -198,4 +193,182 @@ mod test {
     assert_eq!(indices_count, 36);
     assert_eq!(positions_count, 24);
   }
+
+  #[test]
+  fn neighbor_test() {
+    let options = Default::default();
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 1, 1, 1);
+    let mut neighbor = Chunk::new([1.0, 0.0, 0.0], 1, 1, 1);
+
+    chunk.set(0, 0, 0, (0, 1));
+    neighbor.set(0, 0, 0, (0, 1));
+    chunk.update_neighbor_data(&neighbor);
+
+    let mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+
+    let positions_count = mesh.get_positions().len();
+    let indices_count = mesh.get_indices().len();
+
+    assert_eq!(indices_count, 30);
+    assert_eq!(positions_count, 20);
+  }
+
+  #[test]
+  fn level_of_detail_test() {
+    let options = BakerOptions::<Texture2d> {
+      level_of_detail: 3,
+      ..Default::default()
+    };
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+
+    chunk.fill([0, 0, 0], [4, 4, 4], (0, 1));
+
+    let mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+
+    let positions = mesh.get_positions();
+    let max = positions
+      .iter()
+      .fold(f32::MIN, |max, position| max.max(position[0]));
+
+    assert_eq!(mesh.get_indices().len(), 36);
+    assert_eq!(positions.len(), 24);
+    assert_eq!(max, 4.0);
+  }
+
+  #[test]
+  fn ambient_occlusion_test() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 3, 2, 3);
+
+    chunk.fill([0, 0, 0], [3, 1, 3], (1, 1));
+    chunk.set(1, 1, 0, (1, 1));
+    chunk.set(0, 1, 1, (1, 1));
+
+    let mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &Default::default())
+      .unwrap()
+      .unwrap();
+
+    assert!(mesh.get_ambient_occlusion().is_empty());
+
+    let options = BakerOptions::<Texture2d> {
+      ambient_occlusion: true,
+      ..Default::default()
+    };
+    let occluded_mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let occlusion = occluded_mesh.get_ambient_occlusion();
+
+    assert_eq!(occlusion.len(), occluded_mesh.get_positions().len());
+    assert_eq!(
+      occluded_mesh.get_positions().len(),
+      mesh.get_positions().len()
+    );
+
+    let top_occlusion = |position: [f32; 3]| {
+      let index = occluded_mesh
+        .get_positions()
+        .iter()
+        .zip(occluded_mesh.get_normals().iter())
+        .position(|(p, n)| *p == position && *n == [0.0, 1.0, 0.0])
+        .unwrap();
+
+      occlusion[index]
+    };
+
+    // Between the two pillars, next to one pillar and in the open
+    assert_eq!(top_occlusion([1.0, 1.0, 1.0]), 0);
+    assert_eq!(top_occlusion([1.0, 1.0, 2.0]), 2);
+    assert_eq!(top_occlusion([2.0, 1.0, 2.0]), 3);
+  }
+
+  #[test]
+  fn chunk_types_test() {
+    let options = Default::default();
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut paletted = PalettedChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut sparse = SparseChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut octree = OctreeChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+
+    for (x, y, z, value) in [(0, 0, 0, (1, 1)), (1, 0, 0, (2, 1)), (3, 3, 3, (1, 1))] {
+      chunk.set(x, y, z, value);
+      paletted.set(x, y, z, value);
+      sparse.set(x, y, z, value);
+      octree.set(x, y, z, value);
+    }
+
+    let mesh = VoxelBaker::bake::<_, Chunk, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+    let paletted_mesh = VoxelBaker::bake::<_, PalettedChunk, Texture2d, Mesh>(&paletted, &options)
+      .unwrap()
+      .unwrap();
+    let sparse_mesh = VoxelBaker::bake::<_, SparseChunk, Texture2d, Mesh>(&sparse, &options)
+      .unwrap()
+      .unwrap();
+    let octree_mesh = VoxelBaker::bake::<_, OctreeChunk, Texture2d, Mesh>(&octree, &options)
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(mesh.get_indices(), paletted_mesh.get_indices());
+    assert_eq!(mesh.get_positions(), paletted_mesh.get_positions());
+    assert_eq!(mesh.get_indices(), sparse_mesh.get_indices());
+    assert_eq!(mesh.get_positions(), sparse_mesh.get_positions());
+    assert_eq!(mesh.get_indices(), octree_mesh.get_indices());
+    assert_eq!(mesh.get_positions(), octree_mesh.get_positions());
+  }
+
+  struct SingleVoxel((u16, f32));
+
+  impl Chunkify<(u16, f32)> for SingleVoxel {
+    fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+      self.get(x, y, z).is_empty()
+    }
+
+    fn get(&self, x: isize, y: isize, z: isize) -> (u16, f32) {
+      if (x, y, z) == (0, 0, 0) {
+        self.0
+      } else {
+        Default::default()
+      }
+    }
+  }
+
+  impl Sizable for SingleVoxel {
+    fn with_size(_width: u16, _height: u16, _depth: u16) -> Self {
+      Self((0, 0.0))
+    }
+
+    fn width(&self) -> u16 {
+      1
+    }
+
+    fn height(&self) -> u16 {
+      1
+    }
+
+    fn depth(&self) -> u16 {
+      1
+    }
+  }
+
+  #[test]
+  fn custom_voxel_test() {
+    let options = Default::default();
+    let chunk = SingleVoxel((300, 0.5));
+
+    let mesh = VoxelBaker::bake::<_, _, Texture2d, Mesh>(&chunk, &options)
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(mesh.get_indices().len(), 36);
+
+    let chunk = SingleVoxel((300, 0.0));
+    let mesh = VoxelBaker::bake::<_, _, Texture2d, Mesh>(&chunk, &options).unwrap();
+
+    assert!(mesh.is_none());
+  }
 }
@@ -1,53 +1,282 @@
 use mint::Vector3;
 
-const EPSILON: f32 = 1e-5;
+use crate::{boxify::*, mesh::Meshify};
 
-#[derive(Clone, Debug)]
+/// Axis aligned bounding box, it is closed, the points on its faces are inside it.
+///
+/// The center, size and corners are kept in sync, so they are only readable through its
+/// accessors.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Boundary {
-  pub center: Vector3<f32>,
-  pub size: Vector3<f32>,
+  center: Vector3<f32>,
+  size: Vector3<f32>,
   start: Vector3<f32>,
   end: Vector3<f32>,
 }
 
 impl Boundary {
+  /// Creates the boundary from its center and size, a negative size is taken as positive.
   pub fn new(center: [f32; 3], size: [f32; 3]) -> Self {
     let [cx, cy, cz] = center;
+    let size = size.map(f32::abs);
     let [sx, sy, sz] = [size[0] / 2.0, size[1] / 2.0, size[2] / 2.0];
     Self {
       center: center.into(),
       size: size.into(),
-      start: [
-        cx - sx * (1. + EPSILON),
-        cy - sy * (1. + EPSILON),
-        cz - sz * (1. + EPSILON),
-      ]
-      .into(),
-      end: [
-        cx + sx * (1. + EPSILON),
-        cy + sy * (1. + EPSILON),
-        cz + sz * (1. + EPSILON),
+      start: [cx - sx, cy - sy, cz - sz].into(),
+      end: [cx + sx, cy + sy, cz + sz].into(),
+    }
+  }
+
+  /// Creates the boundary from two opposite corners, usually the lowest and highest ones, the
+  /// corners are sorted per axis so `start` is always the lowest.
+  pub fn from_start_end(start: [f32; 3], end: [f32; 3]) -> Self {
+    let (start, end) = (
+      [0, 1, 2].map(|axis| start[axis].min(end[axis])),
+      [0, 1, 2].map(|axis| start[axis].max(end[axis])),
+    );
+
+    Self {
+      center: [
+        (start[0] + end[0]) / 2.0,
+        (start[1] + end[1]) / 2.0,
+        (start[2] + end[2]) / 2.0,
       ]
       .into(),
+      size: [end[0] - start[0], end[1] - start[1], end[2] - start[2]].into(),
+      start: start.into(),
+      end: end.into(),
     }
   }
 
+  /// Boundary of a chunk, from its position to its position plus its size.
+  pub fn from_chunk<C>(chunk: &C) -> Self
+  where
+    C: Boxify,
+  {
+    let [x, y, z] = chunk.position();
+
+    Self::from_start_end(
+      [x, y, z],
+      [
+        x + chunk.width() as f32,
+        y + chunk.height() as f32,
+        z + chunk.depth() as f32,
+      ],
+    )
+  }
+
+  /// Boundary of the mesh positions, `None` if the mesh has no positions.
+  pub fn from_mesh<M>(mesh: &M) -> Option<Self>
+  where
+    M: Meshify,
+  {
+    let mut positions = mesh.get_positions().iter();
+    let first = *positions.next()?;
+
+    Some(
+      positions.fold(Self::from_start_end(first, first), |boundary, position| {
+        boundary.extend(&(*position).into())
+      }),
+    )
+  }
+
+  /// Center of the boundary.
+  pub fn center(&self) -> Vector3<f32> {
+    self.center
+  }
+
+  /// Size of the boundary along each axis.
+  pub fn size(&self) -> Vector3<f32> {
+    self.size
+  }
+
+  /// Lowest corner.
+  pub fn start(&self) -> Vector3<f32> {
+    self.start
+  }
+
+  /// Highest corner.
+  pub fn end(&self) -> Vector3<f32> {
+    self.end
+  }
+
+  /// Returns `true` if `point` is inside the boundary or on its faces.
   pub fn contains(&self, point: &Vector3<f32>) -> bool {
-    self.start.x < point.x
-      && self.start.y < point.y
-      && self.start.z < point.z
-      && self.end.x > point.x
-      && self.end.y > point.y
-      && self.end.z > point.z
+    self.start.x <= point.x
+      && self.start.y <= point.y
+      && self.start.z <= point.z
+      && self.end.x >= point.x
+      && self.end.y >= point.y
+      && self.end.z >= point.z
   }
 
-  #[allow(dead_code)]
+  /// Returns `true` if `range` is fully inside this boundary.
+  pub fn contains_boundary(&self, range: &Boundary) -> bool {
+    self.contains(&range.start) && self.contains(&range.end)
+  }
+
+  /// Returns `true` if both boundaries overlap or touch.
   pub fn intersects(&self, range: &Boundary) -> bool {
-    !(range.start.x > self.start.x
-      || range.start.y > self.start.y
-      || range.start.z > self.start.z
-      || range.end.x < self.end.x
-      || range.end.y < self.end.y
-      || range.end.z < self.end.z)
+    self.start.x <= range.end.x
+      && self.start.y <= range.end.y
+      && self.start.z <= range.end.z
+      && range.start.x <= self.end.x
+      && range.start.y <= self.end.y
+      && range.start.z <= self.end.z
+  }
+
+  /// Returns the overlap of both boundaries, `None` if they don't intersect.
+  pub fn intersection(&self, range: &Boundary) -> Option<Boundary> {
+    if !self.intersects(range) {
+      return None;
+    }
+
+    Some(Self::from_start_end(
+      [
+        self.start.x.max(range.start.x),
+        self.start.y.max(range.start.y),
+        self.start.z.max(range.start.z),
+      ],
+      [
+        self.end.x.min(range.end.x),
+        self.end.y.min(range.end.y),
+        self.end.z.min(range.end.z),
+      ],
+    ))
+  }
+
+  /// Returns the smallest boundary containing both boundaries.
+  pub fn union(&self, range: &Boundary) -> Boundary {
+    Self::from_start_end(
+      [
+        self.start.x.min(range.start.x),
+        self.start.y.min(range.start.y),
+        self.start.z.min(range.start.z),
+      ],
+      [
+        self.end.x.max(range.end.x),
+        self.end.y.max(range.end.y),
+        self.end.z.max(range.end.z),
+      ],
+    )
+  }
+
+  /// Returns the smallest boundary containing this one and `point`.
+  pub fn extend(&self, point: &Vector3<f32>) -> Boundary {
+    self.union(&Self::from_start_end((*point).into(), (*point).into()))
+  }
+
+  /// Grows the boundary by `amount` on every side, a negative amount shrinks it.
+  pub fn expand(&self, amount: f32) -> Boundary {
+    Self::new(
+      self.center.into(),
+      [
+        (self.size.x + amount * 2.0).max(0.0),
+        (self.size.y + amount * 2.0).max(0.0),
+        (self.size.z + amount * 2.0).max(0.0),
+      ],
+    )
+  }
+
+  /// Returns the point of the boundary closest to `point`, the point itself if it is inside.
+  pub fn closest_point(&self, point: &Vector3<f32>) -> Vector3<f32> {
+    [
+      point.x.clamp(self.start.x, self.end.x),
+      point.y.clamp(self.start.y, self.end.y),
+      point.z.clamp(self.start.z, self.end.z),
+    ]
+    .into()
+  }
+
+  /// Squared distance from `point` to the boundary, zero if it is inside.
+  pub fn distance_squared(&self, point: &Vector3<f32>) -> f32 {
+    let closest = self.closest_point(point);
+
+    (closest.x - point.x).powi(2) + (closest.y - point.y).powi(2) + (closest.z - point.z).powi(2)
+  }
+
+  /// Distance from `point` to the boundary, zero if it is inside.
+  pub fn distance(&self, point: &Vector3<f32>) -> f32 {
+    self.distance_squared(point).sqrt()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{chunk::Chunk, mesh::Mesh};
+
+  #[test]
+  fn check_contains_and_intersects() {
+    let boundary = Boundary::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+
+    assert!(boundary.contains(&[1.0, -1.0, 0.0].into()));
+    assert!(!boundary.contains(&[1.1, 0.0, 0.0].into()));
+
+    let inner = Boundary::new([0.5, 0.5, 0.5], [1.0, 1.0, 1.0]);
+    let touching = Boundary::from_start_end([1.0, 0.0, 0.0], [3.0, 1.0, 1.0]);
+    let outside = Boundary::new([5.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+
+    assert!(boundary.contains_boundary(&inner));
+    assert!(!inner.contains_boundary(&boundary));
+    assert!(!boundary.contains_boundary(&touching));
+
+    // A boundary inside the other one also intersects it
+    assert!(boundary.intersects(&inner));
+    assert!(inner.intersects(&boundary));
+    assert!(boundary.intersects(&touching));
+    assert!(!boundary.intersects(&outside));
+
+    assert_eq!(
+      boundary.intersection(&Boundary::new([1.0, 1.0, 1.0], [2.0, 2.0, 2.0])),
+      Some(Boundary::new([0.5, 0.5, 0.5], [1.0, 1.0, 1.0]))
+    );
+    assert_eq!(boundary.intersection(&outside), None);
+    assert_eq!(
+      boundary.union(&outside),
+      Boundary::from_start_end([-1.0, -1.0, -1.0], [5.5, 1.0, 1.0])
+    );
+  }
+
+  #[test]
+  fn check_queries_and_conversions() {
+    let boundary = Boundary::from_start_end([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+
+    assert_eq!(
+      boundary.closest_point(&[3.0, 1.0, -1.0].into()),
+      [2.0, 1.0, 0.0].into()
+    );
+    assert_eq!(boundary.distance(&[5.0, 6.0, 1.0].into()), 5.0);
+    assert_eq!(boundary.distance(&[1.0, 1.0, 1.0].into()), 0.0);
+    assert_eq!(
+      boundary.expand(1.0),
+      Boundary::from_start_end([-1.0, -1.0, -1.0], [3.0, 3.0, 3.0])
+    );
+    assert_eq!(
+      boundary.extend(&[-1.0, 1.0, 4.0].into()),
+      Boundary::from_start_end([-1.0, 0.0, 0.0], [2.0, 2.0, 4.0])
+    );
+
+    let swapped = Boundary::from_start_end([2.0, 0.0, 2.0], [0.0, 2.0, 0.0]);
+    assert_eq!(swapped, boundary);
+    assert_eq!(swapped.start(), [0.0, 0.0, 0.0].into());
+    assert_eq!(swapped.center(), [1.0, 1.0, 1.0].into());
+    assert_eq!(swapped.size(), [2.0, 2.0, 2.0].into());
+    assert_eq!(Boundary::new([1.0, 1.0, 1.0], [-2.0, 2.0, -2.0]), boundary);
+
+    let chunk = Chunk::new([16.0, 0.0, -16.0], 16, 8, 16);
+    assert_eq!(
+      Boundary::from_chunk(&chunk),
+      Boundary::from_start_end([16.0, 0.0, -16.0], [32.0, 8.0, 0.0])
+    );
+
+    let mut mesh = Mesh::new();
+    assert_eq!(Boundary::from_mesh(&mesh), None);
+    mesh.set_positions(vec![[1.0, 2.0, 3.0], [-1.0, 5.0, 0.0]]);
+    assert_eq!(
+      Boundary::from_mesh(&mesh),
+      Some(Boundary::from_start_end([-1.0, 2.0, 0.0], [1.0, 5.0, 3.0]))
+    );
   }
 }
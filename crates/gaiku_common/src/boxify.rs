@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// Define a component that contains a position.
 pub trait Positionable {
   fn with_position(position: [f32; 3]) -> Self;
@@ -15,4 +17,63 @@ pub trait Sizable {
 /// Define a component that is `Sizable` and `Positionable`, also a initializer
 pub trait Boxify: Positionable + Sizable {
   fn new(position: [f32; 3], width: u16, height: u16, depth: u16) -> Self;
+
+  /// Copies the voxels of `neighbor` that touch this chunk into the copy of the voxels around
+  /// its borders, both positions are in voxel units. Returns `true` if the copy changed.
+  ///
+  /// All the chunks of this crate store the neighbor data, the default implementation does
+  /// nothing for the components that don't.
+  fn update_neighbor_data(&mut self, _neighbor: &Self) -> bool
+  where
+    Self: Sized,
+  {
+    false
+  }
+
+  /// Fills the neighbor data of every chunk with the chunks around it.
+  ///
+  /// The chunks are expected to be laid out in a grid of chunks of the same size, so the 26
+  /// neighbors of each chunk are looked up by their position instead of testing every pair.
+  fn update_neighbors(chunks: &mut [Self])
+  where
+    Self: Sized,
+  {
+    let key = |position: [f32; 3]| position.map(|value| value.round() as i64);
+    let indices = chunks
+      .iter()
+      .enumerate()
+      .map(|(index, chunk)| (key(chunk.position()), index))
+      .collect::<HashMap<_, _>>();
+
+    for index in 0..chunks.len() {
+      let position = key(chunks[index].position());
+      let size = [
+        chunks[index].width() as i64,
+        chunks[index].height() as i64,
+        chunks[index].depth() as i64,
+      ];
+
+      for offset in (0..27).map(|index| [index % 3 - 1, (index / 3) % 3 - 1, index / 9 - 1]) {
+        if offset == [0, 0, 0] {
+          continue;
+        }
+
+        let neighbor = [0, 1, 2].map(|axis| position[axis] + offset[axis] * size[axis]);
+        let neighbor = match indices.get(&neighbor) {
+          Some(neighbor) => *neighbor,
+          None => continue,
+        };
+
+        let (chunk, neighbor) = if index < neighbor {
+          let (head, tail) = chunks.split_at_mut(neighbor);
+          (&mut head[index], &tail[0])
+        } else {
+          let (head, tail) = chunks.split_at_mut(index);
+          (&mut tail[0], &head[neighbor])
+        };
+
+        chunk.update_neighbor_data(neighbor);
+      }
+    }
+  }
 }
@@ -1,17 +1,146 @@
+use std::ops::Range;
+
+use crate::{boxify::*, voxel::Voxel};
+
 #[allow(clippy::module_inception)]
 mod chunk;
+mod csg;
+mod downsample;
+mod neighbors;
+mod octree_chunk;
+mod paletted_chunk;
 mod sparse_chunk;
 
 pub use chunk::Chunk;
+pub use csg::{ChunkifyCsg, Csg};
+pub use downsample::{bake_with_lod, DownsampledChunk, Reduction};
+pub use octree_chunk::OctreeChunk;
+pub use paletted_chunk::PalettedChunk;
 pub use sparse_chunk::SparseChunk;
 
 /// Base common denominator across all the chunk implementations used.
+///
+/// Coordinates are signed so the one voxel border with the neighbor data (`-1` and
+/// `width`/`height`/`depth`) can be read, anything outside the chunk without data is air.
 pub trait Chunkify<T> {
-  fn is_air(&self, x: usize, y: usize, z: usize) -> bool;
-  fn get(&self, x: usize, y: usize, z: usize) -> T;
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool;
+  fn get(&self, x: isize, y: isize, z: isize) -> T;
 }
 
 /// Defines a mutable chunk.
 pub trait ChunkifyMut<T> {
   fn set(&mut self, x: usize, y: usize, z: usize, value: T);
 }
+
+/// Bulk operations over axis aligned regions of a chunk, the regions are defined by its `start`
+/// corner and `size`, any part of a region outside the chunk is ignored (read as air).
+///
+/// Every method has a default implementation built on top of `Chunkify` and `ChunkifyMut`, the
+/// chunk implementations can override them when they can do it faster.
+pub trait ChunkifyRegion<T>: Chunkify<T> + ChunkifyMut<T> + Sizable
+where
+  T: Voxel,
+{
+  /// Sets every voxel inside the region to `value`.
+  fn fill(&mut self, start: [usize; 3], size: [usize; 3], value: T) {
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        for x in x_range.clone() {
+          self.set(x, y, z, value);
+        }
+      }
+    }
+  }
+
+  /// Returns the values of the region ordered by `x`, then `y` and `z`, so it can be pasted in
+  /// the same or another chunk with `paste_region`.
+  fn copy_region(&self, start: [usize; 3], size: [usize; 3]) -> Vec<T> {
+    let mut result = vec![T::default(); size[0] * size[1] * size[2]];
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        for x in x_range.clone() {
+          let index =
+            (x - start[0]) + (y - start[1]) * size[0] + (z - start[2]) * size[0] * size[1];
+          result[index] = self.get(x as isize, y as isize, z as isize);
+        }
+      }
+    }
+
+    result
+  }
+
+  /// Writes `values` (as returned by `copy_region`) in the region.
+  fn paste_region(&mut self, start: [usize; 3], size: [usize; 3], values: &[T]) {
+    assert_eq!(values.len(), size[0] * size[1] * size[2]);
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        for x in x_range.clone() {
+          let index =
+            (x - start[0]) + (y - start[1]) * size[0] + (z - start[2]) * size[0] * size[1];
+          self.set(x, y, z, values[index]);
+        }
+      }
+    }
+  }
+
+  /// Returns the coordinates and values of all the non air voxels.
+  fn voxels(&self) -> Vec<([usize; 3], T)> {
+    let mut result = vec![];
+
+    for z in 0..self.depth() as usize {
+      for y in 0..self.height() as usize {
+        for x in 0..self.width() as usize {
+          if !self.is_air(x as isize, y as isize, z as isize) {
+            result.push(([x, y, z], self.get(x as isize, y as isize, z as isize)));
+          }
+        }
+      }
+    }
+
+    result
+  }
+
+  /// Changes the material of every non air voxel made of `from` to `to` keeping its density,
+  /// returns the number of voxels changed.
+  fn replace_material(&mut self, from: u16, to: u16) -> usize {
+    let mut count = 0;
+
+    for z in 0..self.depth() as usize {
+      for y in 0..self.height() as usize {
+        for x in 0..self.width() as usize {
+          let value = self.get(x as isize, y as isize, z as isize);
+          if !value.is_empty() && value.material() == from {
+            self.set(x, y, z, T::new(to, value.density()));
+            count += 1;
+          }
+        }
+      }
+    }
+
+    count
+  }
+}
+
+/// Returns the part of the region inside the chunk as ranges for each axis.
+pub(crate) fn clip_region<C>(chunk: &C, start: [usize; 3], size: [usize; 3]) -> [Range<usize>; 3]
+where
+  C: Sizable + ?Sized,
+{
+  let limits = [
+    chunk.width() as usize,
+    chunk.height() as usize,
+    chunk.depth() as usize,
+  ];
+  let range = |axis: usize| {
+    let end = (start[axis] + size[axis]).min(limits[axis]);
+    start[axis].min(end)..end
+  };
+
+  [range(0), range(1), range(2)]
+}
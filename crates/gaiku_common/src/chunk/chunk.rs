@@ -3,7 +3,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
   boxify::*,
-  chunk::{Chunkify, ChunkifyMut},
+  chunk::{
+    clip_region,
+    neighbors::{size_of, NeighborData},
+    Chunkify, ChunkifyMut, ChunkifyRegion,
+  },
 };
 
 /// Provides a `Chunkify` implementation with index and value support `(u8, u8)`.
@@ -15,6 +19,8 @@ pub struct Chunk {
   height: u16,
   depth: u16,
   values: Vec<(u8, u8)>,
+  #[cfg_attr(feature = "serde", serde(skip))]
+  neighbors: NeighborData,
 }
 
 impl Chunk {
@@ -22,13 +28,17 @@ impl Chunk {
     x + y * self.width as usize + z * self.width as usize * self.height as usize
   }
 
-  pub fn values(&self) -> Vec<(u8, u8)> {
-    self.values.clone()
+  fn is_inside(&self, x: isize, y: isize, z: isize) -> bool {
+    x >= 0
+      && y >= 0
+      && z >= 0
+      && x < self.width as isize
+      && y < self.height as isize
+      && z < self.depth as isize
   }
 
-  // TODO: This will add  the neighbor data at the border of the chunk, so we can calculate correctly  the normals, heights, etc without need to worry to query each time to get that data
-  pub fn update_neighbor_data(&self, _neighbor: &Chunk) {
-    unimplemented!();
+  pub fn values(&self) -> Vec<(u8, u8)> {
+    self.values.clone()
   }
 }
 
@@ -40,21 +50,27 @@ impl Boxify for Chunk {
       height,
       depth,
       values: vec![(0, 0); depth as usize * height as usize * width as usize],
+      neighbors: NeighborData::default(),
     }
   }
+
+  fn update_neighbor_data(&mut self, neighbor: &Self) -> bool {
+    let size = size_of(self);
+    self.neighbors.update(self.position, size, neighbor)
+  }
 }
 
 impl Chunkify<(u8, u8)> for Chunk {
-  fn is_air(&self, x: usize, y: usize, z: usize) -> bool {
-    if x >= self.width as usize || y >= self.height as usize || z >= self.depth as usize {
-      true
-    } else {
-      self.get(x, y, z).1 == 0
-    }
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+    self.get(x, y, z).1 == 0
   }
 
-  fn get(&self, x: usize, y: usize, z: usize) -> (u8, u8) {
-    self.values[self.index(x, y, z)]
+  fn get(&self, x: isize, y: isize, z: isize) -> (u8, u8) {
+    if self.is_inside(x, y, z) {
+      self.values[self.index(x as usize, y as usize, z as usize)]
+    } else {
+      self.neighbors.get(size_of(self), x, y, z).unwrap_or((0, 0))
+    }
   }
 }
 
@@ -65,6 +81,88 @@ impl ChunkifyMut<(u8, u8)> for Chunk {
   }
 }
 
+impl ChunkifyRegion<(u8, u8)> for Chunk {
+  fn fill(&mut self, start: [usize; 3], size: [usize; 3], value: (u8, u8)) {
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        let row = self.index(x_range.start, y, z);
+        self.values[row..row + x_range.len()].fill(value);
+      }
+    }
+  }
+
+  fn copy_region(&self, start: [usize; 3], size: [usize; 3]) -> Vec<(u8, u8)> {
+    let mut result = vec![(0, 0); size[0] * size[1] * size[2]];
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        let row = self.index(x_range.start, y, z);
+        let target = (x_range.start - start[0])
+          + (y - start[1]) * size[0]
+          + (z - start[2]) * size[0] * size[1];
+        result[target..target + x_range.len()]
+          .copy_from_slice(&self.values[row..row + x_range.len()]);
+      }
+    }
+
+    result
+  }
+
+  fn paste_region(&mut self, start: [usize; 3], size: [usize; 3], values: &[(u8, u8)]) {
+    assert_eq!(values.len(), size[0] * size[1] * size[2]);
+    let [x_range, y_range, z_range] = clip_region(self, start, size);
+
+    for z in z_range {
+      for y in y_range.clone() {
+        let row = self.index(x_range.start, y, z);
+        let source = (x_range.start - start[0])
+          + (y - start[1]) * size[0]
+          + (z - start[2]) * size[0] * size[1];
+        self.values[row..row + x_range.len()]
+          .copy_from_slice(&values[source..source + x_range.len()]);
+      }
+    }
+  }
+
+  fn voxels(&self) -> Vec<([usize; 3], (u8, u8))> {
+    let width = self.width as usize;
+    let height = self.height as usize;
+
+    self
+      .values
+      .iter()
+      .enumerate()
+      .filter(|(_, value)| value.1 != 0)
+      .map(|(index, value)| {
+        (
+          [
+            index % width,
+            (index / width) % height,
+            index / (width * height),
+          ],
+          *value,
+        )
+      })
+      .collect()
+  }
+
+  fn replace_material(&mut self, from: u16, to: u16) -> usize {
+    let mut count = 0;
+
+    for value in self.values.iter_mut() {
+      if value.1 != 0 && value.0 as u16 == from {
+        *value = (to.min(u8::MAX as u16) as u8, value.1);
+        count += 1;
+      }
+    }
+
+    count
+  }
+}
+
 impl Positionable for Chunk {
   fn with_position(position: [f32; 3]) -> Self {
     Self::new(position, 16, 16, 16)
@@ -107,4 +205,74 @@ mod test {
     let index = chunk.index(1, 2, 3);
     assert_eq!(index, 69);
   }
+
+  #[test]
+  fn check_update_neighbor_data() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut right = Chunk::new([4.0, 0.0, 0.0], 4, 4, 4);
+    let mut corner = Chunk::new([-4.0, -4.0, -4.0], 4, 4, 4);
+    let far = Chunk::new([8.0, 0.0, 0.0], 4, 4, 4);
+
+    right.set(0, 1, 2, (3, 255));
+    corner.set(3, 3, 3, (4, 255));
+
+    assert!(chunk.is_air(4, 1, 2));
+
+    chunk.update_neighbor_data(&right);
+    chunk.update_neighbor_data(&corner);
+    chunk.update_neighbor_data(&far);
+
+    assert_eq!(chunk.get(4, 1, 2), (3, 255));
+    assert_eq!(chunk.get(-1, -1, -1), (4, 255));
+    assert!(chunk.is_air(4, 1, 1));
+    assert!(chunk.is_air(5, 1, 2));
+    assert_eq!(chunk.values().len(), 64);
+  }
+
+  #[test]
+  fn check_update_neighbors() {
+    let mut chunks = vec![
+      Chunk::new([0.0, 0.0, 0.0], 4, 4, 4),
+      Chunk::new([4.0, 0.0, 0.0], 4, 4, 4),
+      Chunk::new([8.0, 4.0, 4.0], 4, 4, 4),
+      Chunk::new([16.0, 0.0, 0.0], 4, 4, 4),
+    ];
+
+    chunks[0].set(3, 0, 0, (1, 255));
+    chunks[1].set(0, 0, 0, (2, 255));
+    chunks[2].set(0, 0, 0, (3, 255));
+    chunks[3].set(0, 0, 0, (4, 255));
+
+    Chunk::update_neighbors(&mut chunks);
+
+    assert_eq!(chunks[0].get(4, 0, 0), (2, 255));
+    assert_eq!(chunks[1].get(-1, 0, 0), (1, 255));
+    assert_eq!(chunks[1].get(4, 4, 4), (3, 255));
+    assert_eq!(chunks[2].get(-1, -1, -1), (0, 0));
+    assert!(chunks[1].is_air(8, 0, 0));
+  }
+
+  #[test]
+  fn check_region() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 5, 6);
+    let mut other = Chunk::new([0.0, 0.0, 0.0], 4, 5, 6);
+
+    chunk.fill([1, 1, 1], [2, 3, 10], (2, 255));
+    assert_eq!(chunk.voxels().len(), 2 * 3 * 5);
+    assert_eq!(chunk.voxels()[0], ([1, 1, 1], (2, 255)));
+    assert!(chunk.is_air(0, 1, 1));
+    assert!(chunk.is_air(3, 1, 1));
+
+    let values = chunk.copy_region([0, 0, 0], [3, 3, 3]);
+    assert_eq!(values.len(), 27);
+    assert_eq!(values.iter().filter(|value| value.1 != 0).count(), 8);
+
+    other.paste_region([2, 3, 4], [3, 3, 3], &values);
+    assert_eq!(other.get(3, 4, 5), (2, 255));
+    assert_eq!(other.voxels().len(), 1);
+
+    assert_eq!(chunk.replace_material(2, 3), 30);
+    assert_eq!(chunk.get(2, 3, 5), (3, 255));
+    assert_eq!(chunk.replace_material(2, 3), 0);
+  }
 }
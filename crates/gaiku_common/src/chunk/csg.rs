@@ -0,0 +1,210 @@
+use crate::{
+  boxify::*,
+  chunk::{Chunkify, ChunkifyMut},
+  voxel::Voxel,
+};
+
+/// Boolean operation used to combine a chunk into another one.
+///
+/// The densities are combined as a field, so partially filled voxels keep a smooth surface:
+/// union takes the highest density, intersection the lowest and subtraction removes the
+/// density of the other chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Csg {
+  /// Adds the other chunk, the material comes from the densest voxel.
+  Union,
+  /// Carves the other chunk out, the material is kept.
+  Subtract,
+  /// Keeps only what is inside both chunks, the material is kept.
+  Intersect,
+  /// Overwrites the overlapping voxels with the ones of the other chunk, air included.
+  Replace,
+}
+
+impl Csg {
+  fn combine<V>(self, voxel: V, other: V) -> V
+  where
+    V: Voxel,
+  {
+    let (material, density) = match self {
+      Csg::Union => {
+        if other.is_empty() || voxel.density() > other.density() {
+          return voxel;
+        }
+
+        (other.material(), other.density())
+      }
+      Csg::Subtract => (voxel.material(), voxel.density().min(1.0 - other.density())),
+      Csg::Intersect => (voxel.material(), voxel.density().min(other.density())),
+      Csg::Replace => return other,
+    };
+
+    if density <= 0.0 {
+      V::default()
+    } else {
+      V::new(material, density)
+    }
+  }
+}
+
+/// Boolean operations between chunks, the chunks are aligned with their `position()` (in voxel
+/// units) and can overlap partially, the chunk being modified doesn't grow.
+///
+/// Implemented for every chunk that can be read, written and positioned.
+pub trait ChunkifyCsg<T>: Chunkify<T> + ChunkifyMut<T> + Boxify
+where
+  T: Voxel,
+{
+  /// Combines `other` into this chunk with `operation`.
+  fn csg<C>(&mut self, other: &C, operation: Csg)
+  where
+    C: Chunkify<T> + Boxify,
+  {
+    let [sx, sy, sz] = self.position();
+    let [ox, oy, oz] = other.position();
+    let offset = [
+      (ox - sx).round() as isize,
+      (oy - sy).round() as isize,
+      (oz - sz).round() as isize,
+    ];
+    let size = [
+      self.width() as isize,
+      self.height() as isize,
+      self.depth() as isize,
+    ];
+    let other_size = [
+      other.width() as isize,
+      other.height() as isize,
+      other.depth() as isize,
+    ];
+
+    // The intersection also empties everything outside the other chunk
+    let (start, end) = if operation == Csg::Intersect {
+      ([0; 3], size)
+    } else {
+      let mut start = [0; 3];
+      let mut end = [0; 3];
+      for axis in 0..3 {
+        start[axis] = offset[axis].max(0);
+        end[axis] = (offset[axis] + other_size[axis]).min(size[axis]);
+      }
+
+      (start, end)
+    };
+
+    for z in start[2]..end[2] {
+      for y in start[1]..end[1] {
+        for x in start[0]..end[0] {
+          let [ox, oy, oz] = [x - offset[0], y - offset[1], z - offset[2]];
+          let inside = (0..other_size[0]).contains(&ox)
+            && (0..other_size[1]).contains(&oy)
+            && (0..other_size[2]).contains(&oz);
+          let other = if inside {
+            other.get(ox, oy, oz)
+          } else {
+            T::default()
+          };
+
+          let voxel = self.get(x, y, z);
+          let value = operation.combine(voxel, other);
+
+          if value != voxel {
+            self.set(x as usize, y as usize, z as usize, value);
+          }
+        }
+      }
+    }
+  }
+
+  /// Adds `other` to this chunk.
+  fn union<C>(&mut self, other: &C)
+  where
+    C: Chunkify<T> + Boxify,
+  {
+    self.csg(other, Csg::Union);
+  }
+
+  /// Removes `other` from this chunk.
+  fn subtract<C>(&mut self, other: &C)
+  where
+    C: Chunkify<T> + Boxify,
+  {
+    self.csg(other, Csg::Subtract);
+  }
+
+  /// Keeps only the parts of this chunk inside `other`.
+  fn intersect<C>(&mut self, other: &C)
+  where
+    C: Chunkify<T> + Boxify,
+  {
+    self.csg(other, Csg::Intersect);
+  }
+
+  /// Overwrites this chunk with `other` where they overlap.
+  fn replace<C>(&mut self, other: &C)
+  where
+    C: Chunkify<T> + Boxify,
+  {
+    self.csg(other, Csg::Replace);
+  }
+}
+
+impl<T, C> ChunkifyCsg<T> for C
+where
+  T: Voxel,
+  C: Chunkify<T> + ChunkifyMut<T> + Boxify,
+{
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::chunk::{Chunk, ChunkifyRegion, SparseChunk};
+
+  fn solid(position: [f32; 3], size: u16, value: (u8, u8)) -> Chunk {
+    let mut chunk = Chunk::new(position, size, size, size);
+    chunk.fill([0, 0, 0], [size as usize; 3], value);
+
+    chunk
+  }
+
+  #[test]
+  fn check_union_and_subtract() {
+    let mut chunk = SparseChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    chunk.set(0, 0, 0, (1, 200));
+
+    // Only the 2x2x2 corner overlaps
+    chunk.union(&solid([2.0, 2.0, 2.0], 4, (2, 100)));
+    assert_eq!(chunk.len(), 9);
+    assert_eq!(chunk.get(3, 3, 3), (2, 100));
+    assert_eq!(chunk.get(0, 0, 0), (1, 200));
+
+    chunk.union(&solid([-1.0, -1.0, -1.0], 2, (3, 255)));
+    assert_eq!(chunk.get(0, 0, 0), (3, 255));
+
+    // Partial densities leave a partial density
+    chunk.subtract(&solid([0.0, 0.0, 0.0], 1, (0, 55)));
+    assert_eq!(chunk.get(0, 0, 0), (3, 200));
+
+    chunk.subtract(&solid([3.0, 3.0, 3.0], 4, (0, 255)));
+    assert!(chunk.is_air(3, 3, 3));
+    assert_eq!(chunk.len(), 8);
+  }
+
+  #[test]
+  fn check_intersect_and_replace() {
+    let mut chunk = solid([0.0, 0.0, 0.0], 4, (1, 255));
+
+    chunk.intersect(&solid([2.0, 0.0, 0.0], 4, (2, 100)));
+    assert!(chunk.is_air(1, 0, 0));
+    assert_eq!(chunk.get(2, 0, 0), (1, 100));
+    assert_eq!(chunk.get(3, 3, 3), (1, 100));
+
+    let mut stamp = Chunk::new([3.0, 0.0, 0.0], 2, 1, 1);
+    stamp.set(1, 0, 0, (4, 255));
+
+    chunk.replace(&stamp);
+    assert!(chunk.is_air(3, 0, 0));
+    assert_eq!(chunk.get(3, 1, 0), (1, 100));
+  }
+}
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+
+use crate::{boxify::*, chunk::Chunkify, mesh::Meshify, voxel::Voxel, Result};
+
+/// Rule used to reduce a block of voxels into a single voxel when downsampling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+  /// The voxel is solid when at least half of the block is solid, with the most common material
+  /// and the average density of the solid voxels, meant for cubical voxel data.
+  Majority,
+  /// Average density of the block with the most common material, placed at the center of the
+  /// block, meant for smooth density data like heightmaps. With a low isolevel (like the
+  /// default one) a block with a few solid voxels is inside, so the surfaces grow.
+  AverageDensity,
+  /// Highest density of the block with the most common material, placed at the center of the
+  /// block, keeps thin features alive.
+  MaxDensity,
+  /// First voxel of the block, so the samples of every level lie on the samples of the full
+  /// resolution chunk and keep their density, meant for the isosurface bakers and stitching
+  /// meshes of different levels of detail.
+  Subsample,
+}
+
+impl Reduction {
+  fn reduce<V>(self, voxels: &[V]) -> V
+  where
+    V: Voxel,
+  {
+    // A block of a single voxel keeps it as it is with every rule
+    if self == Reduction::Subsample || voxels.len() == 1 {
+      return voxels.first().copied().unwrap_or_default();
+    }
+
+    let solid = voxels.iter().filter(|voxel| !voxel.is_empty());
+    let count = solid.clone().count();
+
+    if count == 0 {
+      return V::default();
+    }
+
+    let mut materials = HashMap::new();
+    for voxel in solid.clone() {
+      *materials.entry(voxel.material()).or_insert(0) += 1;
+    }
+
+    // Ties are resolved by the lowest material so the result doesn't depend on the hash order
+    let (material, _) = materials
+      .into_iter()
+      .max_by_key(|(material, count)| (*count, std::cmp::Reverse(*material)))
+      .unwrap();
+
+    let density = match self {
+      Reduction::Majority => {
+        if count * 2 < voxels.len() {
+          return V::default();
+        }
+
+        solid.map(|voxel| voxel.density()).sum::<f32>() / count as f32
+      }
+      Reduction::AverageDensity => {
+        voxels.iter().map(|voxel| voxel.density()).sum::<f32>() / voxels.len() as f32
+      }
+      Reduction::MaxDensity => solid.map(|voxel| voxel.density()).fold(0.0, f32::max),
+      Reduction::Subsample => unreachable!(),
+    };
+
+    V::new(material, density)
+  }
+}
+
+/// Bakes `chunk` with the `level_of_detail` of `BakerOptions` (`1` is the full resolution), `bake`
+/// gets the chunk reduced with `reduction` and its mesh is mapped back to `chunk` with
+/// `DownsampledChunk::scale_mesh` and `axes`.
+pub fn bake_with_lod<V, C, M, F>(
+  chunk: &C,
+  level_of_detail: usize,
+  reduction: Reduction,
+  axes: [Option<usize>; 3],
+  bake: F,
+) -> Result<Option<M>>
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+  M: Meshify,
+  F: FnOnce(&DownsampledChunk<V>) -> Result<Option<M>>,
+{
+  let chunk = DownsampledChunk::new(chunk, level_of_detail.saturating_sub(1), reduction);
+  let mesh = bake(&chunk)?;
+
+  if chunk.step == 1 {
+    Ok(mesh)
+  } else {
+    Ok(mesh.map(|mesh| chunk.scale_mesh(mesh, axes)))
+  }
+}
+
+/// A reduced resolution copy of a chunk, every voxel is made from a block of `2^levels` voxels
+/// per axis of the source chunk, including the one voxel border with the neighbor data so the
+/// bakers can keep reading it.
+///
+/// The last block of an axis is smaller when the source size isn't a multiple of the block,
+/// `scale_mesh` maps the meshes through the position of each sample so they end at the source
+/// chunk border anyway.
+#[derive(Debug, Clone)]
+pub struct DownsampledChunk<V> {
+  width: u16,
+  height: u16,
+  depth: u16,
+  source: [u16; 3],
+  step: usize,
+  reduction: Reduction,
+  values: Vec<V>,
+}
+
+impl<V> DownsampledChunk<V>
+where
+  V: Voxel,
+{
+  /// Downsamples `chunk` by `levels` halvings (`0` keeps the resolution), once a block covers
+  /// the whole chunk the extra levels give the same single voxel.
+  pub fn new<C>(chunk: &C, levels: usize, reduction: Reduction) -> Self
+  where
+    C: Chunkify<V> + Sizable,
+  {
+    let largest = chunk.width().max(chunk.height()).max(chunk.depth()).max(1) as usize;
+    let step = 1 << levels.min(largest.next_power_of_two().trailing_zeros() as usize);
+    let source = [
+      chunk.width() as isize,
+      chunk.height() as isize,
+      chunk.depth() as isize,
+    ];
+    let size = [
+      (chunk.width() as usize).div_ceil(step),
+      (chunk.height() as usize).div_ceil(step),
+      (chunk.depth() as usize).div_ceil(step),
+    ];
+
+    // The range of source voxels reduced into the voxel at `index` of `axis`, the border
+    // voxels only have the source neighbor data
+    let block = |axis: usize, index: isize| {
+      if index < 0 {
+        -1..0
+      } else if index >= size[axis] as isize {
+        source[axis]..source[axis] + 1
+      } else {
+        let start = index * step as isize;
+        start..(start + step as isize).min(source[axis])
+      }
+    };
+
+    let mut values = Vec::with_capacity((size[0] + 2) * (size[1] + 2) * (size[2] + 2));
+    let mut voxels = Vec::with_capacity(step * step * step);
+
+    for z in -1..=size[2] as isize {
+      for y in -1..=size[1] as isize {
+        for x in -1..=size[0] as isize {
+          voxels.clear();
+
+          for sz in block(2, z) {
+            for sy in block(1, y) {
+              for sx in block(0, x) {
+                voxels.push(chunk.get(sx, sy, sz));
+              }
+            }
+          }
+
+          values.push(reduction.reduce(&voxels));
+        }
+      }
+    }
+
+    Self {
+      width: size[0] as u16,
+      height: size[1] as u16,
+      depth: size[2] as u16,
+      source: [chunk.width(), chunk.height(), chunk.depth()],
+      step,
+      reduction,
+      values,
+    }
+  }
+
+  /// Size of a voxel of this chunk in voxels of the source chunk.
+  pub fn scale(&self) -> f32 {
+    self.step as f32
+  }
+
+  /// Position in the source chunk of the voxel `index` of `axis`.
+  ///
+  /// The cubical `Majority` voxels start at the start of their block and `Subsample` takes the
+  /// first voxel of the block, the density reductions are placed at the center of the block.
+  /// The border voxels are the neighbor data right outside the source chunk.
+  fn sample_position(&self, axis: usize, index: isize) -> f32 {
+    let size = [self.width, self.height, self.depth][axis] as isize;
+    let source = self.source[axis] as isize;
+    let step = self.step as isize;
+
+    if index < 0 {
+      return index as f32;
+    } else if index >= size {
+      return (source + index - size) as f32;
+    }
+
+    let start = index * step;
+    match self.reduction {
+      Reduction::Majority | Reduction::Subsample => start as f32,
+      Reduction::AverageDensity | Reduction::MaxDensity => {
+        (start + (start + step).min(source) - 1) as f32 / 2.0
+      }
+    }
+  }
+
+  /// Maps the mesh baked from this chunk back to the source chunk, `axes` has the chunk axis
+  /// of each mesh axis, `None` keeps the mesh axis as it is.
+  ///
+  /// The positions between two voxels are interpolated between the positions of the voxels in
+  /// the source chunk.
+  pub fn scale_mesh<M>(&self, mesh: M, axes: [Option<usize>; 3]) -> M
+  where
+    M: Meshify,
+  {
+    let mut mesh = mesh;
+    let sizes = [self.width, self.height, self.depth];
+    let map = |axis: usize, value: f32| {
+      let index = (value.floor() as isize).clamp(-1, sizes[axis] as isize);
+      let start = self.sample_position(axis, index);
+      let end = self.sample_position(axis, index + 1);
+
+      start + (end - start) * (value - index as f32)
+    };
+
+    let positions = mesh
+      .get_positions()
+      .iter()
+      .map(|position| {
+        let mut result = *position;
+        for (value, axis) in result.iter_mut().zip(axes.iter()) {
+          if let Some(axis) = axis {
+            *value = map(*axis, *value);
+          }
+        }
+
+        result
+      })
+      .collect();
+    mesh.set_positions(positions);
+
+    mesh
+  }
+
+  fn index(&self, x: isize, y: isize, z: isize) -> Option<usize> {
+    let (width, height, depth) = (
+      self.width as isize,
+      self.height as isize,
+      self.depth as isize,
+    );
+
+    if x < -1 || y < -1 || z < -1 || x > width || y > height || z > depth {
+      None
+    } else {
+      Some(((x + 1) + (y + 1) * (width + 2) + (z + 1) * (width + 2) * (height + 2)) as usize)
+    }
+  }
+}
+
+impl<V> Chunkify<V> for DownsampledChunk<V>
+where
+  V: Voxel,
+{
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+    self.get(x, y, z).is_empty()
+  }
+
+  fn get(&self, x: isize, y: isize, z: isize) -> V {
+    if let Some(index) = self.index(x, y, z) {
+      self.values[index]
+    } else {
+      V::default()
+    }
+  }
+}
+
+impl<V> Sizable for DownsampledChunk<V>
+where
+  V: Voxel,
+{
+  fn with_size(width: u16, height: u16, depth: u16) -> Self {
+    Self {
+      width,
+      height,
+      depth,
+      source: [width, height, depth],
+      step: 1,
+      reduction: Reduction::Subsample,
+      values: vec![
+        V::default();
+        (width as usize + 2) * (height as usize + 2) * (depth as usize + 2)
+      ],
+    }
+  }
+
+  fn depth(&self) -> u16 {
+    self.depth
+  }
+
+  fn height(&self) -> u16 {
+    self.height
+  }
+
+  fn width(&self) -> u16 {
+    self.width
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{
+    chunk::{Chunk, ChunkifyMut},
+    mesh::Mesh,
+  };
+
+  #[test]
+  fn check_size_and_rules() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 3);
+
+    // 3 of 8 voxels solid in the first block, all solid in the second block
+    chunk.set(0, 0, 0, (1, 255));
+    chunk.set(1, 0, 0, (1, 255));
+    chunk.set(0, 1, 0, (2, 255));
+    for x in 2..4 {
+      for y in 0..2 {
+        for z in 0..2 {
+          chunk.set(x, y, z, (3, 255));
+        }
+      }
+    }
+    chunk.set(0, 0, 2, (4, 100));
+
+    let majority = DownsampledChunk::new(&chunk, 1, Reduction::Majority);
+    assert_eq!(
+      (majority.width(), majority.height(), majority.depth()),
+      (2, 2, 2)
+    );
+    assert_eq!(majority.scale(), 2.0);
+    assert_eq!(majority.get(0, 0, 0), (0, 0));
+    assert_eq!(majority.get(1, 0, 0), (3, 255));
+    // The last block only has half of the voxels
+    assert_eq!(majority.get(0, 0, 1), (0, 0));
+
+    let average = DownsampledChunk::new(&chunk, 1, Reduction::AverageDensity);
+    assert_eq!(average.get(0, 0, 0), (1, 96));
+    assert_eq!(average.get(0, 0, 1), (4, 25));
+
+    let max = DownsampledChunk::new(&chunk, 1, Reduction::MaxDensity);
+    assert_eq!(max.get(0, 0, 0), (1, 255));
+    assert_eq!(max.get(0, 0, 1), (4, 100));
+
+    let subsample = DownsampledChunk::new(&chunk, 1, Reduction::Subsample);
+    assert_eq!(subsample.get(0, 0, 0), (1, 255));
+    assert_eq!(subsample.get(0, 1, 0), (0, 0));
+    assert_eq!(subsample.get(0, 0, 1), (4, 100));
+  }
+
+  #[test]
+  fn check_levels() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 5, 3, 2);
+    chunk.set(0, 0, 0, (1, 255));
+    chunk.set(4, 2, 1, (2, 0));
+
+    // Every level past the chunk size is a single voxel
+    for levels in [3, 4, 64, 1000, usize::MAX] {
+      let reduced = DownsampledChunk::new(&chunk, levels, Reduction::Majority);
+
+      assert_eq!(
+        (reduced.width(), reduced.height(), reduced.depth()),
+        (1, 1, 1)
+      );
+      assert_eq!(reduced.scale(), 8.0);
+    }
+
+    // The full resolution keeps every voxel
+    let mesh = bake_with_lod(&chunk, 1, Reduction::Majority, [None; 3], |reduced| {
+      for (x, y, z) in [(0, 0, 0), (4, 2, 1), (5, 0, 0), (-1, 0, 0)] {
+        assert_eq!(reduced.get(x, y, z), chunk.get(x, y, z));
+      }
+
+      Ok(Some(Mesh::new()))
+    })
+    .unwrap();
+    assert!(mesh.is_some());
+  }
+
+  #[test]
+  fn check_neighbor_data() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut neighbor = Chunk::new([4.0, 0.0, 0.0], 4, 4, 4);
+    neighbor.set(0, 0, 0, (5, 255));
+    neighbor.set(0, 1, 0, (5, 255));
+    neighbor.set(0, 0, 1, (5, 255));
+    chunk.update_neighbor_data(&neighbor);
+
+    let reduced = DownsampledChunk::new(&chunk, 1, Reduction::Majority);
+    assert_eq!(reduced.get(2, 0, 0), (5, 255));
+    assert!(reduced.is_air(2, 1, 0));
+    assert!(reduced.is_air(-1, 0, 0));
+    assert!(reduced.is_air(3, 0, 0));
+  }
+
+  #[test]
+  fn check_scale_mesh() {
+    let chunk = Chunk::new([0.0, 0.0, 0.0], 5, 4, 4);
+    let mesh = || {
+      let mut mesh = Mesh::new();
+      mesh.set_positions(vec![[0.0, 1.0, -0.5], [2.5, 2.0, 2.0], [3.0, 1.5, 1.0]]);
+      mesh
+    };
+
+    // The last block of `x` only has one voxel, the voxel past it is the neighbor data at `5`
+    let subsample = DownsampledChunk::new(&chunk, 1, Reduction::Subsample);
+    assert_eq!(subsample.width(), 3);
+    assert_eq!(
+      subsample
+        .scale_mesh(mesh(), [Some(0), Some(1), Some(2)])
+        .get_positions(),
+      &vec![[0.0, 2.0, -0.5], [4.5, 4.0, 4.0], [5.0, 3.0, 2.0]]
+    );
+
+    // The density reductions are at the center of their block
+    let average = DownsampledChunk::new(&chunk, 1, Reduction::AverageDensity);
+    assert_eq!(
+      average
+        .scale_mesh(mesh(), [Some(0), None, Some(1)])
+        .get_positions(),
+      &vec![[0.5, 1.0, -0.25], [4.5, 2.0, 4.0], [5.0, 1.5, 2.5]]
+    );
+  }
+}
@@ -0,0 +1,135 @@
+use crate::{boxify::*, chunk::Chunkify};
+
+/// Copy of the voxels of the neighbors in the one voxel border around a chunk, shared by the
+/// chunk implementations so `get` and `is_air` can read the coordinates `-1` and
+/// `width`/`height`/`depth` without querying the neighbor chunks each time.
+///
+/// The buffer is laid out as the two `x` faces (with edges and corners), followed by the two `y`
+/// faces (with the remaining edges) and the two `z` faces, it's empty until the first neighbor
+/// is copied.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NeighborData {
+  values: Vec<(u8, u8)>,
+}
+
+impl NeighborData {
+  /// Maps a coordinate of the border around a chunk of `size` into the buffer.
+  fn index([width, height, depth]: [isize; 3], x: isize, y: isize, z: isize) -> Option<usize> {
+    if x < -1 || y < -1 || z < -1 || x > width || y > height || z > depth {
+      return None;
+    }
+
+    let x_face = (height + 2) * (depth + 2);
+    let y_face = width * (depth + 2);
+    let z_face = width * height;
+
+    let index = if x == -1 || x == width {
+      let side = if x == -1 { 0 } else { 1 };
+      side * x_face + (y + 1) + (z + 1) * (height + 2)
+    } else if y == -1 || y == height {
+      let side = if y == -1 { 0 } else { 1 };
+      2 * x_face + side * y_face + x + (z + 1) * width
+    } else if z == -1 || z == depth {
+      let side = if z == -1 { 0 } else { 1 };
+      2 * x_face + 2 * y_face + side * z_face + x + y * width
+    } else {
+      return None;
+    };
+
+    Some(index as usize)
+  }
+
+  fn len([width, height, depth]: [isize; 3]) -> usize {
+    ((width + 2) * (height + 2) * (depth + 2) - width * height * depth) as usize
+  }
+
+  /// Value of the border voxel at `[x, y, z]` of a chunk of `size`, air if no neighbor covers
+  /// it, `None` if the coordinate isn't part of the border.
+  pub fn get(&self, size: [isize; 3], x: isize, y: isize, z: isize) -> Option<(u8, u8)> {
+    Self::index(size, x, y, z).map(|index| *self.values.get(index).unwrap_or(&(0, 0)))
+  }
+
+  /// Copies the voxels of `neighbor` that touch the chunk at `position` of `size`, both
+  /// positions are expected to be in voxel units, chunks that doesn't touch it are ignored.
+  /// Returns `true` if any value changed.
+  pub fn update<C>(&mut self, position: [f32; 3], size: [isize; 3], neighbor: &C) -> bool
+  where
+    C: Chunkify<(u8, u8)> + Positionable + Sizable,
+  {
+    let neighbor_position = neighbor.position();
+    let offset = [0, 1, 2].map(|axis| (neighbor_position[axis] - position[axis]).round() as isize);
+    let neighbor_size = size_of(neighbor);
+
+    // Range of the border (in the chunk coordinates) covered by the neighbor
+    let mut start = [0; 3];
+    let mut end = [0; 3];
+    for axis in 0..3 {
+      start[axis] = offset[axis].max(-1);
+      end[axis] = (offset[axis] + neighbor_size[axis]).min(size[axis] + 1);
+
+      if start[axis] >= end[axis] {
+        return false;
+      }
+    }
+
+    if self.values.is_empty() {
+      self.values = vec![(0, 0); Self::len(size)];
+    }
+
+    let mut changed = false;
+    for x in start[0]..end[0] {
+      for y in start[1]..end[1] {
+        for z in start[2]..end[2] {
+          if let Some(index) = Self::index(size, x, y, z) {
+            let value = neighbor.get(x - offset[0], y - offset[1], z - offset[2]);
+            changed |= self.values[index] != value;
+            self.values[index] = value;
+          }
+        }
+      }
+    }
+
+    changed
+  }
+}
+
+/// Size of `chunk` as signed coordinates.
+pub(crate) fn size_of<C>(chunk: &C) -> [isize; 3]
+where
+  C: Sizable + ?Sized,
+{
+  [
+    chunk.width() as isize,
+    chunk.height() as isize,
+    chunk.depth() as isize,
+  ]
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn check_index() {
+    let size = [4, 5, 6];
+    let mut indices = vec![];
+
+    for x in -1..=4 {
+      for y in -1..=5 {
+        for z in -1..=6 {
+          if let Some(index) = NeighborData::index(size, x, y, z) {
+            indices.push(index);
+          }
+        }
+      }
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+
+    assert_eq!(indices.len(), NeighborData::len(size));
+    assert_eq!(indices.last(), Some(&(NeighborData::len(size) - 1)));
+    assert_eq!(NeighborData::index(size, 1, 1, 1), None);
+    assert_eq!(NeighborData::index(size, -2, 0, 0), None);
+  }
+}
@@ -0,0 +1,388 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Range;
+
+use crate::{
+  boxify::*,
+  chunk::{
+    clip_region,
+    neighbors::{size_of, NeighborData},
+    Chunkify, ChunkifyMut, ChunkifyRegion,
+  },
+};
+
+/// Node of an `OctreeChunk`, a leaf holds the value of every voxel of its cube.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum OctreeNode {
+  Leaf((u8, u8)),
+  Branch(Box<[OctreeNode; 8]>),
+}
+
+impl OctreeNode {
+  /// Index of the child containing the position, relative to the node origin.
+  fn child(x: usize, y: usize, z: usize, half: usize) -> usize {
+    (x >= half) as usize | ((y >= half) as usize) << 1 | ((z >= half) as usize) << 2
+  }
+
+  fn child_origin(origin: [usize; 3], index: usize, half: usize) -> [usize; 3] {
+    [
+      origin[0] + (index & 1) * half,
+      origin[1] + ((index >> 1) & 1) * half,
+      origin[2] + ((index >> 2) & 1) * half,
+    ]
+  }
+
+  /// Splits a leaf into 8 leaves with its value.
+  fn split(&mut self) -> &mut [OctreeNode; 8] {
+    if let OctreeNode::Leaf(value) = *self {
+      *self = OctreeNode::Branch(Box::new(std::array::from_fn(|_| OctreeNode::Leaf(value))));
+    }
+
+    match self {
+      OctreeNode::Branch(children) => children,
+      OctreeNode::Leaf(_) => unreachable!(),
+    }
+  }
+
+  /// Turns the branch into a leaf if all its children are the same leaf.
+  fn collapse(&mut self) {
+    if let OctreeNode::Branch(children) = self {
+      if let OctreeNode::Leaf(value) = children[0] {
+        if children
+          .iter()
+          .all(|child| *child == OctreeNode::Leaf(value))
+        {
+          *self = OctreeNode::Leaf(value);
+        }
+      }
+    }
+  }
+
+  fn get(&self, x: usize, y: usize, z: usize, size: usize) -> (u8, u8) {
+    match self {
+      OctreeNode::Leaf(value) => *value,
+      OctreeNode::Branch(children) => {
+        let half = size / 2;
+        children[Self::child(x, y, z, half)].get(x % half, y % half, z % half, half)
+      }
+    }
+  }
+
+  fn set(&mut self, x: usize, y: usize, z: usize, size: usize, value: (u8, u8)) {
+    if *self == OctreeNode::Leaf(value) {
+      return;
+    }
+
+    if size == 1 {
+      *self = OctreeNode::Leaf(value);
+      return;
+    }
+
+    let half = size / 2;
+    self.split()[Self::child(x, y, z, half)].set(x % half, y % half, z % half, half, value);
+    self.collapse();
+  }
+
+  fn fill(&mut self, origin: [usize; 3], size: usize, region: &[Range<usize>; 3], value: (u8, u8)) {
+    let mut inside = true;
+    for axis in 0..3 {
+      let end = origin[axis] + size;
+      if region[axis].end <= origin[axis] || region[axis].start >= end {
+        return;
+      }
+
+      inside &= region[axis].start <= origin[axis] && end <= region[axis].end;
+    }
+
+    if inside {
+      *self = OctreeNode::Leaf(value);
+      return;
+    }
+
+    let half = size / 2;
+    for (index, child) in self.split().iter_mut().enumerate() {
+      child.fill(Self::child_origin(origin, index, half), half, region, value);
+    }
+    self.collapse();
+  }
+
+  fn visit<F>(&self, origin: [usize; 3], size: usize, visitor: &mut F)
+  where
+    F: FnMut([usize; 3], usize, Option<(u8, u8)>) -> bool,
+  {
+    match self {
+      OctreeNode::Leaf(value) => {
+        visitor(origin, size, Some(*value));
+      }
+      OctreeNode::Branch(children) => {
+        if visitor(origin, size, None) {
+          let half = size / 2;
+          for (index, child) in children.iter().enumerate() {
+            child.visit(Self::child_origin(origin, index, half), half, visitor);
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Provides a `Chunkify` implementation with a sparse voxel octree, the uniform regions are
+/// collapsed into a single node, so big chunks of mostly air or rock use little memory.
+///
+/// The octree is a cube with the next power of two of the biggest chunk dimension as size.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OctreeChunk {
+  position: [f32; 3],
+  width: u16,
+  height: u16,
+  depth: u16,
+  size: usize,
+  root: OctreeNode,
+  #[cfg_attr(feature = "serde", serde(skip))]
+  neighbors: NeighborData,
+}
+
+impl OctreeChunk {
+  /// Size of the cube covered by the root node.
+  pub fn size(&self) -> usize {
+    self.size
+  }
+
+  /// Number of nodes (branches and leaves) in the octree.
+  pub fn node_count(&self) -> usize {
+    let mut count = 0;
+    self.visit(|_, _, _| {
+      count += 1;
+      true
+    });
+
+    count
+  }
+
+  /// Walks the octree depth first calling `visitor` with the origin, size and value of every
+  /// node, the value is `None` for the branches and the children of a branch are only visited
+  /// if `visitor` returns `true`.
+  ///
+  /// The leaves are uniform cubes, so a baker can skip them when fully empty or solid, the cubes
+  /// can reach outside the chunk size.
+  pub fn visit<F>(&self, mut visitor: F)
+  where
+    F: FnMut([usize; 3], usize, Option<(u8, u8)>) -> bool,
+  {
+    self.root.visit([0, 0, 0], self.size, &mut visitor);
+  }
+
+  /// Returns the origin, size and value of every leaf.
+  pub fn leaves(&self) -> Vec<([usize; 3], usize, (u8, u8))> {
+    let mut result = vec![];
+    self.visit(|origin, size, value| {
+      if let Some(value) = value {
+        result.push((origin, size, value));
+      }
+      true
+    });
+
+    result
+  }
+}
+
+impl Boxify for OctreeChunk {
+  fn new(position: [f32; 3], width: u16, height: u16, depth: u16) -> Self {
+    Self {
+      position,
+      width,
+      height,
+      depth,
+      size: (width.max(height).max(depth).max(1) as usize).next_power_of_two(),
+      root: OctreeNode::Leaf((0, 0)),
+      neighbors: NeighborData::default(),
+    }
+  }
+
+  fn update_neighbor_data(&mut self, neighbor: &Self) -> bool {
+    let size = size_of(self);
+    self.neighbors.update(self.position, size, neighbor)
+  }
+}
+
+impl Chunkify<(u8, u8)> for OctreeChunk {
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+    self.get(x, y, z).1 == 0
+  }
+
+  fn get(&self, x: isize, y: isize, z: isize) -> (u8, u8) {
+    if x < 0
+      || y < 0
+      || z < 0
+      || x >= self.width as isize
+      || y >= self.height as isize
+      || z >= self.depth as isize
+    {
+      self.neighbors.get(size_of(self), x, y, z).unwrap_or((0, 0))
+    } else {
+      self.root.get(x as usize, y as usize, z as usize, self.size)
+    }
+  }
+}
+
+impl ChunkifyMut<(u8, u8)> for OctreeChunk {
+  fn set(&mut self, x: usize, y: usize, z: usize, value: (u8, u8)) {
+    // The padding up to the power of two size would wrap into another voxel
+    assert!(
+      x < self.width as usize && y < self.height as usize && z < self.depth as usize,
+      "Voxel [{}, {}, {}] is outside of the {}x{}x{} chunk",
+      x,
+      y,
+      z,
+      self.width,
+      self.height,
+      self.depth
+    );
+
+    self.root.set(x, y, z, self.size, value);
+  }
+}
+
+impl ChunkifyRegion<(u8, u8)> for OctreeChunk {
+  fn fill(&mut self, start: [usize; 3], size: [usize; 3], value: (u8, u8)) {
+    let region = clip_region(self, start, size);
+    self.root.fill([0, 0, 0], self.size, &region, value);
+  }
+
+  fn voxels(&self) -> Vec<([usize; 3], (u8, u8))> {
+    let mut result = vec![];
+
+    for (origin, size, value) in self.leaves() {
+      if value.1 == 0 {
+        continue;
+      }
+
+      let region = clip_region(self, origin, [size; 3]);
+      for z in region[2].clone() {
+        for y in region[1].clone() {
+          for x in region[0].clone() {
+            result.push(([x, y, z], value));
+          }
+        }
+      }
+    }
+
+    result.sort_by_key(|([x, y, z], _)| (*z, *y, *x));
+
+    result
+  }
+}
+
+impl Positionable for OctreeChunk {
+  fn with_position(position: [f32; 3]) -> Self {
+    Self::new(position, 16, 16, 16)
+  }
+
+  fn position(&self) -> [f32; 3] {
+    self.position
+  }
+}
+
+impl Sizable for OctreeChunk {
+  fn with_size(width: u16, height: u16, depth: u16) -> Self {
+    Self::new([0.0, 0.0, 0.0], width, height, depth)
+  }
+
+  fn depth(&self) -> u16 {
+    self.depth
+  }
+
+  fn height(&self) -> u16 {
+    self.height
+  }
+
+  fn width(&self) -> u16 {
+    self.width
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn check_set_get_collapse() {
+    let mut chunk = OctreeChunk::new([0.0, 0.0, 0.0], 4, 4, 2);
+    assert_eq!(chunk.size(), 4);
+    assert_eq!(chunk.node_count(), 1);
+
+    chunk.set(1, 2, 1, (3, 255));
+    assert_eq!(chunk.get(1, 2, 1), (3, 255));
+    assert!(chunk.is_air(1, 1, 1));
+    assert!(chunk.is_air(-1, 0, 0));
+    assert_eq!(chunk.node_count(), 17);
+
+    for x in 0..2 {
+      for y in 2..4 {
+        for z in 0..2 {
+          chunk.set(x, y, z, (3, 255));
+        }
+      }
+    }
+    assert_eq!(chunk.node_count(), 9);
+
+    chunk.set(1, 2, 1, (0, 0));
+    chunk.set(0, 2, 0, (0, 0));
+    chunk.fill([0, 0, 0], [4, 4, 4], (0, 0));
+    assert_eq!(chunk.node_count(), 1);
+  }
+
+  #[test]
+  #[should_panic]
+  fn check_set_outside() {
+    let mut chunk = OctreeChunk::new([0.0, 0.0, 0.0], 4, 4, 2);
+
+    // Inside the power of two padding of `z`
+    chunk.set(0, 0, 2, (1, 255));
+  }
+
+  #[test]
+  fn check_neighbor_data() {
+    let mut chunk = OctreeChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut left = OctreeChunk::new([-4.0, 0.0, 0.0], 4, 4, 4);
+
+    left.fill([0, 0, 0], [4, 4, 4], (5, 255));
+    chunk.update_neighbor_data(&left);
+
+    assert_eq!(chunk.get(-1, 3, 0), (5, 255));
+    assert!(chunk.is_air(-1, 4, 0));
+    assert_eq!(chunk.node_count(), 1);
+  }
+
+  #[test]
+  fn check_region_and_visit() {
+    let mut chunk = OctreeChunk::new([0.0, 0.0, 0.0], 64, 64, 64);
+
+    chunk.fill([0, 0, 0], [64, 32, 64], (1, 255));
+    chunk.set(10, 50, 10, (2, 255));
+    assert_eq!(chunk.get(63, 31, 0), (1, 255));
+    assert!(chunk.is_air(0, 32, 0));
+    assert_eq!(chunk.voxels().len(), 64 * 32 * 64 + 1);
+
+    // Only the branches over the single voxel are visited below the root children
+    let mut solid = 0;
+    chunk.visit(|_, size, value| {
+      if value.map(|value| value.1 != 0).unwrap_or(false) {
+        solid += size * size * size;
+      }
+      true
+    });
+    assert_eq!(solid, 64 * 32 * 64 + 1);
+    assert_eq!(chunk.leaves().len(), 8 + 5 * 7);
+
+    let mut visited = 0;
+    chunk.visit(|_, size, _| {
+      visited += 1;
+      size == 64
+    });
+    assert_eq!(visited, 9);
+  }
+}
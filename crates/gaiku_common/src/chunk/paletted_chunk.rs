@@ -0,0 +1,330 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  boxify::*,
+  chunk::{
+    neighbors::{size_of, NeighborData},
+    Chunkify, ChunkifyMut, ChunkifyRegion,
+  },
+};
+
+/// Provides a `Chunkify` implementation that stores every distinct `(u8, u8)` value once in a
+/// palette and keeps a bit-packed palette index per voxel, the bits per voxel grows and shrinks
+/// with the palette (0, 1, 2, 4, 8 or 16 bits).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PalettedChunk {
+  position: [f32; 3],
+  width: u16,
+  height: u16,
+  depth: u16,
+  palette: Vec<(u8, u8)>,
+  counts: Vec<u32>,
+  bits: u8,
+  data: Vec<u64>,
+  #[cfg_attr(feature = "serde", serde(skip))]
+  neighbors: NeighborData,
+}
+
+impl PalettedChunk {
+  fn index(&self, x: usize, y: usize, z: usize) -> usize {
+    x + y * self.width as usize + z * self.width as usize * self.height as usize
+  }
+
+  fn len(&self) -> usize {
+    self.width as usize * self.height as usize * self.depth as usize
+  }
+
+  /// Returns the bits needed to store `entries` different palette indices.
+  fn bits_for(entries: usize) -> u8 {
+    match entries {
+      0..=1 => 0,
+      2 => 1,
+      3..=4 => 2,
+      5..=16 => 4,
+      17..=256 => 8,
+      _ => 16,
+    }
+  }
+
+  fn read(&self, index: usize) -> usize {
+    if self.bits == 0 {
+      return 0;
+    }
+
+    let bits = self.bits as usize;
+    let per_word = 64 / bits;
+    let mask = (1u64 << bits) - 1;
+    let word = self.data[index / per_word];
+
+    ((word >> ((index % per_word) * bits)) & mask) as usize
+  }
+
+  fn write(&mut self, index: usize, value: usize) {
+    if self.bits == 0 {
+      return;
+    }
+
+    let bits = self.bits as usize;
+    let per_word = 64 / bits;
+    let mask = (1u64 << bits) - 1;
+    let shift = (index % per_word) * bits;
+    let word = &mut self.data[index / per_word];
+
+    *word = (*word & !(mask << shift)) | ((value as u64 & mask) << shift);
+  }
+
+  /// Packs `indices` again using `bits` per voxel.
+  fn repack(&mut self, bits: u8, indices: Vec<usize>) {
+    self.bits = bits;
+    self.data = if bits == 0 {
+      vec![]
+    } else {
+      let per_word = 64 / bits as usize;
+      vec![0; self.len().div_ceil(per_word)]
+    };
+
+    for (index, value) in indices.into_iter().enumerate() {
+      self.write(index, value);
+    }
+  }
+
+  fn indices(&self) -> Vec<usize> {
+    (0..self.len()).map(|index| self.read(index)).collect()
+  }
+
+  /// Returns the palette index of `value`, adding it to the palette (and growing the bits per
+  /// voxel) if needed.
+  fn palette_index(&mut self, value: (u8, u8)) -> usize {
+    if let Some(index) = self.palette.iter().position(|entry| *entry == value) {
+      return index;
+    }
+
+    if let Some(index) = self.counts.iter().position(|count| *count == 0) {
+      self.palette[index] = value;
+      return index;
+    }
+
+    self.palette.push(value);
+    self.counts.push(0);
+
+    let bits = Self::bits_for(self.palette.len());
+    if bits != self.bits {
+      let indices = self.indices();
+      self.repack(bits, indices);
+    }
+
+    self.palette.len() - 1
+  }
+
+  /// Removes the unused palette entries if that reduces the bits per voxel.
+  fn shrink(&mut self) {
+    let used = self.counts.iter().filter(|count| **count > 0).count();
+    let bits = Self::bits_for(used);
+
+    if bits >= self.bits {
+      return;
+    }
+
+    let mut remap = vec![0; self.palette.len()];
+    let mut palette = Vec::with_capacity(used);
+    let mut counts = Vec::with_capacity(used);
+
+    for (index, count) in self.counts.iter().enumerate() {
+      if *count > 0 {
+        remap[index] = palette.len();
+        palette.push(self.palette[index]);
+        counts.push(*count);
+      }
+    }
+
+    let indices = self
+      .indices()
+      .into_iter()
+      .map(|index| remap[index])
+      .collect();
+
+    self.palette = palette;
+    self.counts = counts;
+    self.repack(bits, indices);
+  }
+
+  /// The distinct values stored in the chunk, may contain unused entries.
+  pub fn palette(&self) -> &[(u8, u8)] {
+    &self.palette
+  }
+
+  /// Bits used to store each voxel palette index.
+  pub fn bits_per_voxel(&self) -> u8 {
+    self.bits
+  }
+
+  pub fn values(&self) -> Vec<(u8, u8)> {
+    (0..self.len())
+      .map(|index| self.palette[self.read(index)])
+      .collect()
+  }
+}
+
+impl Boxify for PalettedChunk {
+  fn new(position: [f32; 3], width: u16, height: u16, depth: u16) -> Self {
+    Self {
+      position,
+      width,
+      height,
+      depth,
+      palette: vec![(0, 0)],
+      counts: vec![depth as u32 * height as u32 * width as u32],
+      bits: 0,
+      data: vec![],
+      neighbors: NeighborData::default(),
+    }
+  }
+
+  fn update_neighbor_data(&mut self, neighbor: &Self) -> bool {
+    let size = size_of(self);
+    self.neighbors.update(self.position, size, neighbor)
+  }
+}
+
+impl Chunkify<(u8, u8)> for PalettedChunk {
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+    self.get(x, y, z).1 == 0
+  }
+
+  fn get(&self, x: isize, y: isize, z: isize) -> (u8, u8) {
+    if x < 0
+      || y < 0
+      || z < 0
+      || x >= self.width as isize
+      || y >= self.height as isize
+      || z >= self.depth as isize
+    {
+      self.neighbors.get(size_of(self), x, y, z).unwrap_or((0, 0))
+    } else {
+      self.palette[self.read(self.index(x as usize, y as usize, z as usize))]
+    }
+  }
+}
+
+impl ChunkifyMut<(u8, u8)> for PalettedChunk {
+  fn set(&mut self, x: usize, y: usize, z: usize, value: (u8, u8)) {
+    let index = self.index(x, y, z);
+    let old = self.read(index);
+
+    if self.palette[old] == value {
+      return;
+    }
+
+    let new = self.palette_index(value);
+    self.write(index, new);
+    self.counts[new] += 1;
+    self.counts[old] -= 1;
+
+    if self.counts[old] == 0 {
+      self.shrink();
+    }
+  }
+}
+
+impl ChunkifyRegion<(u8, u8)> for PalettedChunk {}
+
+impl Positionable for PalettedChunk {
+  fn with_position(position: [f32; 3]) -> Self {
+    Self::new(position, 16, 16, 16)
+  }
+
+  fn position(&self) -> [f32; 3] {
+    self.position
+  }
+}
+
+impl Sizable for PalettedChunk {
+  fn with_size(width: u16, height: u16, depth: u16) -> Self {
+    Self::new([0.0, 0.0, 0.0], width, height, depth)
+  }
+
+  fn depth(&self) -> u16 {
+    self.depth
+  }
+
+  fn height(&self) -> u16 {
+    self.height
+  }
+
+  fn width(&self) -> u16 {
+    self.width
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn check_set_get() {
+    let mut chunk = PalettedChunk::new([0.0, 0.0, 0.0], 4, 5, 6);
+
+    for x in 0..4 {
+      for y in 0..5 {
+        for z in 0..6 {
+          chunk.set(x, y, z, ((x + y) as u8, z as u8));
+        }
+      }
+    }
+
+    for x in 0..4 {
+      for y in 0..5 {
+        for z in 0..6 {
+          assert_eq!(
+            chunk.get(x as isize, y as isize, z as isize),
+            ((x + y) as u8, z as u8)
+          );
+        }
+      }
+    }
+
+    assert_eq!(chunk.get(-1, 0, 0), (0, 0));
+    assert!(chunk.is_air(4, 0, 0));
+  }
+
+  #[test]
+  fn check_neighbor_data() {
+    let mut chunk = PalettedChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut front = PalettedChunk::new([0.0, 0.0, 4.0], 4, 4, 4);
+
+    front.set(2, 1, 0, (3, 255));
+    chunk.update_neighbor_data(&front);
+
+    assert_eq!(chunk.get(2, 1, 4), (3, 255));
+    assert!(chunk.is_air(2, 1, 5));
+    assert_eq!(chunk.bits_per_voxel(), 0);
+  }
+
+  #[test]
+  fn check_bits_grow_and_shrink() {
+    let mut chunk = PalettedChunk::new([0.0, 0.0, 0.0], 16, 16, 16);
+    assert_eq!(chunk.bits_per_voxel(), 0);
+
+    chunk.set(0, 0, 0, (1, 255));
+    assert_eq!(chunk.bits_per_voxel(), 1);
+
+    for index in 0..20 {
+      chunk.set(index % 16, 1 + index / 16, 0, (index as u8, 255));
+    }
+    assert_eq!(chunk.bits_per_voxel(), 8);
+    assert_eq!(chunk.get(3, 2, 0), (19, 255));
+    assert_eq!(chunk.get(0, 0, 0), (1, 255));
+
+    for index in 0..20 {
+      chunk.set(index % 16, 1 + index / 16, 0, (0, 0));
+    }
+    assert_eq!(chunk.bits_per_voxel(), 1);
+    assert_eq!(chunk.get(0, 0, 0), (1, 255));
+
+    chunk.set(0, 0, 0, (0, 0));
+    assert_eq!(chunk.bits_per_voxel(), 0);
+    assert_eq!(chunk.palette(), &[(0, 0)]);
+  }
+}
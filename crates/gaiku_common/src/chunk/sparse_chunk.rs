@@ -5,38 +5,130 @@ use std::collections::HashMap;
 
 use crate::{
   boxify::*,
-  chunk::{Chunkify, ChunkifyMut},
+  chunk::{
+    neighbors::{size_of, NeighborData},
+    Chunkify, ChunkifyMut, ChunkifyRegion,
+  },
 };
 
-/// Provides a `Chunkify` implementation with a hashmap and `u8` position based on x, y and z axis with `u8` value.
+/// Provides a `Chunkify` implementation with a hashmap and `usize` position based on x, y and z axis
+/// with index and value support `(u8, u8)`, only the voxels with density are stored, so the
+/// material of the air voxels is not kept.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SparseChunk {
+  position: [f32; 3],
   width: u16,
   height: u16,
   depth: u16,
-  data: HashMap<(usize, usize, usize), u8>,
+  data: HashMap<(usize, usize, usize), (u8, u8)>,
+  #[cfg_attr(feature = "serde", serde(skip))]
+  neighbors: NeighborData,
 }
 
-impl Chunkify<u8> for SparseChunk {
-  fn is_air(&self, x: usize, y: usize, z: usize) -> bool {
-    self.get(x, y, z) == 0
+impl SparseChunk {
+  /// Number of voxels stored in the chunk.
+  pub fn len(&self) -> usize {
+    self.data.len()
   }
 
-  fn get(&self, x: usize, y: usize, z: usize) -> u8 {
-    *self.data.get(&(x, y, z)).unwrap_or(&0)
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
   }
 }
 
-impl Sizable for SparseChunk {
-  fn with_size(width: u16, height: u16, depth: u16) -> Self {
+impl Boxify for SparseChunk {
+  fn new(position: [f32; 3], width: u16, height: u16, depth: u16) -> Self {
     Self {
+      position,
       width,
       height,
       depth,
       data: HashMap::new(),
+      neighbors: NeighborData::default(),
+    }
+  }
+
+  fn update_neighbor_data(&mut self, neighbor: &Self) -> bool {
+    let size = size_of(self);
+    self.neighbors.update(self.position, size, neighbor)
+  }
+}
+
+impl Chunkify<(u8, u8)> for SparseChunk {
+  fn is_air(&self, x: isize, y: isize, z: isize) -> bool {
+    self.get(x, y, z).1 == 0
+  }
+
+  fn get(&self, x: isize, y: isize, z: isize) -> (u8, u8) {
+    if x < 0
+      || y < 0
+      || z < 0
+      || x >= self.width as isize
+      || y >= self.height as isize
+      || z >= self.depth as isize
+    {
+      self.neighbors.get(size_of(self), x, y, z).unwrap_or((0, 0))
+    } else {
+      *self
+        .data
+        .get(&(x as usize, y as usize, z as usize))
+        .unwrap_or(&(0, 0))
+    }
+  }
+}
+
+impl ChunkifyMut<(u8, u8)> for SparseChunk {
+  fn set(&mut self, x: usize, y: usize, z: usize, value: (u8, u8)) {
+    if value.1 == 0 {
+      self.data.remove(&(x, y, z));
+    } else {
+      self.data.insert((x, y, z), value);
     }
   }
+}
+
+impl ChunkifyRegion<(u8, u8)> for SparseChunk {
+  fn voxels(&self) -> Vec<([usize; 3], (u8, u8))> {
+    let mut result = self
+      .data
+      .iter()
+      .map(|((x, y, z), value)| ([*x, *y, *z], *value))
+      .collect::<Vec<_>>();
+
+    result.sort_by_key(|([x, y, z], _)| (*z, *y, *x));
+
+    result
+  }
+
+  fn replace_material(&mut self, from: u16, to: u16) -> usize {
+    let mut count = 0;
+
+    for value in self.data.values_mut() {
+      if value.0 as u16 == from {
+        value.0 = to.min(u8::MAX as u16) as u8;
+        count += 1;
+      }
+    }
+
+    count
+  }
+}
+
+impl Positionable for SparseChunk {
+  fn with_position(position: [f32; 3]) -> Self {
+    Self::new(position, 16, 16, 16)
+  }
+
+  fn position(&self) -> [f32; 3] {
+    self.position
+  }
+}
+
+impl Sizable for SparseChunk {
+  fn with_size(width: u16, height: u16, depth: u16) -> Self {
+    Self::new([0.0, 0.0, 0.0], width, height, depth)
+  }
 
   fn depth(&self) -> u16 {
     self.depth
@@ -51,8 +143,57 @@ impl Sizable for SparseChunk {
   }
 }
 
-impl ChunkifyMut<u8> for SparseChunk {
-  fn set(&mut self, x: usize, y: usize, z: usize, value: u8) {
-    self.data.insert((x, y, z), value);
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn check_set_removes_empty() {
+    let mut chunk = SparseChunk::new([16.0, 0.0, 0.0], 16, 16, 16);
+
+    chunk.set(1, 2, 3, (4, 255));
+    chunk.set(3, 2, 1, (5, 0));
+
+    // The air with a material isn't stored
+    assert_eq!(chunk.len(), 1);
+    assert_eq!(chunk.get(1, 2, 3), (4, 255));
+    assert_eq!(chunk.get(3, 2, 1), (0, 0));
+    assert!(!chunk.is_air(1, 2, 3));
+    assert!(chunk.is_air(3, 2, 1));
+    assert_eq!(chunk.position(), [16.0, 0.0, 0.0]);
+
+    chunk.set(1, 2, 3, (4, 0));
+
+    assert!(chunk.is_empty());
+    assert_eq!(chunk.get(1, 2, 3), (0, 0));
+  }
+
+  #[test]
+  fn check_neighbor_data() {
+    let mut chunk = SparseChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+    let mut top = SparseChunk::new([0.0, 4.0, 0.0], 4, 4, 4);
+
+    chunk.set(1, 3, 1, (1, 255));
+    top.set(1, 0, 1, (2, 255));
+    chunk.update_neighbor_data(&top);
+    top.update_neighbor_data(&chunk);
+
+    assert_eq!(chunk.get(1, 4, 1), (2, 255));
+    assert_eq!(top.get(1, -1, 1), (1, 255));
+    assert!(chunk.is_air(1, 4, 2));
+    assert_eq!(chunk.len(), 1);
+  }
+
+  #[test]
+  fn check_region() {
+    let mut chunk = SparseChunk::new([0.0, 0.0, 0.0], 4, 4, 4);
+
+    chunk.fill([0, 0, 0], [4, 4, 4], (1, 255));
+    chunk.fill([1, 1, 1], [10, 10, 10], (0, 0));
+
+    assert_eq!(chunk.len(), 64 - 27);
+    assert_eq!(chunk.voxels()[0], ([0, 0, 0], (1, 255)));
+    assert_eq!(chunk.replace_material(1, 2), 64 - 27);
+    assert_eq!(chunk.get(3, 0, 0), (2, 255));
   }
 }
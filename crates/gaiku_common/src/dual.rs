@@ -0,0 +1,321 @@
+use glam::Vec3;
+
+use crate::{
+  boxify::*,
+  chunk::Chunkify,
+  mesh::MeshBuilder,
+  texture::{triplanar_uv, Texturify2d},
+  voxel::Voxel,
+  BakerOptions, Isosurface, Shading,
+};
+
+/// Edges of a cell as pairs of corners, the corner `i` is at `[i & 1, (i >> 1) & 1, i >> 2]`.
+pub const EDGES: [(usize, usize); 12] = [
+  (0, 1),
+  (2, 3),
+  (4, 5),
+  (6, 7),
+  (0, 2),
+  (1, 3),
+  (4, 6),
+  (5, 7),
+  (0, 4),
+  (1, 5),
+  (2, 6),
+  (3, 7),
+];
+
+/// Position of the corner `index` inside its cell.
+pub fn corner(index: usize) -> [f32; 3] {
+  [
+    (index & 1) as f32,
+    ((index >> 1) & 1) as f32,
+    (index >> 2) as f32,
+  ]
+}
+
+/// Signed density of the corners of the cell `[x, y, z]`, that goes from the voxel `[x, y, z]`
+/// to the voxel `[x + 1, y + 1, z + 1]`.
+pub fn corner_values<V, C>(chunk: &C, isosurface: &Isosurface, [x, y, z]: [isize; 3]) -> [f32; 8]
+where
+  V: Voxel,
+  C: Chunkify<V>,
+{
+  let mut values = [0.0; 8];
+
+  for (index, value) in values.iter_mut().enumerate() {
+    let [cx, cy, cz] = corner(index);
+    *value = isosurface.sample(chunk.get(x + cx as isize, y + cy as isize, z + cz as isize));
+  }
+
+  values
+}
+
+/// Gradient of the trilinear interpolation of `values` at the cell local `position`.
+pub fn gradient(values: &[f32; 8], position: [f32; 3]) -> [f32; 3] {
+  let mut gradient = [0.0; 3];
+
+  for (axis, gradient) in gradient.iter_mut().enumerate() {
+    for index in (0..8).filter(|index| index & (1 << axis) == 0) {
+      let weight: f32 = (0..3)
+        .filter(|other| *other != axis)
+        .map(|other| {
+          if index & (1 << other) != 0 {
+            position[other]
+          } else {
+            1.0 - position[other]
+          }
+        })
+        .product();
+
+      *gradient += (values[index | (1 << axis)] - values[index]) * weight;
+    }
+  }
+
+  gradient
+}
+
+/// Normal of the surface at the cell local `position`, zero if the density is flat. It points
+/// against the `gradient`, the density grows towards the inside of the surface.
+pub fn normal(values: &[f32; 8], position: [f32; 3]) -> [f32; 3] {
+  let gradient = Vec3::from(gradient(values, position));
+
+  if gradient.length_squared() > 0.0 {
+    (-gradient.normalize()).into()
+  } else {
+    [0.0; 3]
+  }
+}
+
+/// Calls `quad` for each edge of the chunk crossed by the surface, with the 4 cells around the
+/// edge and the material of the voxel inside the surface.
+///
+/// The chunk owns the edges starting inside it, the edges starting in the neighbor data are left
+/// to the neighbor. The cells are counter-clockwise seen from outside the surface, they go from
+/// `-1` to `size - 1` so the quads reach the cells made with the neighbor data.
+pub fn quads<V, C, F>(chunk: &C, isosurface: &Isosurface, mut quad: F)
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+  F: FnMut([[isize; 3]; 4], u16),
+{
+  let size = [
+    chunk.width() as isize,
+    chunk.height() as isize,
+    chunk.depth() as isize,
+  ];
+
+  for axis in 0..3 {
+    let u = (axis + 1) % 3;
+    let v = (axis + 2) % 3;
+
+    for x in 0..size[0] {
+      for y in 0..size[1] {
+        for z in 0..size[2] {
+          let start = [x, y, z];
+          let mut end = start;
+          end[axis] += 1;
+
+          let start_voxel = chunk.get(start[0], start[1], start[2]);
+          let end_voxel = chunk.get(end[0], end[1], end[2]);
+          let inside = isosurface.sample(start_voxel) >= 0.0;
+
+          if inside == (isosurface.sample(end_voxel) >= 0.0) {
+            continue;
+          }
+
+          // Counter-clockwise around `axis`, so the quad faces `axis` when the start is inside
+          let mut cells = [(-1, -1), (0, -1), (0, 0), (-1, 0)].map(|(du, dv)| {
+            let mut cell = start;
+            cell[u] += du;
+            cell[v] += dv;
+            cell
+          });
+
+          let material = if inside {
+            start_voxel.material()
+          } else {
+            cells.reverse();
+            end_voxel.material()
+          };
+
+          quad(cells, material);
+        }
+      }
+    }
+  }
+}
+
+/// Diagonal `add_quad` splits the quads through.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Diagonal {
+  /// The shorter diagonal.
+  Shortest,
+  /// The diagonal that folds the quad less, so the sharp edges crossing it are kept.
+  Flattest,
+}
+
+/// Adds the counter-clockwise `quad` of vertex positions and normals as two triangles split
+/// through `diagonal`, the vertices without normal take the normal of their triangle.
+///
+/// The uvs map the cells around the quad to the tile of `material` with `triplanar_uv`.
+pub fn add_quad<T>(
+  builder: &mut MeshBuilder,
+  options: &BakerOptions<T>,
+  quad: [([f32; 3], [f32; 3]); 4],
+  diagonal: Diagonal,
+  material: u16,
+) where
+  T: Texturify2d,
+{
+  let positions = quad.map(|(position, _)| Vec3::from(position));
+  let start = positions
+    .iter()
+    .fold(positions[0], |start, position| start.min(*position))
+    .floor();
+  let end = positions
+    .iter()
+    .fold(positions[0], |end, position| end.max(*position))
+    .ceil();
+  let cell = start.into();
+  let cell_size = (end - start).max(Vec3::one()).into();
+
+  let normal = |[a, b, c]: [usize; 3]| {
+    let normal = (positions[b] - positions[a]).cross(positions[c] - positions[a]);
+
+    if normal.length_squared() > 0.0 {
+      normal.normalize()
+    } else {
+      Vec3::zero()
+    }
+  };
+
+  let split = match diagonal {
+    Diagonal::Shortest => {
+      (positions[0] - positions[2]).length_squared()
+        <= (positions[1] - positions[3]).length_squared()
+    }
+    Diagonal::Flattest => {
+      let fold = |triangles: [[usize; 3]; 2]| normal(triangles[0]).dot(normal(triangles[1]));
+      fold([[0, 1, 2], [0, 2, 3]]) >= fold([[0, 1, 3], [1, 2, 3]])
+    }
+  };
+  let triangles = if split {
+    [[0, 1, 2], [0, 2, 3]]
+  } else {
+    [[0, 1, 3], [1, 2, 3]]
+  };
+
+  for triangle in triangles.iter() {
+    let face_normal = normal(*triangle);
+
+    // The vertices clamped to the same cell face make triangles without area
+    if face_normal == Vec3::zero() {
+      continue;
+    }
+
+    let triangle_positions = triangle.map(|index| quad[index].0);
+    let uv = options.texture.as_ref().map(|texture| {
+      let tile = texture.get_uv(material);
+      triangle_positions
+        .map(|position| triplanar_uv(tile, face_normal.into(), position, cell, cell_size))
+    });
+
+    if options.shading == Shading::Smooth {
+      for (index, vertex) in triangle.iter().enumerate() {
+        let normal = if quad[*vertex].1 == [0.0; 3] {
+          face_normal.into()
+        } else {
+          quad[*vertex].1
+        };
+
+        builder.add(
+          triangle_positions[index],
+          Some(normal),
+          uv.map(|uv| uv[index]),
+          material,
+        );
+      }
+    } else {
+      builder.add_triangle(triangle_positions, Some(face_normal.into()), uv, material);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{
+    chunk::{Chunk, ChunkifyMut},
+    mesh::{Mesh, Meshify},
+    texture::Texture2d,
+  };
+
+  #[test]
+  fn check_gradient() {
+    // The density grows along x and y, twice as fast along y
+    let values = [0, 1, 2, 3, 4, 5, 6, 7].map(|index| {
+      let [x, y, _] = corner(index);
+      x + 2.0 * y - 1.0
+    });
+
+    assert_eq!(gradient(&values, [0.3, 0.6, 0.2]), [1.0, 2.0, 0.0]);
+    let normal = normal(&values, [0.3, 0.6, 0.2]);
+    assert!((Vec3::from(normal) + Vec3::new(1.0, 2.0, 0.0).normalize()).length() < 1e-6);
+    assert_eq!(super::normal(&[0.5; 8], [0.5; 3]), [0.0; 3]);
+  }
+
+  #[test]
+  fn check_quads() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 2, 2, 2);
+    chunk.set(0, 0, 0, (3, 255));
+
+    let mut result = vec![];
+    quads(&chunk, &Isosurface::default(), |cells, material| {
+      result.push((cells, material))
+    });
+
+    // The edges leaving the voxel, the edges reaching it start in the neighbor data
+    assert_eq!(result.len(), 3);
+    assert!(result.iter().all(|(_, material)| *material == 3));
+    assert_eq!(
+      result[0].0,
+      [[0, -1, -1], [0, 0, -1], [0, 0, 0], [0, -1, 0]]
+    );
+  }
+
+  #[test]
+  fn check_add_quad() {
+    // The corner 2 is raised, the shorter diagonal folds the quad more
+    let quad = [
+      [0.0, 0.0, 0.0],
+      [1.0, 0.0, 0.0],
+      [0.5, 0.5, 1.5],
+      [0.0, 1.0, 0.0],
+    ]
+    .map(|position| (position, [0.0; 3]));
+    let options = BakerOptions::<Texture2d> {
+      shading: Shading::Flat,
+      ..Default::default()
+    };
+
+    for (diagonal, first) in [
+      (Diagonal::Shortest, [0, 1, 3]),
+      (Diagonal::Flattest, [0, 1, 2]),
+    ]
+    .iter()
+    {
+      let mut builder = MeshBuilder::create([0.5, 0.5, 0.5], [2.0, 2.0, 2.0]);
+      add_quad(&mut builder, &options, quad, *diagonal, 1);
+
+      let mesh = builder.build::<Mesh>().unwrap();
+      let positions = mesh.get_positions();
+      let triangle = &mesh.get_indices()[0..3];
+
+      assert_eq!(mesh.get_indices().len(), 6);
+      for (index, corner) in triangle.iter().zip(first.iter()) {
+        assert_eq!(positions[*index as usize], quad[*corner].0);
+      }
+    }
+  }
+}
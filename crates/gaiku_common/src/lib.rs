@@ -2,37 +2,64 @@
 //!
 //! The idea behind this crate is to offer a common interop interface to
 //! work with different file formats and mesh generators, based on voxels.
-use std::fs::read;
+#![cfg_attr(
+  test,
+  allow(
+    clippy::assertions_on_constants,
+    clippy::erasing_op,
+    clippy::identity_op,
+    clippy::manual_repeat_n
+  )
+)]
+use std::{collections::HashSet, fs::read};
 
-pub use anyhow::Result;
+pub use anyhow::{bail, Result};
 pub use mint;
 
 use crate::{
+  boundary::Boundary,
   boxify::*,
   chunk::{Chunkify, ChunkifyMut},
   mesh::Meshify,
+  raycast::RaycastHit,
   texture::{TextureAtlas2d, Texturify2d},
+  tree::Octree,
+  voxel::Voxel,
 };
 
-mod boundary;
+/// Axis aligned bounding box used in the spatial queries.
+pub mod boundary;
 /// Trait to define position and size.
 pub mod boxify;
 /// Chunk implementation, also offers all traits used internally to build the chunk object.
 pub mod chunk;
+/// Cells, normals and quads shared by the bakers with a vertex per cell.
+pub mod dual;
 /// Mesh related traits/implementation, also offers some utils like MeshBuilder.
 pub mod mesh;
+/// Voxel picking with rays.
+pub mod raycast;
+/// Region files to save and load the chunks of a world.
+#[cfg(feature = "serialization")]
+pub mod region;
+/// Viewer driven loading and unloading of the chunks of infinite worlds.
+pub mod streaming;
 /// Texture related traits/implementation.
 pub mod texture;
-//mod tree;
+/// Octree used to store the chunks of the world.
+pub mod tree;
+/// Trait to define the value stored per voxel.
+pub mod voxel;
 
 /// `use gaiku_common::prelude::*;` to import common traits and utils.
 pub mod prelude {
   pub use crate::{
     boxify::*,
-    chunk::{Chunkify, ChunkifyMut},
+    chunk::{Chunkify, ChunkifyCsg, ChunkifyMut, ChunkifyRegion},
     mesh::{MeshBuilder, Meshify},
     texture::{TextureAtlas2d, Texturify2d},
-    Baker, BakerOptions, FileFormat,
+    voxel::Voxel,
+    Baker, BakerOptions, DensityChannel, FileFormat, Inside, Isosurface, Shading, Topology,
   };
 }
 
@@ -41,8 +68,104 @@ pub struct BakerOptions<T>
 where
   T: Texturify2d,
 {
+  /// `1` bakes the chunk at full resolution, every extra level halves the resolution,
+  /// see `chunk::DownsampledChunk`.
   pub level_of_detail: usize,
   pub texture: Option<TextureAtlas2d<T>>,
+  /// Computes the ambient occlusion of each vertex, see `Meshify::get_ambient_occlusion`,
+  /// only the bakers that support it use it.
+  pub ambient_occlusion: bool,
+  /// How the normals of the smooth surfaces are computed, the cubical bakers are always flat.
+  pub shading: Shading,
+  /// Where the smooth bakers place the surface.
+  pub isosurface: Isosurface,
+  /// How the marching cubes bakers resolve the ambiguous cells, the other bakers ignore it.
+  pub topology: Topology,
+}
+
+/// Normals of the baked meshes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Shading {
+  /// Each vertex normal follows the density gradient, so the surface looks smooth, even across
+  /// the chunk borders.
+  #[default]
+  Smooth,
+  /// Each triangle uses its face normal.
+  Flat,
+}
+
+/// Surface of the marching cubes cells whose corners can be joined in more than one way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+  /// The cases table, fast but it always joins the inside corners of the ambiguous faces, so
+  /// the surface can join parts that the densities keep apart.
+  #[default]
+  Classic,
+  /// The topology of the trilinear interpolation of the cell, the ambiguous faces are resolved
+  /// with the asymptotic decider and the segments on the faces are chained into loops, so the
+  /// surface is closed. The interior of the cells with two loops is tested for a tunnel between
+  /// them, the cells with more loops are always capped with a disc per loop.
+  Trilinear,
+}
+
+/// Value of the voxel read as density by the smooth bakers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DensityChannel {
+  /// `Voxel::density`.
+  #[default]
+  Density,
+  /// `Voxel::material` divided by `255`, for volumes that store a single `u8` value per voxel
+  /// as material, i.e. grayscale images.
+  Material,
+}
+
+/// Side of the isolevel that is inside the surface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Inside {
+  /// Densities equal or above the isolevel are inside.
+  #[default]
+  Above,
+  /// Densities equal or below the isolevel are inside.
+  Below,
+}
+
+/// Defines the surface extracted from the voxel densities by the smooth bakers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isosurface {
+  /// Density of the surface, in the `0.0..=1.0` range of `Voxel::density`, i.e. `128.0 / 255.0`
+  /// places the surface at the `128` value of `u8` data.
+  pub isolevel: f32,
+  pub channel: DensityChannel,
+  pub inside: Inside,
+}
+
+impl Isosurface {
+  /// Signed density of `voxel`, positive inside the surface, negative outside and zero on it.
+  pub fn sample<V>(&self, voxel: V) -> f32
+  where
+    V: Voxel,
+  {
+    let density = match self.channel {
+      DensityChannel::Density => voxel.density(),
+      DensityChannel::Material => voxel.material() as f32 / 255.0,
+    };
+
+    match self.inside {
+      Inside::Above => density - self.isolevel,
+      Inside::Below => self.isolevel - density,
+    }
+  }
+}
+
+impl Default for Isosurface {
+  /// The surface is at the lowest non zero `u8` density.
+  fn default() -> Self {
+    Self {
+      isolevel: 1.0 / 255.0,
+      channel: DensityChannel::default(),
+      inside: Inside::default(),
+    }
+  }
 }
 
 impl<T> Default for BakerOptions<T>
@@ -53,66 +176,575 @@ where
     Self {
       level_of_detail: 1,
       texture: None,
+      ambient_occlusion: false,
+      shading: Shading::default(),
+      isosurface: Isosurface::default(),
+      topology: Topology::default(),
     }
   }
 }
 
 /// Baker is a trait used to define a chunk to mesh converter
 pub trait Baker {
-  type Value;
-
-  fn bake<C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
+  fn bake<V, C, T, M>(chunk: &C, options: &BakerOptions<T>) -> Result<Option<M>>
   where
-    C: Chunkify<Self::Value> + Sizable,
+    V: Voxel,
+    C: Chunkify<V> + Sizable,
     T: Texturify2d,
     M: Meshify;
 }
 
 /// FileFormat is a trait used to define a {file extension} to chunk converter
 pub trait FileFormat {
-  type Value;
-
-  fn load<C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
+  fn load<V, C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
   where
-    C: Chunkify<Self::Value> + ChunkifyMut<Self::Value> + Boxify,
+    V: Voxel,
+    C: Chunkify<V> + ChunkifyMut<V> + Boxify,
     T: Texturify2d;
 
-  fn read<C, T>(file: &str) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
+  fn read<V, C, T>(file: &str) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
   where
-    C: Chunkify<Self::Value> + ChunkifyMut<Self::Value> + Boxify,
+    V: Voxel,
+    C: Chunkify<V> + ChunkifyMut<V> + Boxify,
     T: Texturify2d,
   {
     let bytes = read(file)?;
-    Self::load::<C, T>(bytes)
+    Self::load::<V, C, T>(bytes)
   }
 }
 
-/*
-pub struct Gaiku {
-  terrain: Octree,
+/// World container that keeps the chunks in an octree, any `Boxify` chunk type can be used.
+///
+/// The chunk `position()` is its key, setting a chunk at a position that already has one
+/// replaces it. The voxels can be read and written with world coordinates, the chunks are laid
+/// out in a grid of `chunk_size` voxels and its position is in voxel units.
+#[derive(Clone, Debug)]
+pub struct Gaiku<C> {
+  terrain: Octree<C>,
+  chunk_size: [u16; 3],
+  // Positions as bits, so they can be hashed
+  dirty: HashSet<[u32; 3]>,
 }
 
-impl Gaiku {
-  pub fn new(data: Vec<Chunk>, size: [f32; 3]) -> Self {
-    let mut terrain = Octree::new(size, 8);
+impl<C> Gaiku<C>
+where
+  C: Boxify,
+{
+  /// Creates the world with the `data` chunks, `size` is the initial size of the octree, it
+  /// grows as needed to fit the chunks. `chunk_size` is the size of the chunks created when
+  /// writing voxels in empty space.
+  pub fn new(data: Vec<C>, size: [f32; 3], chunk_size: [u16; 3]) -> Self {
+    let mut world = Self {
+      terrain: Octree::new(size, 8),
+      chunk_size,
+      dirty: HashSet::new(),
+    };
 
     for chunk in data {
-      terrain.insert(&chunk);
+      world.set_chunk(chunk);
     }
 
-    Self { terrain }
+    world
   }
 
-  pub fn query(&self, boundary: &Boundary) -> Vec<Chunk> {
+  /// Size of the chunks of the world grid.
+  pub fn chunk_size(&self) -> [u16; 3] {
+    self.chunk_size
+  }
+
+  /// Returns the chunks with its position inside `boundary`.
+  pub fn query(&self, boundary: &Boundary) -> Vec<&C> {
     self.terrain.query(boundary)
   }
 
-  pub fn get_chunk(&self, point: &Vector3<f32>) -> Option<Chunk> {
-    self.terrain.get_leaf(point)
+  /// Returns the chunk at `position`.
+  pub fn get_chunk(&self, position: [f32; 3]) -> Option<&C> {
+    self.terrain.get_leaf(position)
+  }
+
+  /// Returns the chunk at `position`, the neighbor data of the chunks around isn't updated with
+  /// the changes.
+  pub fn get_chunk_mut(&mut self, position: [f32; 3]) -> Option<&mut C> {
+    self.terrain.get_leaf_mut(position)
+  }
+
+  /// Inserts the chunk, returning the chunk it replaces. The neighbor data of the chunk and the
+  /// chunks around is updated, the chunk is marked as dirty and the chunks around too when the
+  /// voxels they see across the border changed.
+  pub fn set_chunk(&mut self, chunk: C) -> Option<C> {
+    let mut chunk = chunk;
+    let positions = self.neighbor_positions(&chunk, [-1..2, -1..2, -1..2]);
+    let mut changed = vec![];
+
+    for neighbor in self.terrain.get_leaves_mut(&positions) {
+      if neighbor.update_neighbor_data(&chunk) {
+        changed.push(neighbor.position());
+      }
+      chunk.update_neighbor_data(neighbor);
+    }
+
+    for position in changed {
+      self.mark_dirty(position);
+    }
+
+    self.mark_dirty(chunk.position());
+    self.terrain.insert(chunk)
+  }
+
+  /// Removes and returns the chunk at `position`, the chunks around are marked as dirty when
+  /// the chunk wasn't empty.
+  pub fn remove_chunk(&mut self, position: [f32; 3]) -> Option<C> {
+    let chunk = self.terrain.remove(position)?;
+    let empty = C::new(position, chunk.width(), chunk.height(), chunk.depth());
+    let positions = self.neighbor_positions(&chunk, [-1..2, -1..2, -1..2]);
+    let mut changed = vec![];
+
+    for neighbor in self.terrain.get_leaves_mut(&positions) {
+      if neighbor.update_neighbor_data(&empty) {
+        changed.push(neighbor.position());
+      }
+    }
+
+    for position in changed {
+      self.mark_dirty(position);
+    }
+
+    self.mark_dirty(position);
+    Some(chunk)
+  }
+
+  /// Returns all the chunks.
+  pub fn chunks(&self) -> Vec<&C> {
+    self.terrain.leaves()
+  }
+
+  /// Number of chunks.
+  pub fn len(&self) -> usize {
+    self.terrain.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.terrain.is_empty()
+  }
+
+  /// Maps world voxel coordinates into the position of the chunk holding it and the
+  /// coordinates inside that chunk.
+  pub fn world_to_chunk(&self, [x, y, z]: [isize; 3]) -> ([f32; 3], [usize; 3]) {
+    let [width, height, depth] = self.chunk_size.map(|size| size as isize);
+
+    (
+      [
+        (x.div_euclid(width) * width) as f32,
+        (y.div_euclid(height) * height) as f32,
+        (z.div_euclid(depth) * depth) as f32,
+      ],
+      [
+        x.rem_euclid(width) as usize,
+        y.rem_euclid(height) as usize,
+        z.rem_euclid(depth) as usize,
+      ],
+    )
+  }
+
+  /// Returns the voxel at the world coordinates, air if there isn't a chunk there.
+  pub fn get_voxel<V>(&self, position: [isize; 3]) -> V
+  where
+    V: Voxel,
+    C: Chunkify<V>,
+  {
+    let (chunk, [x, y, z]) = self.world_to_chunk(position);
+
+    self
+      .get_chunk(chunk)
+      .map(|chunk| chunk.get(x as isize, y as isize, z as isize))
+      .unwrap_or_default()
+  }
+
+  /// Returns `true` if the voxel at the world coordinates is air.
+  pub fn is_air<V>(&self, position: [isize; 3]) -> bool
+  where
+    V: Voxel,
+    C: Chunkify<V>,
+  {
+    let (chunk, [x, y, z]) = self.world_to_chunk(position);
+
+    self
+      .get_chunk(chunk)
+      .map(|chunk| chunk.is_air(x as isize, y as isize, z as isize))
+      .unwrap_or(true)
+  }
+
+  /// Sets the voxel at the world coordinates, creating the chunk if there isn't one (unless the
+  /// voxel is air). The chunk is edited in place and marked as dirty, and its neighbors when the
+  /// voxel is on the border.
+  pub fn set_voxel<V>(&mut self, position: [isize; 3], value: V)
+  where
+    V: Voxel,
+    C: Chunkify<V> + ChunkifyMut<V>,
+  {
+    let (position, [x, y, z]) = self.world_to_chunk(position);
+
+    if self.get_chunk(position).is_none() {
+      if value.is_empty() {
+        return;
+      }
+
+      let [width, height, depth] = self.chunk_size;
+      self.set_chunk(C::new(position, width, height, depth));
+    }
+
+    let chunk = self.get_chunk(position).unwrap();
+    let border = |local: usize, size: u16| {
+      let start = if local == 0 { -1 } else { 0 };
+      let end = if local + 1 == size as usize { 2 } else { 1 };
+      start..end
+    };
+    let ranges = [
+      border(x, chunk.width()),
+      border(y, chunk.height()),
+      border(z, chunk.depth()),
+    ];
+    let mut positions = self.neighbor_positions(chunk, ranges);
+    positions.push(position);
+
+    // The chunk and its neighbors are borrowed at once to edit the chunk in place
+    let mut leaves = self.terrain.get_leaves_mut(&positions);
+    let index = leaves
+      .iter()
+      .position(|leaf| leaf.position() == position)
+      .unwrap();
+    let chunk = leaves.swap_remove(index);
+    chunk.set(x, y, z, value);
+
+    let mut changed = vec![position];
+    for neighbor in leaves {
+      if neighbor.update_neighbor_data(chunk) {
+        changed.push(neighbor.position());
+      }
+    }
+
+    for position in changed {
+      self.mark_dirty(position);
+    }
+  }
+
+  /// Casts a ray in world coordinates against the voxels of all the chunks, returning the first
+  /// voxel that `is_solid`. The ray stops when it leaves the chunks of the world.
+  pub fn raycast<V, F>(
+    &self,
+    origin: [f32; 3],
+    direction: [f32; 3],
+    max_distance: f32,
+    is_solid: F,
+  ) -> Option<RaycastHit<V>>
+  where
+    V: Voxel,
+    C: Chunkify<V>,
+    F: Fn(&V) -> bool,
+  {
+    let start: [f32; 3] = self.terrain.boundary().start().into();
+    let end: [f32; 3] = self.terrain.boundary().end().into();
+    let [width, height, depth] = self.chunk_size.map(f32::from);
+    let boundary =
+      Boundary::from_start_end(start, [end[0] + width, end[1] + height, end[2] + depth]);
+    let max_distance = max_distance.min(raycast::exit_distance(origin, direction, &boundary)?);
+
+    raycast::traverse(origin, direction, max_distance, |position| {
+      let value = self.get_voxel(position);
+
+      if is_solid(&value) {
+        Some(value)
+      } else {
+        None
+      }
+    })
+  }
+
+  /// Positions of the chunks marked as dirty, in no particular order.
+  pub fn dirty_chunks(&self) -> Vec<[f32; 3]> {
+    self
+      .dirty
+      .iter()
+      .map(|key| key.map(f32::from_bits))
+      .collect()
+  }
+
+  /// Returns `true` if the chunk at `position` is marked as dirty.
+  pub fn is_dirty(&self, position: [f32; 3]) -> bool {
+    self.dirty.contains(&dirty_key(position))
+  }
+
+  pub fn clear_dirty(&mut self) {
+    self.dirty.clear();
+  }
+
+  /// Bakes again only the dirty chunks with the baker `B` and clears them, returning the
+  /// position of each one with its new mesh, or `None` when its mesh must be removed (the chunk
+  /// was removed or there is nothing to show).
+  pub fn bake_dirty<B, V, T, M>(
+    &mut self,
+    options: &BakerOptions<T>,
+  ) -> Result<Vec<([f32; 3], Option<M>)>>
+  where
+    B: Baker,
+    V: Voxel,
+    C: Chunkify<V>,
+    T: Texturify2d,
+    M: Meshify,
+  {
+    let mut result = Vec::with_capacity(self.dirty.len());
+
+    for position in self.dirty_chunks() {
+      let mesh = match self.terrain.get_leaf(position) {
+        Some(chunk) => B::bake::<V, C, T, M>(chunk, options)?,
+        None => None,
+      };
+
+      result.push((position, mesh));
+    }
+
+    self.dirty.clear();
+
+    Ok(result)
+  }
+
+  /// Marks the chunk at `position` to be baked again by `bake_dirty`, i.e. after editing it
+  /// through `get_chunk_mut`.
+  pub fn mark_dirty(&mut self, position: [f32; 3]) {
+    self.dirty.insert(dirty_key(position));
+  }
+
+  /// Positions of the neighbor chunks (of the same size) in the offset ranges, in chunk units,
+  /// excluding the chunk itself.
+  fn neighbor_positions(&self, chunk: &C, ranges: [std::ops::Range<isize>; 3]) -> Vec<[f32; 3]> {
+    let [x, y, z] = chunk.position();
+    let [width, height, depth] = [
+      chunk.width() as f32,
+      chunk.height() as f32,
+      chunk.depth() as f32,
+    ];
+    let mut result = vec![];
+
+    for dz in ranges[2].clone() {
+      for dy in ranges[1].clone() {
+        for dx in ranges[0].clone() {
+          if dx != 0 || dy != 0 || dz != 0 {
+            result.push([
+              x + dx as f32 * width,
+              y + dy as f32 * height,
+              z + dz as f32 * depth,
+            ]);
+          }
+        }
+      }
+    }
+
+    result
+  }
+}
+
+/// Key of a chunk position in the dirty set, `-0.0` and `0.0` have different bits but are the
+/// same position.
+fn dirty_key(position: [f32; 3]) -> [u32; 3] {
+  position.map(|value| (value + 0.0).to_bits())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{
+    chunk::{Chunk, SparseChunk},
+    mesh::{Mesh, MeshBuilder},
+    texture::Texture2d,
+  };
+
+  #[test]
+  fn check_world_voxels() {
+    let mut world: Gaiku<Chunk> = Gaiku::new(vec![], [64.0, 64.0, 64.0], [4, 4, 4]);
+
+    assert_eq!(
+      world.world_to_chunk([-1, 5, 0]),
+      ([-4.0, 4.0, 0.0], [3, 1, 0])
+    );
+
+    world.set_voxel([0, 0, 0], (0, 0));
+    assert!(world.is_empty());
+
+    world.set_voxel([5, 1, 1], (1, 255));
+    assert_eq!(world.len(), 1);
+    assert_eq!(world.dirty_chunks(), &[[4.0, 0.0, 0.0]]);
+    assert_eq!(world.get_voxel::<(u8, u8)>([5, 1, 1]), (1, 255));
+    assert!(world.is_air::<(u8, u8)>([4, 1, 1]));
+    assert!(world.is_air::<(u8, u8)>([-40, 1, 1]));
+
+    // A new chunk picks the neighbor data of the chunks around it, the neighbors aren't dirty
+    // while the voxels they see across the border are still air
+    world.clear_dirty();
+    world.set_voxel([0, 0, 0], (2, 255));
+    assert_eq!(world.len(), 2);
+    assert!(world.is_dirty([0.0, 0.0, 0.0]));
+    assert!(!world.is_dirty([4.0, 0.0, 0.0]));
+
+    // Editing a border voxel updates the neighbor data and dirties the neighbor
+    world.clear_dirty();
+    world.set_voxel([4, 1, 1], (3, 255));
+    assert_eq!(world.dirty_chunks().len(), 2);
+    let chunk = world.get_chunk([0.0, 0.0, 0.0]).unwrap();
+    assert_eq!(chunk.get(4, 1, 1), (3, 255));
+
+    world.clear_dirty();
+    world.set_voxel([5, 1, 1], (0, 0));
+    assert_eq!(world.dirty_chunks(), &[[4.0, 0.0, 0.0]]);
+
+    world.clear_dirty();
+    world.remove_chunk([4.0, 0.0, 0.0]);
+    assert!(world.is_dirty([0.0, 0.0, 0.0]));
+    assert!(world.get_chunk([0.0, 0.0, 0.0]).unwrap().is_air(4, 1, 1));
+
+    world.clear_dirty();
+    world.set_chunk(Chunk::new([0.0, 4.0, 0.0], 4, 4, 4));
+    assert_eq!(world.dirty_chunks(), &[[0.0, 4.0, 0.0]]);
+
+    world.clear_dirty();
+    world.mark_dirty([-0.0, 4.0, -0.0]);
+    assert!(world.is_dirty([0.0, 4.0, 0.0]));
+    world.mark_dirty([0.0, 4.0, 0.0]);
+    assert_eq!(world.dirty_chunks().len(), 1);
   }
 
-  pub fn set_chunk(&mut self, chunk: &Chunk) -> bool {
-    self.terrain.set_leaf(chunk)
+  struct CountBaker;
+
+  /// Bakes a triangle per non air voxel.
+  impl Baker for CountBaker {
+    fn bake<V, C, T, M>(chunk: &C, _options: &BakerOptions<T>) -> Result<Option<M>>
+    where
+      V: Voxel,
+      C: Chunkify<V> + Sizable,
+      T: Texturify2d,
+      M: Meshify,
+    {
+      let mut builder = MeshBuilder::create([8.0, 8.0, 8.0], [16.0, 16.0, 16.0]);
+
+      for z in 0..chunk.depth() as isize {
+        for y in 0..chunk.height() as isize {
+          for x in 0..chunk.width() as isize {
+            if !chunk.is_air(x, y, z) {
+              let [x, y, z] = [x as f32, y as f32, z as f32];
+              builder.add_triangle([[x, y, z], [x + 1.0, y, z], [x, y + 1.0, z]], None, None, 0);
+            }
+          }
+        }
+      }
+
+      Ok(builder.build::<M>())
+    }
+  }
+
+  #[test]
+  fn check_bake_dirty() {
+    let options = BakerOptions::<Texture2d>::default();
+    let mut world: Gaiku<Chunk> = Gaiku::new(vec![], [64.0, 64.0, 64.0], [4, 4, 4]);
+
+    world.set_voxel([1, 1, 1], (1, 255));
+    world.set_voxel([5, 1, 1], (1, 255));
+    let deltas = world
+      .bake_dirty::<CountBaker, _, _, Mesh>(&options)
+      .unwrap();
+    assert_eq!(deltas.len(), 2);
+    assert!(deltas.iter().all(|(_, mesh)| mesh.is_some()));
+    assert!(world.dirty_chunks().is_empty());
+
+    // Only the edited chunk is baked again
+    world.set_voxel([6, 1, 1], (1, 255));
+    let deltas = world
+      .bake_dirty::<CountBaker, _, _, Mesh>(&options)
+      .unwrap();
+    assert_eq!(deltas.len(), 1);
+    assert_eq!(deltas[0].0, [4.0, 0.0, 0.0]);
+    assert_eq!(deltas[0].1.as_ref().unwrap().get_indices().len(), 6);
+
+    world.set_voxel([1, 1, 1], (0, 0));
+    world.remove_chunk([4.0, 0.0, 0.0]);
+    let deltas = world
+      .bake_dirty::<CountBaker, _, _, Mesh>(&options)
+      .unwrap();
+    assert_eq!(deltas.len(), 2);
+    assert!(deltas.iter().all(|(_, mesh)| mesh.is_none()));
+  }
+
+  #[test]
+  fn check_world_raycast() {
+    let mut world: Gaiku<Chunk> = Gaiku::new(vec![], [64.0, 64.0, 64.0], [4, 4, 4]);
+    world.set_voxel([-6, 1, 1], (1, 255));
+
+    let hit = world
+      .raycast(
+        [5.5, 1.5, 1.5],
+        [-1.0, 0.0, 0.0],
+        20.0,
+        |value: &(u8, u8)| !value.is_empty(),
+      )
+      .unwrap();
+    assert_eq!(hit.position, [-6, 1, 1]);
+    assert_eq!(hit.normal, [1, 0, 0]);
+    assert_eq!(hit.distance, 10.5);
+
+    assert!(world
+      .raycast(
+        [5.5, 1.5, 1.5],
+        [-1.0, 0.0, 0.0],
+        10.0,
+        |value: &(u8, u8)| { !value.is_empty() }
+      )
+      .is_none());
+
+    // An endless ray that misses everything stops at the border of the world
+    assert!(world
+      .raycast(
+        [5.5, 1.5, 1.5],
+        [1.0, 0.0, 0.0],
+        f32::INFINITY,
+        |value: &(u8, u8)| { !value.is_empty() }
+      )
+      .is_none());
+    let hit = world
+      .raycast(
+        [5.5, 1.5, 1.5],
+        [-1.0, 0.0, 0.0],
+        f32::INFINITY,
+        |value: &(u8, u8)| !value.is_empty(),
+      )
+      .unwrap();
+    assert_eq!(hit.distance, 10.5);
+  }
+
+  #[test]
+  fn check_world_chunks() {
+    let mut world = Gaiku::new(
+      vec![
+        SparseChunk::new([0.0, 0.0, 0.0], 16, 16, 16),
+        SparseChunk::new([16.0, 0.0, 0.0], 16, 16, 16),
+      ],
+      [64.0, 64.0, 64.0],
+      [16, 16, 16],
+    );
+
+    assert_eq!(world.len(), 2);
+    assert!(world
+      .set_chunk(SparseChunk::new([16.0, 0.0, 0.0], 16, 16, 16))
+      .is_some());
+    assert_eq!(world.len(), 2);
+    assert_eq!(
+      world
+        .query(&Boundary::new([0.0, 0.0, 0.0], [8.0, 8.0, 8.0]))
+        .len(),
+      1
+    );
+
+    world.set_voxel([-1, 0, 0], (1, 255));
+    assert_eq!(world.len(), 3);
+    assert!(world.get_chunk([-16.0, 0.0, 0.0]).is_some());
+    assert!(world.remove_chunk([16.0, 0.0, 0.0]).is_some());
+    assert_eq!(world.chunks().len(), 2);
   }
 }
-*/
@@ -1,4 +1,9 @@
-use crate::boundary::Boundary;
+use crate::{boundary::Boundary, chunk::Chunkify, voxel::Voxel};
+
+/// Relative tolerance of the `MeshBuilder` octree nodes, so the vertices on the border of two
+/// nodes aren't lost to float precision, the nodes keep the tolerance of the root so a vertex
+/// accepted by a node always fits in one of its children.
+const EPSILON: f32 = 1e-5;
 
 /// Base common denominator across all the mesh implementations used.
 pub trait Meshify {
@@ -17,26 +22,58 @@ pub trait Meshify {
   fn set_normals(&mut self, normals: Vec<[f32; 3]>);
   fn set_positions(&mut self, positions: Vec<[f32; 3]>);
   fn set_uvs(&mut self, uvs: Vec<[f32; 2]>);
+
+  /// Texture atlas index of each vertex, empty if the mesh doesn't store them.
+  fn get_atlas_indices(&self) -> &[u16] {
+    &[]
+  }
+
+  /// Stores the texture atlas index of each vertex, ignored by default.
+  fn set_atlas_indices(&mut self, _atlas_indices: Vec<u16>) {}
+
+  /// Ambient occlusion of each vertex, from `0` (fully occluded) to `3` (not occluded), empty if
+  /// the mesh doesn't store it or it wasn't baked.
+  fn get_ambient_occlusion(&self) -> &[u8] {
+    &[]
+  }
+
+  /// Stores the ambient occlusion of each vertex, ignored by default.
+  fn set_ambient_occlusion(&mut self, _ambient_occlusion: Vec<u8>) {}
+
+  /// Texture atlas tile of each vertex as its lowest and highest uv (`[u0, v0, u1, v1]`), empty
+  /// if the mesh doesn't store them or the faces don't repeat its tile. The uvs of a face that
+  /// repeats its tile run past it, the shader wraps them with
+  /// `tile.xy + mod(uv - tile.xy, tile.zw - tile.xy)`.
+  fn get_tile_rects(&self) -> &[[f32; 4]] {
+    &[]
+  }
+
+  /// Stores the texture atlas tile of each vertex, ignored by default.
+  fn set_tile_rects(&mut self, _tile_rects: Vec<[f32; 4]>) {}
+
+  /// Multiplies every position by `scale`.
+  fn scale(&mut self, [x, y, z]: [f32; 3]) {
+    let positions = self
+      .get_positions()
+      .iter()
+      .map(|position| [position[0] * x, position[1] * y, position[2] * z])
+      .collect();
+
+    self.set_positions(positions);
+  }
 }
 
-/// Provides a `Meshify` implementation width indices, normals, positions and uvs.
-#[derive(Debug)]
+/// Provides a `Meshify` implementation width indices, normals, positions, uvs, atlas indices,
+/// ambient occlusion and tile rects.
+#[derive(Debug, Default)]
 pub struct Mesh {
   indices: Vec<u32>,
   normals: Vec<[f32; 3]>,
   positions: Vec<[f32; 3]>,
   uvs: Vec<[f32; 2]>,
-}
-
-impl Default for Mesh {
-  fn default() -> Self {
-    Self {
-      indices: vec![],
-      normals: vec![],
-      positions: vec![],
-      uvs: vec![],
-    }
-  }
+  atlas_indices: Vec<u16>,
+  ambient_occlusion: Vec<u8>,
+  tile_rects: Vec<[f32; 4]>,
 }
 
 impl Meshify for Mesh {
@@ -55,6 +92,9 @@ impl Meshify for Mesh {
       positions,
       normals,
       uvs,
+      atlas_indices: vec![],
+      ambient_occlusion: vec![],
+      tile_rects: vec![],
     }
   }
 
@@ -89,6 +129,30 @@ impl Meshify for Mesh {
   fn set_uvs(&mut self, uvs: Vec<[f32; 2]>) {
     self.uvs = uvs;
   }
+
+  fn get_atlas_indices(&self) -> &[u16] {
+    &self.atlas_indices
+  }
+
+  fn set_atlas_indices(&mut self, atlas_indices: Vec<u16>) {
+    self.atlas_indices = atlas_indices;
+  }
+
+  fn get_ambient_occlusion(&self) -> &[u8] {
+    &self.ambient_occlusion
+  }
+
+  fn set_ambient_occlusion(&mut self, ambient_occlusion: Vec<u8>) {
+    self.ambient_occlusion = ambient_occlusion;
+  }
+
+  fn get_tile_rects(&self) -> &[[f32; 4]] {
+    &self.tile_rects
+  }
+
+  fn set_tile_rects(&mut self, tile_rects: Vec<[f32; 4]>) {
+    self.tile_rects = tile_rects;
+  }
   /*
     /// This will generate a texture from the
     /// mesh vertex colors and update the UV map
@@ -173,6 +237,8 @@ struct MeshBuilderData {
   normal: Option<[f32; 3]>,
   uv: Option<[f32; 2]>,
   atlas_index: u16,
+  occlusion: Option<u8>,
+  tile_rect: Option<[f32; 4]>,
   index: u32,
 }
 
@@ -189,6 +255,8 @@ impl MeshBuilderData {
       normal,
       uv,
       atlas_index,
+      occlusion: None,
+      tile_rect: None,
       index,
     }
   }
@@ -227,31 +295,40 @@ struct MeshBuilderOctree {
   bucket: usize,
   node: MeshBuilderOctreeNode,
   split_at: usize,
+  tolerance: f32,
 }
 
 impl MeshBuilderOctree {
   fn new(boundary: Boundary, bucket: usize, split_at: usize) -> Self {
+    let size = boundary.size();
+
     Self {
       boundary,
       bucket,
       node: MeshBuilderOctreeNode::Leaf(vec![]),
       split_at,
+      tolerance: size.x.max(size.y).max(size.z) * EPSILON,
     }
   }
 
   fn insert(&mut self, leaf: &MeshBuilderData) -> InsertResult {
-    if self.boundary.contains(&leaf.position.into()) {
+    if self
+      .boundary
+      .expand(self.tolerance)
+      .contains(&leaf.position.into())
+    {
       match &mut self.node {
         MeshBuilderOctreeNode::Leaf(leafs) => {
-          let leaf_normal = if let Some(normal) = leaf.normal {
-            Some(Boundary::new(normal, [1e-5, 1e-5, 1e-5]))
-          } else {
-            None
-          };
+          let leaf_normal = leaf
+            .normal
+            .map(|normal| Boundary::new(normal, [1e-5, 1e-5, 1e-5]));
 
           for (data, position) in leafs.iter() {
             if position.contains(&leaf.position.into())
               && data.atlas_index == leaf.atlas_index
+              && data.uv == leaf.uv
+              && data.occlusion == leaf.occlusion
+              && data.tile_rect == leaf.tile_rect
               && if let (Some(leaf_normal), Some(data_normal)) = (leaf_normal.as_ref(), data.normal)
               {
                 leaf_normal.contains(&data_normal.into())
@@ -269,6 +346,9 @@ impl MeshBuilderOctree {
           if leafs.len() > self.split_at && self.bucket > 0 {
             let leafs = leafs.clone();
             let mut nodes = subdivide(&self.boundary, self.bucket, self.split_at);
+            for node in nodes.iter_mut() {
+              node.tolerance = self.tolerance;
+            }
             for (leaf, _) in leafs.iter() {
               for node in nodes.iter_mut() {
                 if let InsertResult::Inserted = node.insert(leaf) {
@@ -305,11 +385,9 @@ impl MeshBuilderOctree {
       MeshBuilderOctreeNode::Leaf(leafs) => {
         leafs.iter().map(|(d, _)| d.clone()).collect::<Vec<_>>()
       }
-      MeshBuilderOctreeNode::Subtree(nodes) => nodes
-        .iter()
-        .map(|n| n.get_all())
-        .flatten()
-        .collect::<Vec<_>>(),
+      MeshBuilderOctreeNode::Subtree(nodes) => {
+        nodes.iter().flat_map(|n| n.get_all()).collect::<Vec<_>>()
+      }
     }
   }
 }
@@ -324,19 +402,6 @@ impl std::fmt::Debug for MeshBuilderOctree {
   }
 }
 
-#[derive(Debug, Eq, Hash, PartialEq)]
-struct Position(i32, i32, i32);
-
-impl From<[f32; 3]> for Position {
-  fn from([x, y, z]: [f32; 3]) -> Self {
-    Position(
-      (x * 1_000_000.0) as i32,
-      (y * 1_000_000.0) as i32,
-      (z * 1_000_000.0) as i32,
-    )
-  }
-}
-
 /// Helper component that makes easy to build a triangle list mesh.
 #[derive(Debug)]
 pub struct MeshBuilder {
@@ -366,6 +431,10 @@ impl MeshBuilder {
     atlas_index: u16,
   ) {
     let mesh_data = MeshBuilderData::new(position, normal, uv, atlas_index, self.current_index);
+    self.insert(mesh_data);
+  }
+
+  fn insert(&mut self, mesh_data: MeshBuilderData) {
     match self.cache.insert(&mesh_data) {
       InsertResult::Inserted => {
         self.indices.push(self.current_index);
@@ -387,12 +456,7 @@ impl MeshBuilder {
     atlas_index: u16,
   ) {
     for (i, vertex) in triangle.iter().enumerate() {
-      self.add(
-        *vertex,
-        normal,
-        if let Some(uv) = uv { Some(uv[i]) } else { None },
-        atlas_index,
-      );
+      self.add(*vertex, normal, uv.map(|uv| uv[i]), atlas_index);
     }
   }
 
@@ -408,18 +472,70 @@ impl MeshBuilder {
   ) {
     [[0, 1, 3], [1, 2, 3]].iter().for_each(|triangle| {
       triangle.iter().for_each(|i| {
-        self.add(
+        self.add(face[*i], normal, uv.map(|uv| uv[*i]), atlas_index);
+      });
+    });
+  }
+
+  /// Inserts the face like `add_face` with the ambient occlusion of each vertex (see
+  /// `Meshify::get_ambient_occlusion`), the face is split through the diagonal with less
+  /// occlusion so the occlusion is interpolated the same way in every face.
+  pub fn add_face_with_occlusion(
+    &mut self,
+    face: [[f32; 3]; 4],
+    normal: Option<[f32; 3]>,
+    uv: Option<[[f32; 2]; 4]>,
+    atlas_index: u16,
+    occlusion: [u8; 4],
+  ) {
+    self.add_face_data(face, normal, uv, atlas_index, Some(occlusion), None);
+  }
+
+  /// Inserts a face that repeats the atlas tile `tile_rect` (see `Meshify::get_tile_rects`),
+  /// with the ambient occlusion of each vertex if any, its uvs are expected to start at the tile
+  /// and run a tile per repetition.
+  pub fn add_tiled_face(
+    &mut self,
+    face: [[f32; 3]; 4],
+    normal: Option<[f32; 3]>,
+    uv: Option<[[f32; 2]; 4]>,
+    atlas_index: u16,
+    occlusion: Option<[u8; 4]>,
+    tile_rect: Option<[f32; 4]>,
+  ) {
+    self.add_face_data(face, normal, uv, atlas_index, occlusion, tile_rect);
+  }
+
+  fn add_face_data(
+    &mut self,
+    face: [[f32; 3]; 4],
+    normal: Option<[f32; 3]>,
+    uv: Option<[[f32; 2]; 4]>,
+    atlas_index: u16,
+    occlusion: Option<[u8; 4]>,
+    tile_rect: Option<[f32; 4]>,
+  ) {
+    let triangles = match occlusion {
+      Some(occlusion) if occlusion[0] + occlusion[2] > occlusion[1] + occlusion[3] => {
+        [[0, 1, 2], [0, 2, 3]]
+      }
+      _ => [[0, 1, 3], [1, 2, 3]],
+    };
+
+    for triangle in triangles.iter() {
+      for i in triangle.iter() {
+        let mut mesh_data = MeshBuilderData::new(
           face[*i],
           normal,
-          if let Some(uv) = uv {
-            Some(uv[*i])
-          } else {
-            None
-          },
+          uv.map(|uv| uv[*i]),
           atlas_index,
+          self.current_index,
         );
-      });
-    });
+        mesh_data.occlusion = occlusion.map(|occlusion| occlusion[*i]);
+        mesh_data.tile_rect = tile_rect;
+        self.insert(mesh_data);
+      }
+    }
   }
 
   pub fn build<M>(&self) -> Option<M>
@@ -434,9 +550,21 @@ impl MeshBuilder {
       let mut positions = vec![];
       let mut normals = vec![];
       let mut uvs = vec![];
+      let mut atlas_indices = vec![];
+      let mut ambient_occlusion = vec![];
+      let mut tile_rects = vec![];
 
       for row in data.iter() {
         positions.push(row.position);
+        atlas_indices.push(row.atlas_index);
+
+        if let Some(occlusion) = row.occlusion {
+          ambient_occlusion.push(occlusion);
+        }
+
+        if let Some(tile_rect) = row.tile_rect {
+          tile_rects.push(tile_rect);
+        }
 
         if let Some(normal) = row.normal {
           normals.push(normal);
@@ -447,7 +575,12 @@ impl MeshBuilder {
         }
       }
 
-      Some(M::with(indices, positions, normals, uvs))
+      let mut mesh = M::with(indices, positions, normals, uvs);
+      mesh.set_atlas_indices(atlas_indices);
+      mesh.set_ambient_occlusion(ambient_occlusion);
+      mesh.set_tile_rects(tile_rects);
+
+      Some(mesh)
     } else {
       None
     }
@@ -460,19 +593,71 @@ impl Default for MeshBuilder {
   }
 }
 
+/// Ambient occlusion of the `face` vertices of the voxel at `position`, each vertex is occluded
+/// by the voxels in front of the face that touch it, the two at its sides and the one at its
+/// corner, if both sides are solid the vertex is fully occluded.
+pub fn face_occlusion<V, C>(
+  chunk: &C,
+  position: [isize; 3],
+  normal: [f32; 3],
+  face: &[[f32; 3]; 4],
+) -> [u8; 4]
+where
+  V: Voxel,
+  C: Chunkify<V>,
+{
+  let axis = normal.iter().position(|n| *n != 0.0).unwrap_or(0);
+  let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+  let mut front = position;
+  front[axis] += normal[axis] as isize;
+
+  let is_solid = |[x, y, z]: [isize; 3]| !chunk.is_air(x, y, z) as u8;
+  let mut occlusion = [3; 4];
+
+  for (index, vertex) in face.iter().enumerate() {
+    let step_u = if vertex[u] > position[u] as f32 {
+      1
+    } else {
+      -1
+    };
+    let step_v = if vertex[v] > position[v] as f32 {
+      1
+    } else {
+      -1
+    };
+
+    let mut side_u = front;
+    side_u[u] += step_u;
+    let mut side_v = front;
+    side_v[v] += step_v;
+    let mut corner = side_u;
+    corner[v] += step_v;
+
+    let (side_u, side_v, corner) = (is_solid(side_u), is_solid(side_v), is_solid(corner));
+
+    occlusion[index] = if side_u == 1 && side_v == 1 {
+      0
+    } else {
+      3 - side_u - side_v - corner
+    };
+  }
+
+  occlusion
+}
+
 #[allow(clippy::many_single_char_names)]
 fn subdivide(boundary: &Boundary, bucket: usize, split_at: usize) -> Box<[MeshBuilderOctree; 8]> {
-  let w = boundary.size.x / 2.0;
-  let h = boundary.size.y / 2.0;
-  let d = boundary.size.z / 2.0;
+  let w = boundary.size().x / 2.0;
+  let h = boundary.size().y / 2.0;
+  let d = boundary.size().z / 2.0;
   let size: [f32; 3] = [w, h, d];
   let hw = size[0] / 2.0;
   let hh = size[1] / 2.0;
   let hd = size[2] / 2.0;
 
-  let x = boundary.center.x;
-  let y = boundary.center.y;
-  let z = boundary.center.z;
+  let x = boundary.center().x;
+  let y = boundary.center().y;
+  let z = boundary.center().z;
 
   let new_bucket = bucket - 1;
 
@@ -583,4 +768,59 @@ mod test {
 
     assert_eq!(tree.get_all().len(), 1);
   }
+
+  #[test]
+  fn test_octree_insert_near_split() {
+    let mut tree = MeshBuilderOctree::new(Boundary::new([8.0, 8.0, 8.0], [18.0, 18.0, 18.0]), 3, 0);
+
+    tree.insert(&MeshBuilderData::new([1.0, 10.0, 13.0], None, None, 0, 0));
+
+    // Below the split plane at `y = 8`, but inside the tolerance of the node above it
+    match tree.insert(&MeshBuilderData::new(
+      [1.0, 7.99994, 14.0],
+      None,
+      None,
+      0,
+      1,
+    )) {
+      InsertResult::Inserted => {}
+      _ => panic!("Failed to insert {:#?}", &tree),
+    }
+
+    assert_eq!(tree.get_all().len(), 2);
+  }
+
+  #[test]
+  fn test_face_with_occlusion() {
+    let face = [
+      [0.0, 0.0, 0.0],
+      [1.0, 0.0, 0.0],
+      [1.0, 1.0, 0.0],
+      [0.0, 1.0, 0.0],
+    ];
+
+    let mut builder = MeshBuilder::default();
+    builder.add_face_with_occlusion(face, Some([0.0, 0.0, 1.0]), None, 0, [0, 3, 3, 3]);
+    let mesh = builder.build::<Mesh>().unwrap();
+
+    assert_eq!(mesh.get_indices(), &vec![0, 1, 2, 1, 3, 2]);
+    assert_eq!(mesh.get_ambient_occlusion(), &[0, 3, 3, 3]);
+
+    // The darker vertex is on the default diagonal, so the face is flipped
+    let mut builder = MeshBuilder::default();
+    builder.add_face_with_occlusion(face, Some([0.0, 0.0, 1.0]), None, 0, [3, 0, 3, 3]);
+    let mesh = builder.build::<Mesh>().unwrap();
+
+    assert_eq!(mesh.get_indices(), &vec![0, 1, 2, 0, 2, 3]);
+    assert_eq!(mesh.get_ambient_occlusion(), &[3, 0, 3, 3]);
+
+    let mut builder = MeshBuilder::default();
+    builder.add_face(face, Some([0.0, 0.0, 1.0]), None, 0);
+
+    assert!(builder
+      .build::<Mesh>()
+      .unwrap()
+      .get_ambient_occlusion()
+      .is_empty());
+  }
 }
@@ -0,0 +1,259 @@
+use crate::{boundary::Boundary, boxify::*, chunk::Chunkify, voxel::Voxel};
+
+/// Voxel hit by a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RaycastHit<V> {
+  /// Coordinates of the voxel hit.
+  pub position: [isize; 3],
+  /// Normal of the face hit, `position + normal` is the voxel the ray comes from, it is zero
+  /// when the ray starts inside the voxel.
+  pub normal: [isize; 3],
+  /// Distance from the ray origin to the face hit.
+  pub distance: f32,
+  /// Value of the voxel hit.
+  pub value: V,
+}
+
+/// Walks the voxels crossed by the ray in order (Amanatides & Woo DDA) until `visit` returns a
+/// value or `max_distance` is reached, the voxel `[x, y, z]` covers from `x` to `x + 1`.
+///
+/// `visit` gets the coordinates of each voxel and returns the value to hit it. A `max_distance`
+/// that is not finite never hits anything, the walk would not end, use `exit_distance` to cap it.
+pub fn traverse<V, F>(
+  origin: [f32; 3],
+  direction: [f32; 3],
+  max_distance: f32,
+  mut visit: F,
+) -> Option<RaycastHit<V>>
+where
+  F: FnMut([isize; 3]) -> Option<V>,
+{
+  let length = direction
+    .iter()
+    .map(|value| value * value)
+    .sum::<f32>()
+    .sqrt();
+  if length == 0.0 || !length.is_finite() || !max_distance.is_finite() {
+    return None;
+  }
+
+  let direction = direction.map(|value| value / length);
+  let mut position = origin.map(|value| value.floor() as isize);
+  let mut step = [0; 3];
+  let mut t_max = [f32::INFINITY; 3];
+  let mut t_delta = [f32::INFINITY; 3];
+
+  for axis in 0..3 {
+    if direction[axis] > 0.0 {
+      step[axis] = 1;
+      t_delta[axis] = 1.0 / direction[axis];
+      t_max[axis] = (position[axis] as f32 + 1.0 - origin[axis]) * t_delta[axis];
+    } else if direction[axis] < 0.0 {
+      step[axis] = -1;
+      t_delta[axis] = -1.0 / direction[axis];
+      t_max[axis] = (origin[axis] - position[axis] as f32) * t_delta[axis];
+    }
+  }
+
+  let mut normal = [0; 3];
+  let mut distance = 0.0;
+
+  while distance <= max_distance {
+    if let Some(value) = visit(position) {
+      return Some(RaycastHit {
+        position,
+        normal,
+        distance,
+        value,
+      });
+    }
+
+    let axis = if t_max[0] < t_max[1] {
+      if t_max[0] < t_max[2] {
+        0
+      } else {
+        2
+      }
+    } else if t_max[1] < t_max[2] {
+      1
+    } else {
+      2
+    };
+
+    distance = t_max[axis];
+    position[axis] += step[axis];
+    t_max[axis] += t_delta[axis];
+    normal = [0; 3];
+    normal[axis] = -step[axis];
+  }
+
+  None
+}
+
+/// Distance along the ray at which it leaves `boundary`, or `None` if it never crosses it.
+pub fn exit_distance(origin: [f32; 3], direction: [f32; 3], boundary: &Boundary) -> Option<f32> {
+  let length = direction
+    .iter()
+    .map(|value| value * value)
+    .sum::<f32>()
+    .sqrt();
+  let start: [f32; 3] = boundary.start().into();
+  let end: [f32; 3] = boundary.end().into();
+  let mut enter = 0.0f32;
+  let mut exit = f32::INFINITY;
+
+  for axis in 0..3 {
+    let direction = direction[axis] / length;
+
+    if direction == 0.0 {
+      if origin[axis] < start[axis] || origin[axis] > end[axis] {
+        return None;
+      }
+    } else {
+      let a = (start[axis] - origin[axis]) / direction;
+      let b = (end[axis] - origin[axis]) / direction;
+      enter = enter.max(a.min(b));
+      exit = exit.min(a.max(b));
+    }
+  }
+
+  if enter <= exit && exit.is_finite() {
+    Some(exit)
+  } else {
+    None
+  }
+}
+
+/// Casts a ray against the voxels of `chunk`, in chunk coordinates, returning the first voxel
+/// that `is_solid`. The ray stops when it leaves the chunk and its neighbor data.
+pub fn raycast<V, C, F>(
+  chunk: &C,
+  origin: [f32; 3],
+  direction: [f32; 3],
+  max_distance: f32,
+  is_solid: F,
+) -> Option<RaycastHit<V>>
+where
+  V: Voxel,
+  C: Chunkify<V> + Sizable,
+  F: Fn(&V) -> bool,
+{
+  let boundary = Boundary::from_start_end(
+    [-1.0, -1.0, -1.0],
+    [
+      chunk.width() as f32 + 1.0,
+      chunk.height() as f32 + 1.0,
+      chunk.depth() as f32 + 1.0,
+    ],
+  );
+  let max_distance = max_distance.min(exit_distance(origin, direction, &boundary)?);
+
+  traverse(origin, direction, max_distance, |[x, y, z]| {
+    let value = chunk.get(x, y, z);
+
+    if is_solid(&value) {
+      Some(value)
+    } else {
+      None
+    }
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::chunk::{Chunk, ChunkifyMut};
+
+  #[test]
+  fn check_raycast() {
+    let mut chunk = Chunk::new([0.0, 0.0, 0.0], 8, 8, 8);
+    chunk.set(3, 0, 0, (1, 255));
+    chunk.set(2, 2, 2, (2, 255));
+
+    let is_solid = |value: &(u8, u8)| !value.is_empty();
+
+    let hit = raycast(&chunk, [0.5, 0.5, 0.5], [1.0, 0.0, 0.0], 10.0, is_solid).unwrap();
+    assert_eq!(hit.position, [3, 0, 0]);
+    assert_eq!(hit.normal, [-1, 0, 0]);
+    assert_eq!(hit.distance, 2.5);
+    assert_eq!(hit.value, (1, 255));
+
+    assert!(raycast(&chunk, [0.5, 0.5, 0.5], [1.0, 0.0, 0.0], 2.0, is_solid).is_none());
+    assert!(raycast(&chunk, [0.5, 0.5, 0.5], [0.0, 0.0, 0.0], 2.0, is_solid).is_none());
+
+    let hit = raycast(&chunk, [0.5, 0.5, 0.5], [1.0, 1.0, 1.0], 10.0, is_solid).unwrap();
+    assert_eq!(hit.position, [2, 2, 2]);
+    assert!((hit.distance - 1.5 * 3f32.sqrt()).abs() < 1e-5);
+
+    let hit = raycast(&chunk, [2.5, 9.5, 2.5], [0.0, -1.0, 0.0], 10.0, is_solid).unwrap();
+    assert_eq!(hit.position, [2, 2, 2]);
+    assert_eq!(hit.normal, [0, 1, 0]);
+    assert_eq!(hit.distance, 6.5);
+
+    // Starting inside a solid voxel
+    let hit = raycast(&chunk, [3.5, 0.5, 0.5], [0.0, 0.0, 1.0], 10.0, is_solid).unwrap();
+    assert_eq!(hit.normal, [0, 0, 0]);
+    assert_eq!(hit.distance, 0.0);
+
+    // Custom predicate
+    let hit = raycast(&chunk, [0.5, 0.5, 0.5], [1.0, 1.0, 1.0], 10.0, |value| {
+      value.0 == 1
+    });
+    assert!(hit.is_none());
+
+    // An endless ray stops at the border of the chunk
+    let hit = raycast(
+      &chunk,
+      [0.5, 0.5, 0.5],
+      [0.0, 0.0, 1.0],
+      f32::INFINITY,
+      is_solid,
+    );
+    assert!(hit.is_none());
+    let hit = raycast(
+      &chunk,
+      [-5.5, 0.5, 0.5],
+      [1.0, 0.0, 0.0],
+      f32::INFINITY,
+      is_solid,
+    );
+    assert_eq!(hit.unwrap().distance, 8.5);
+    assert!(raycast(
+      &chunk,
+      [-5.5, 20.5, 0.5],
+      [1.0, 0.0, 0.0],
+      f32::INFINITY,
+      is_solid
+    )
+    .is_none());
+    assert!(traverse(
+      [0.5, 0.5, 0.5],
+      [1.0, 0.0, 0.0],
+      f32::INFINITY,
+      |_| None::<(u8, u8)>
+    )
+    .is_none());
+  }
+
+  #[test]
+  fn check_exit_distance() {
+    let boundary = Boundary::from_start_end([0.0, 0.0, 0.0], [4.0, 4.0, 4.0]);
+
+    assert_eq!(
+      exit_distance([1.0, 1.0, 1.0], [2.0, 0.0, 0.0], &boundary),
+      Some(3.0)
+    );
+    assert_eq!(
+      exit_distance([-2.0, 1.0, 1.0], [1.0, 0.0, 0.0], &boundary),
+      Some(6.0)
+    );
+    assert_eq!(
+      exit_distance([-2.0, 5.0, 1.0], [1.0, 0.0, 0.0], &boundary),
+      None
+    );
+    assert_eq!(
+      exit_distance([6.0, 1.0, 1.0], [1.0, 0.0, 0.0], &boundary),
+      None
+    );
+  }
+}
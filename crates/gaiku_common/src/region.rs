@@ -0,0 +1,627 @@
+use std::{
+  convert::TryFrom,
+  fs::{self, File, OpenOptions},
+  io::{Read, Seek, SeekFrom, Write},
+  path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use flate2::{read::DeflateDecoder, write::DeflateEncoder};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{boxify::*, Gaiku};
+
+const MAGIC: &[u8; 4] = b"GKRG";
+const VERSION: u32 = 2;
+const HEADER_SIZE: u64 = 12;
+const ENTRY_SIZE: u64 = 16;
+/// Entries per index page, the pages are only allocated when a chunk of them is saved.
+const PAGE_ENTRIES: usize = 64;
+const PAGE_SIZE: u64 = ENTRY_SIZE * PAGE_ENTRIES as u64;
+/// Largest number of chunks per axis of a region, the page table of a region file is written
+/// whole when it's created.
+const MAX_REGION_SIZE: usize = 256;
+
+/// Compression applied to each chunk stored in a region file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+  None,
+  Deflate,
+}
+
+impl Compression {
+  fn from_flag(flag: u8) -> Result<Self> {
+    match flag {
+      0 => Ok(Compression::None),
+      1 => Ok(Compression::Deflate),
+      _ => bail!("Unknown region chunk compression {}", flag),
+    }
+  }
+
+  fn flag(self) -> u8 {
+    match self {
+      Compression::None => 0,
+      Compression::Deflate => 1,
+    }
+  }
+}
+
+/// Location of a chunk inside its region file.
+#[derive(Clone, Copy, Debug, Default)]
+struct Entry {
+  offset: u64,
+  length: u32,
+  compression: u8,
+}
+
+impl Entry {
+  fn read(bytes: &[u8]) -> Self {
+    let mut offset = [0; 8];
+    let mut length = [0; 4];
+    offset.copy_from_slice(&bytes[0..8]);
+    length.copy_from_slice(&bytes[8..12]);
+
+    Self {
+      offset: u64::from_le_bytes(offset),
+      length: u32::from_le_bytes(length),
+      compression: bytes[12],
+    }
+  }
+
+  fn to_bytes(self) -> [u8; ENTRY_SIZE as usize] {
+    let mut bytes = [0; ENTRY_SIZE as usize];
+    bytes[0..8].copy_from_slice(&self.offset.to_le_bytes());
+    bytes[8..12].copy_from_slice(&self.length.to_le_bytes());
+    bytes[12] = self.compression;
+
+    bytes
+  }
+}
+
+/// Stores the chunks of a world in region files, each region file groups `region_size` chunks
+/// per axis and has an index of where each chunk is stored, so single chunks can be loaded and
+/// saved without reading the whole region.
+///
+/// Saving a chunk appends its data to the region file before updating the index entry, so an
+/// interrupted save leaves the previous version of the chunk, the space of the replaced
+/// versions can be reclaimed with `compact_region`.
+///
+/// The region file layout is the magic `GKRG`, the version and the region size (`u32` little
+/// endian), followed by the offset (`u64`) of each index page, `0` for the pages not allocated
+/// yet. Each page has the entries of 64 chunks (offset `u64`, length `u32`, compression `u8`
+/// and 3 bytes of padding) ordered by `x`, `y` and `z`, and it's appended to the file with the
+/// first chunk saved in it, so a sparse region only stores the index of the chunks around the
+/// saved ones. The chunk data is serialized with `bincode`.
+#[derive(Clone, Debug)]
+pub struct RegionStore {
+  directory: PathBuf,
+  chunk_size: [u16; 3],
+  region_size: usize,
+  compression: Compression,
+}
+
+impl RegionStore {
+  /// Creates a store saving the region files in `directory`, the chunks are expected to be in a
+  /// grid of `chunk_size` voxels with its position in voxel units (as in `Gaiku`).
+  pub fn new<P>(directory: P, chunk_size: [u16; 3]) -> Self
+  where
+    P: AsRef<Path>,
+  {
+    Self {
+      directory: directory.as_ref().to_path_buf(),
+      chunk_size,
+      region_size: 32,
+      compression: Compression::Deflate,
+    }
+  }
+
+  /// Sets the number of chunks per axis of each region file, it must match the existing files.
+  /// It's clamped between 1 and 256.
+  pub fn with_region_size(mut self, region_size: usize) -> Self {
+    self.region_size = region_size.clamp(1, MAX_REGION_SIZE);
+    self
+  }
+
+  /// Sets the compression used when saving chunks, the chunks already saved keep theirs.
+  pub fn with_compression(mut self, compression: Compression) -> Self {
+    self.compression = compression;
+    self
+  }
+
+  /// Coordinates of the region holding the chunk at `position`.
+  pub fn region(&self, position: [f32; 3]) -> [isize; 3] {
+    let (region, _) = self.locate(position);
+    region
+  }
+
+  /// Path of the file of the region at `region`.
+  pub fn region_path(&self, [x, y, z]: [isize; 3]) -> PathBuf {
+    self.directory.join(format!("r.{}.{}.{}.gkr", x, y, z))
+  }
+
+  /// Saves the chunk, replacing the previous version.
+  pub fn save_chunk<C>(&self, chunk: &C) -> Result<()>
+  where
+    C: Boxify + Serialize,
+  {
+    let (region, index) = self.locate(chunk.position());
+    let mut file = self.open_region(region, true)?.unwrap();
+
+    let bytes = bincode::serialize(chunk)?;
+    let bytes = match self.compression {
+      Compression::None => bytes,
+      Compression::Deflate => {
+        let mut encoder = DeflateEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&bytes)?;
+        encoder.finish()?
+      }
+    };
+    let length = u32::try_from(bytes.len())?;
+
+    let offset = file.seek(SeekFrom::End(0))?;
+    file.write_all(&bytes)?;
+    file.sync_data()?;
+
+    if self.read_page(&mut file, index)? == 0 {
+      let page = file.seek(SeekFrom::End(0))?;
+      file.write_all(&[0; PAGE_SIZE as usize])?;
+      file.sync_data()?;
+      self.write_page(&mut file, index, page)?;
+    }
+
+    let entry = Entry {
+      offset,
+      length,
+      compression: self.compression.flag(),
+    };
+    self.write_entry(&mut file, index, entry)
+  }
+
+  /// Loads the chunk at `position`, `None` if it was never saved.
+  pub fn load_chunk<C>(&self, position: [f32; 3]) -> Result<Option<C>>
+  where
+    C: DeserializeOwned,
+  {
+    let (region, index) = self.locate(position);
+    let mut file = match self.open_region(region, false)? {
+      Some(file) => file,
+      None => return Ok(None),
+    };
+
+    let entry = self.read_entry(&mut file, index)?;
+    if entry.length == 0 {
+      return Ok(None);
+    }
+
+    let mut bytes = vec![0; entry.length as usize];
+    file.seek(SeekFrom::Start(entry.offset))?;
+    file.read_exact(&mut bytes)?;
+
+    let bytes = match Compression::from_flag(entry.compression)? {
+      Compression::None => bytes,
+      Compression::Deflate => {
+        let mut result = vec![];
+        DeflateDecoder::new(&bytes[..]).read_to_end(&mut result)?;
+        result
+      }
+    };
+
+    Ok(Some(bincode::deserialize(&bytes)?))
+  }
+
+  /// Removes the chunk at `position`, returns `false` if it wasn't saved.
+  pub fn remove_chunk(&self, position: [f32; 3]) -> Result<bool> {
+    let (region, index) = self.locate(position);
+    let mut file = match self.open_region(region, false)? {
+      Some(file) => file,
+      None => return Ok(false),
+    };
+
+    if self.read_entry(&mut file, index)?.length == 0 {
+      return Ok(false);
+    }
+
+    self.write_entry(&mut file, index, Entry::default())?;
+    Ok(true)
+  }
+
+  /// Saves every chunk of the world.
+  pub fn save_world<C>(&self, world: &Gaiku<C>) -> Result<()>
+  where
+    C: Boxify + Serialize,
+  {
+    for chunk in world.chunks() {
+      self.save_chunk(chunk)?;
+    }
+
+    Ok(())
+  }
+
+  /// Coordinates of the regions with a file in the store directory.
+  pub fn regions(&self) -> Result<Vec<[isize; 3]>> {
+    let mut result = vec![];
+    if !self.directory.exists() {
+      return Ok(result);
+    }
+
+    for entry in fs::read_dir(&self.directory)? {
+      let name = entry?.file_name();
+      let name = match name.to_str() {
+        Some(name) => name,
+        None => continue,
+      };
+
+      let coords = name
+        .strip_prefix("r.")
+        .and_then(|name| name.strip_suffix(".gkr"))
+        .map(|name| name.split('.').map(|coord| coord.parse::<isize>()));
+
+      if let Some(coords) = coords {
+        if let Ok(coords) = coords.collect::<std::result::Result<Vec<_>, _>>() {
+          if let [x, y, z] = coords[..] {
+            result.push([x, y, z]);
+          }
+        }
+      }
+    }
+
+    Ok(result)
+  }
+
+  /// Positions of the chunks saved in the region at `region`.
+  pub fn chunks(&self, region: [isize; 3]) -> Result<Vec<[f32; 3]>> {
+    let mut file = match self.open_region(region, false)? {
+      Some(file) => file,
+      None => return Ok(vec![]),
+    };
+
+    let result = self
+      .read_index(&mut file)?
+      .iter()
+      .enumerate()
+      .filter(|(_, entry)| entry.length != 0)
+      .map(|(index, _)| self.position(region, index))
+      .collect();
+
+    Ok(result)
+  }
+
+  /// Rewrites the region file without the space of the replaced or removed chunks, the new file
+  /// is written aside and then renamed over the old one.
+  pub fn compact_region(&self, region: [isize; 3]) -> Result<()> {
+    let mut file = match self.open_region(region, false)? {
+      Some(file) => file,
+      None => return Ok(()),
+    };
+
+    let mut index = self.read_index(&mut file)?;
+    let mut data = vec![];
+
+    for entry in index.iter_mut().filter(|entry| entry.length != 0) {
+      let mut bytes = vec![0; entry.length as usize];
+      file.seek(SeekFrom::Start(entry.offset))?;
+      file.read_exact(&mut bytes)?;
+
+      entry.offset = data.len() as u64;
+      data.extend(bytes);
+    }
+    drop(file);
+
+    // Only the pages with chunks are kept, placed after the page table and before the data
+    let pages = index
+      .chunks(PAGE_ENTRIES)
+      .map(|page| page.iter().any(|entry| entry.length != 0))
+      .collect::<Vec<_>>();
+    let mut offset = HEADER_SIZE + 8 * pages.len() as u64;
+    let mut table = vec![0; pages.len()];
+    for (page, used) in pages.iter().enumerate() {
+      if *used {
+        table[page] = offset;
+        offset += PAGE_SIZE;
+      }
+    }
+
+    let path = self.region_path(region);
+    let temp = path.with_extension("gkr.tmp");
+    let mut output = File::create(&temp)?;
+    self.write_header(&mut output)?;
+    for page in table {
+      output.write_all(&page.to_le_bytes())?;
+    }
+    for (page, entries) in index.chunks(PAGE_ENTRIES).enumerate() {
+      if !pages[page] {
+        continue;
+      }
+
+      for entry in entries {
+        let entry = if entry.length == 0 {
+          *entry
+        } else {
+          Entry {
+            offset: offset + entry.offset,
+            ..*entry
+          }
+        };
+        output.write_all(&entry.to_bytes())?;
+      }
+      for _ in entries.len()..PAGE_ENTRIES {
+        output.write_all(&Entry::default().to_bytes())?;
+      }
+    }
+    output.write_all(&data)?;
+    output.sync_all()?;
+    drop(output);
+
+    fs::rename(temp, path)?;
+    Ok(())
+  }
+
+  fn entries(&self) -> usize {
+    self.region_size * self.region_size * self.region_size
+  }
+
+  fn pages(&self) -> usize {
+    self.entries().div_ceil(PAGE_ENTRIES)
+  }
+
+  /// Position of the chunk at the entry `index` of the region at `region`, the inverse of
+  /// `locate`.
+  fn position(&self, region: [isize; 3], index: usize) -> [f32; 3] {
+    let size = self.region_size;
+    let local = [index % size, (index / size) % size, index / (size * size)];
+
+    [0, 1, 2].map(|axis| {
+      let coords = region[axis] * size as isize + local[axis] as isize;
+      (coords * self.chunk_size[axis] as isize) as f32
+    })
+  }
+
+  /// Returns the region coordinates and the entry index of the chunk at `position`.
+  fn locate(&self, position: [f32; 3]) -> ([isize; 3], usize) {
+    let size = self.region_size as isize;
+    let mut region = [0; 3];
+    let mut index = 0;
+
+    for axis in (0..3).rev() {
+      let coords = (position[axis] / self.chunk_size[axis] as f32).floor() as isize;
+      region[axis] = coords.div_euclid(size);
+      index = index * self.region_size + coords.rem_euclid(size) as usize;
+    }
+
+    (region, index)
+  }
+
+  /// Opens the region file, creating it when `create` is `true`.
+  fn open_region(&self, region: [isize; 3], create: bool) -> Result<Option<File>> {
+    let path = self.region_path(region);
+
+    if !path.exists() {
+      if !create {
+        return Ok(None);
+      }
+
+      fs::create_dir_all(&self.directory)?;
+      let mut file = File::create(&path)?;
+      self.write_header(&mut file)?;
+      file.write_all(&vec![0; 8 * self.pages()])?;
+      file.sync_all()?;
+    }
+
+    let mut file = OpenOptions::new().read(true).write(true).open(&path)?;
+    let mut header = [0; HEADER_SIZE as usize];
+    file.read_exact(&mut header)?;
+
+    let mut version = [0; 4];
+    let mut region_size = [0; 4];
+    version.copy_from_slice(&header[4..8]);
+    region_size.copy_from_slice(&header[8..12]);
+
+    if &header[0..4] != MAGIC || u32::from_le_bytes(version) != VERSION {
+      bail!("{} isn't a gaiku region file", path.display());
+    }
+
+    if u32::from_le_bytes(region_size) as usize != self.region_size {
+      bail!(
+        "{} has a region size of {} chunks instead of {}",
+        path.display(),
+        u32::from_le_bytes(region_size),
+        self.region_size
+      );
+    }
+
+    Ok(Some(file))
+  }
+
+  fn write_header(&self, file: &mut File) -> Result<()> {
+    file.write_all(MAGIC)?;
+    file.write_all(&VERSION.to_le_bytes())?;
+    file.write_all(&(self.region_size as u32).to_le_bytes())?;
+
+    Ok(())
+  }
+
+  /// Reads all the entries of the region, a page at a time.
+  fn read_index(&self, file: &mut File) -> Result<Vec<Entry>> {
+    let mut table = vec![0; 8 * self.pages()];
+    file.seek(SeekFrom::Start(HEADER_SIZE))?;
+    file.read_exact(&mut table)?;
+
+    let mut result = vec![Entry::default(); self.entries()];
+    let mut bytes = [0; PAGE_SIZE as usize];
+    for (page, entries) in result.chunks_mut(PAGE_ENTRIES).enumerate() {
+      let mut offset = [0; 8];
+      offset.copy_from_slice(&table[page * 8..page * 8 + 8]);
+      let offset = u64::from_le_bytes(offset);
+      if offset == 0 {
+        continue;
+      }
+
+      file.seek(SeekFrom::Start(offset))?;
+      file.read_exact(&mut bytes)?;
+      for (index, entry) in entries.iter_mut().enumerate() {
+        *entry = Entry::read(&bytes[index * ENTRY_SIZE as usize..]);
+      }
+    }
+
+    Ok(result)
+  }
+
+  /// Offset of the index page holding the entry `index`, `0` if it isn't allocated.
+  fn read_page(&self, file: &mut File, index: usize) -> Result<u64> {
+    let mut bytes = [0; 8];
+    file.seek(SeekFrom::Start(
+      HEADER_SIZE + 8 * (index / PAGE_ENTRIES) as u64,
+    ))?;
+    file.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+  }
+
+  fn write_page(&self, file: &mut File, index: usize, offset: u64) -> Result<()> {
+    file.seek(SeekFrom::Start(
+      HEADER_SIZE + 8 * (index / PAGE_ENTRIES) as u64,
+    ))?;
+    file.write_all(&offset.to_le_bytes())?;
+    file.sync_data()?;
+
+    Ok(())
+  }
+
+  fn read_entry(&self, file: &mut File, index: usize) -> Result<Entry> {
+    let page = self.read_page(file, index)?;
+    if page == 0 {
+      return Ok(Entry::default());
+    }
+
+    let mut bytes = [0; ENTRY_SIZE as usize];
+    file.seek(SeekFrom::Start(
+      page + ENTRY_SIZE * (index % PAGE_ENTRIES) as u64,
+    ))?;
+    file.read_exact(&mut bytes)?;
+
+    Ok(Entry::read(&bytes))
+  }
+
+  /// Writes the entry `index`, its page must be allocated.
+  fn write_entry(&self, file: &mut File, index: usize, entry: Entry) -> Result<()> {
+    let page = self.read_page(file, index)?;
+    file.seek(SeekFrom::Start(
+      page + ENTRY_SIZE * (index % PAGE_ENTRIES) as u64,
+    ))?;
+    file.write_all(&entry.to_bytes())?;
+    file.sync_data()?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::chunk::{Chunk, Chunkify, ChunkifyMut, SparseChunk};
+
+  fn directory(name: &str) -> PathBuf {
+    let directory =
+      std::env::temp_dir().join(format!("gaiku_region_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+
+    directory
+  }
+
+  #[test]
+  fn check_save_load() {
+    let directory = directory("save_load");
+    let store = RegionStore::new(&directory, [16, 16, 16]).with_region_size(4);
+
+    let mut chunk = Chunk::new([-16.0, 32.0, 48.0], 16, 16, 16);
+    chunk.set(1, 2, 3, (4, 255));
+    store.save_chunk(&chunk).unwrap();
+
+    assert_eq!(store.region([-16.0, 32.0, 48.0]), [-1, 0, 0]);
+    assert!(store.region_path([-1, 0, 0]).exists());
+    assert!(store
+      .load_chunk::<Chunk>([0.0, 32.0, 48.0])
+      .unwrap()
+      .is_none());
+    assert!(store
+      .load_chunk::<Chunk>([0.0, 0.0, 256.0])
+      .unwrap()
+      .is_none());
+
+    let loaded: Chunk = store.load_chunk([-16.0, 32.0, 48.0]).unwrap().unwrap();
+    assert_eq!(loaded.position(), [-16.0, 32.0, 48.0]);
+    assert_eq!(loaded.values(), chunk.values());
+
+    // Replacing a chunk with another compression
+    let store = store.with_compression(Compression::None);
+    chunk.set(1, 2, 3, (5, 255));
+    store.save_chunk(&chunk).unwrap();
+    let loaded: Chunk = store.load_chunk([-16.0, 32.0, 48.0]).unwrap().unwrap();
+    assert_eq!(loaded.get(1, 2, 3), (5, 255));
+
+    let length = fs::metadata(store.region_path([-1, 0, 0])).unwrap().len();
+    store.compact_region([-1, 0, 0]).unwrap();
+    assert!(fs::metadata(store.region_path([-1, 0, 0])).unwrap().len() < length);
+    let loaded: Chunk = store.load_chunk([-16.0, 32.0, 48.0]).unwrap().unwrap();
+    assert_eq!(loaded.get(1, 2, 3), (5, 255));
+
+    assert!(store.remove_chunk([-16.0, 32.0, 48.0]).unwrap());
+    assert!(!store.remove_chunk([-16.0, 32.0, 48.0]).unwrap());
+    assert!(store
+      .load_chunk::<Chunk>([-16.0, 32.0, 48.0])
+      .unwrap()
+      .is_none());
+
+    // The region size is part of the file
+    let store = RegionStore::new(&directory, [16, 16, 16]);
+    assert!(store.load_chunk::<Chunk>([-16.0, 32.0, 48.0]).is_err());
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn check_region_size() {
+    let store = RegionStore::new("regions", [16, 16, 16]);
+
+    assert_eq!(store.clone().with_region_size(0).region_size, 1);
+    assert_eq!(store.clone().with_region_size(8).region_size, 8);
+    assert_eq!(
+      store.with_region_size(usize::MAX).entries(),
+      MAX_REGION_SIZE.pow(3)
+    );
+  }
+
+  #[test]
+  fn check_save_world() {
+    let directory = directory("save_world");
+    let store = RegionStore::new(&directory, [4, 4, 4]);
+
+    let mut world: Gaiku<SparseChunk> = Gaiku::new(vec![], [64.0, 64.0, 64.0], [4, 4, 4]);
+    world.set_voxel([1, 1, 1], (1, 255));
+    world.set_voxel([200, -1, 1], (2, 255));
+    store.save_world(&world).unwrap();
+
+    // Only the index pages around the saved chunks are allocated
+    let length = fs::metadata(store.region_path([0, 0, 0])).unwrap().len();
+    assert!(length < 8 * 1024);
+
+    let mut regions = store.regions().unwrap();
+    regions.sort_unstable();
+    assert_eq!(regions, vec![[0, 0, 0], [1, -1, 0]]);
+    assert_eq!(store.chunks([0, 0, 0]).unwrap(), vec![[0.0, 0.0, 0.0]]);
+    assert!(store.chunks([5, 0, 0]).unwrap().is_empty());
+
+    let chunks = regions
+      .iter()
+      .flat_map(|region| store.chunks(*region).unwrap())
+      .map(|position| store.load_chunk(position).unwrap().unwrap())
+      .collect::<Vec<SparseChunk>>();
+    assert_eq!(chunks.len(), world.len());
+    let world = Gaiku::new(chunks, [64.0, 64.0, 64.0], [4, 4, 4]);
+
+    assert_eq!(world.get_voxel::<(u8, u8)>([1, 1, 1]), (1, 255));
+    assert_eq!(world.get_voxel::<(u8, u8)>([200, -1, 1]), (2, 255));
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+}
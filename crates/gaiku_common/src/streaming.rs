@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+
+/// Options to customize the `Streamer` behaviour, all the distances are in voxel units.
+#[derive(Clone, Debug)]
+pub struct StreamerOptions {
+  /// Size of the chunks of the world grid.
+  pub chunk_size: [u16; 3],
+  /// Chunks with its center closer than this to a viewer are loaded.
+  pub view_radius: f32,
+  /// Extra distance a chunk can be away from the view radius before being unloaded, so the
+  /// chunks near the edge aren't loaded and unloaded each time a viewer moves a bit.
+  pub unload_margin: f32,
+  /// Maximum number of load and bake tasks returned by each update, `0` means no limit.
+  pub max_tasks: usize,
+}
+
+impl Default for StreamerOptions {
+  fn default() -> Self {
+    Self {
+      chunk_size: [16, 16, 16],
+      view_radius: 128.0,
+      unload_margin: 16.0,
+      max_tasks: 0,
+    }
+  }
+}
+
+/// Work the caller must do for a chunk, the chunk is identified by its coordinates in the chunk
+/// grid, `Streamer::position` returns its position in voxel units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamerTask {
+  /// Load the chunk from storage or generate it, then call `Streamer::set_loaded`.
+  Load([isize; 3]),
+  /// Bake the chunk mesh, then call `Streamer::set_baked`.
+  Bake([isize; 3]),
+  /// Drop the chunk and its mesh, the streamer already forgot it.
+  Unload([isize; 3]),
+}
+
+/// State of a chunk tracked by the `Streamer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChunkState {
+  Loading,
+  Loaded,
+  Baking,
+  Baked,
+}
+
+/// Decides which chunks of an infinite world must be loaded, baked or unloaded around one or
+/// more viewers.
+///
+/// It doesn't store any chunk, the caller calls `update` with the viewer positions (i.e. each
+/// frame), does the returned tasks and reports back when a chunk is loaded or baked.
+#[derive(Clone, Debug)]
+pub struct Streamer {
+  options: StreamerOptions,
+  chunks: HashMap<[isize; 3], ChunkState>,
+}
+
+impl Streamer {
+  pub fn new(options: StreamerOptions) -> Self {
+    Self {
+      options,
+      chunks: HashMap::new(),
+    }
+  }
+
+  pub fn options(&self) -> &StreamerOptions {
+    &self.options
+  }
+
+  /// Position in voxel units of the chunk at `coords`.
+  pub fn position(&self, coords: [isize; 3]) -> [f32; 3] {
+    let [width, height, depth] = self.options.chunk_size;
+
+    [
+      (coords[0] * width as isize) as f32,
+      (coords[1] * height as isize) as f32,
+      (coords[2] * depth as isize) as f32,
+    ]
+  }
+
+  /// Coordinates of the chunk containing the voxel `position`.
+  pub fn coords(&self, position: [f32; 3]) -> [isize; 3] {
+    let [width, height, depth] = self.options.chunk_size;
+
+    [
+      (position[0] / width as f32).floor() as isize,
+      (position[1] / height as f32).floor() as isize,
+      (position[2] / depth as f32).floor() as isize,
+    ]
+  }
+
+  /// State of the chunk at `coords`, `None` if it isn't tracked.
+  pub fn state(&self, coords: [isize; 3]) -> Option<ChunkState> {
+    self.chunks.get(&coords).copied()
+  }
+
+  /// Number of tracked chunks.
+  pub fn len(&self) -> usize {
+    self.chunks.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.chunks.is_empty()
+  }
+
+  /// Marks the chunk as loaded, so it is baked on the next update.
+  pub fn set_loaded(&mut self, coords: [isize; 3]) {
+    if let Some(state) = self.chunks.get_mut(&coords) {
+      *state = ChunkState::Loaded;
+    }
+  }
+
+  pub fn set_baked(&mut self, coords: [isize; 3]) {
+    if let Some(state) = self.chunks.get_mut(&coords) {
+      *state = ChunkState::Baked;
+    }
+  }
+
+  /// Marks a baked chunk to be baked again on the next update, i.e. after editing it.
+  pub fn invalidate(&mut self, coords: [isize; 3]) {
+    if let Some(state) = self.chunks.get_mut(&coords) {
+      if *state == ChunkState::Baked || *state == ChunkState::Baking {
+        *state = ChunkState::Loaded;
+      }
+    }
+  }
+
+  /// Returns the tasks needed for the `viewers` positions, the load and bake tasks go first
+  /// ordered by the distance to the nearest viewer, followed by the unload tasks.
+  ///
+  /// The returned tasks are considered in progress, they aren't returned again.
+  pub fn update(&mut self, viewers: &[[f32; 3]]) -> Vec<StreamerTask> {
+    let view_radius = self.options.view_radius;
+    let unload_radius = view_radius + self.options.unload_margin.max(0.0);
+
+    let mut tasks = vec![];
+    let mut unloads = vec![];
+
+    let options = &self.options;
+    self.chunks.retain(|coords, _| {
+      let keep = viewers
+        .iter()
+        .any(|viewer| distance(options, *coords, *viewer) <= unload_radius);
+
+      if !keep {
+        unloads.push(StreamerTask::Unload(*coords));
+      }
+
+      keep
+    });
+
+    for viewer in viewers {
+      for coords in self.coords_in_radius(*viewer, view_radius) {
+        self.chunks.entry(coords).or_insert_with(|| {
+          tasks.push(coords);
+          ChunkState::Loading
+        });
+      }
+    }
+
+    let mut tasks = tasks
+      .into_iter()
+      .map(StreamerTask::Load)
+      .chain(
+        self
+          .chunks
+          .iter()
+          .filter(|(_, state)| **state == ChunkState::Loaded)
+          .map(|(coords, _)| StreamerTask::Bake(*coords)),
+      )
+      .map(|task| {
+        let coords = match task {
+          StreamerTask::Load(coords)
+          | StreamerTask::Bake(coords)
+          | StreamerTask::Unload(coords) => coords,
+        };
+        let distance = viewers
+          .iter()
+          .map(|viewer| distance(&self.options, coords, *viewer))
+          .fold(f32::MAX, f32::min);
+
+        (distance, coords, task)
+      })
+      .collect::<Vec<_>>();
+
+    tasks.sort_by(|(a, a_coords, _), (b, b_coords, _)| {
+      a.total_cmp(b).then_with(|| a_coords.cmp(b_coords))
+    });
+
+    if self.options.max_tasks > 0 {
+      for (_, coords, task) in tasks.drain(self.options.max_tasks.min(tasks.len())..) {
+        // The chunks left out are picked again on the next update
+        if let StreamerTask::Load(_) = task {
+          self.chunks.remove(&coords);
+        }
+      }
+    }
+
+    for (_, coords, task) in tasks.iter() {
+      if let StreamerTask::Bake(_) = task {
+        self.chunks.insert(*coords, ChunkState::Baking);
+      }
+    }
+
+    unloads.sort_by_key(|task| match task {
+      StreamerTask::Unload(coords) => *coords,
+      _ => unreachable!(),
+    });
+
+    tasks
+      .into_iter()
+      .map(|(_, _, task)| task)
+      .chain(unloads)
+      .collect()
+  }
+
+  /// Coordinates of the chunks with its center inside `radius` of `viewer`.
+  fn coords_in_radius(&self, viewer: [f32; 3], radius: f32) -> Vec<[isize; 3]> {
+    let start = self.coords([viewer[0] - radius, viewer[1] - radius, viewer[2] - radius]);
+    let end = self.coords([viewer[0] + radius, viewer[1] + radius, viewer[2] + radius]);
+    let mut result = vec![];
+
+    for z in start[2]..=end[2] {
+      for y in start[1]..=end[1] {
+        for x in start[0]..=end[0] {
+          if distance(&self.options, [x, y, z], viewer) <= radius {
+            result.push([x, y, z]);
+          }
+        }
+      }
+    }
+
+    result
+  }
+}
+
+/// Distance from the center of the chunk at `coords` to `point`.
+fn distance(options: &StreamerOptions, coords: [isize; 3], point: [f32; 3]) -> f32 {
+  (0..3)
+    .map(|axis| {
+      let size = options.chunk_size[axis] as f32;
+      let center = coords[axis] as f32 * size + size / 2.0;
+      (center - point[axis]).powi(2)
+    })
+    .sum::<f32>()
+    .sqrt()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn options() -> StreamerOptions {
+    StreamerOptions {
+      chunk_size: [16, 16, 16],
+      view_radius: 20.0,
+      unload_margin: 16.0,
+      max_tasks: 0,
+    }
+  }
+
+  #[test]
+  fn check_load_bake_unload() {
+    let mut streamer = Streamer::new(options());
+    let viewer = [8.0, 8.0, 8.0];
+
+    let tasks = streamer.update(&[viewer]);
+    assert_eq!(tasks.len(), 7);
+    assert_eq!(tasks[0], StreamerTask::Load([0, 0, 0]));
+    assert_eq!(streamer.state([1, 0, 0]), Some(ChunkState::Loading));
+    assert!(streamer.update(&[viewer]).is_empty());
+
+    streamer.set_loaded([0, 0, 0]);
+    streamer.set_loaded([1, 0, 0]);
+    assert_eq!(
+      streamer.update(&[viewer]),
+      vec![StreamerTask::Bake([0, 0, 0]), StreamerTask::Bake([1, 0, 0])]
+    );
+    streamer.set_baked([0, 0, 0]);
+    streamer.invalidate([0, 0, 0]);
+    assert_eq!(
+      streamer.update(&[viewer]),
+      vec![StreamerTask::Bake([0, 0, 0])]
+    );
+
+    // Inside the hysteresis band nothing is unloaded
+    let tasks = streamer.update(&[[24.0, 8.0, 8.0]]);
+    assert_eq!(tasks.len(), 5);
+    assert!(tasks.contains(&StreamerTask::Load([2, 0, 0])));
+
+    let tasks = streamer.update(&[[200.0, 8.0, 8.0]]);
+    assert!(tasks.contains(&StreamerTask::Unload([-1, 0, 0])));
+    assert!(tasks.contains(&StreamerTask::Load([12, 0, 0])));
+    assert!(streamer.state([0, 0, 0]).is_none());
+  }
+
+  #[test]
+  fn check_max_tasks() {
+    let mut streamer = Streamer::new(StreamerOptions {
+      max_tasks: 2,
+      ..options()
+    });
+    let viewers = [[8.0, 8.0, 8.0], [100.0, 8.0, 8.0]];
+
+    let tasks = streamer.update(&viewers);
+    assert_eq!(
+      tasks,
+      vec![StreamerTask::Load([0, 0, 0]), StreamerTask::Load([6, 0, 0])]
+    );
+    assert_eq!(streamer.len(), 2);
+    assert_eq!(streamer.update(&viewers).len(), 2);
+    assert_eq!(streamer.len(), 4);
+  }
+}
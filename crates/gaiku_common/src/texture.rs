@@ -13,15 +13,52 @@ pub(crate) const ROW_SIZE: f32 = 1.0 / COLS as f32;
 pub(crate) const COL_PADDING: f32 = COL_SIZE * 1e-5;
 pub(crate) const ROW_PADDING: f32 = ROW_SIZE * 1e-5;
 
-fn index_to_xy(index: u8) -> (u8, u8) {
-  (index % COLS as u8, index / COLS as u8)
+/// Number of tiles of a `TextureAtlas2d`.
+pub const TILES: u16 = (COLS * ROWS) as u16;
+
+/// Column and row of the tile `index`, the indices past the last tile get the last tile.
+fn index_to_xy(index: u16) -> (u32, u32) {
+  let index = index.min(TILES - 1) as u32;
+
+  (index % COLS, index / COLS)
+}
+
+fn xy_to_uv((x, y): (u32, u32)) -> (f32, f32) {
+  (x as f32 / COLS as f32, (ROWS - 1 - y) as f32 / ROWS as f32)
 }
 
-fn xy_to_uv((x, y): (u8, u8)) -> (f32, f32) {
-  (
-    x as f32 / COLS as f32,
-    (ROWS as u8 - 1 - y) as f32 / ROWS as f32,
-  )
+/// Projects `position` into the texture atlas `tile` (from `TextureAtlas2d::get_uv`) on the plane
+/// most perpendicular to `normal` (triplanar mapping), the tile covers the box from `cell` with
+/// `cell_size`, so the texture repeats once per box.
+pub fn triplanar_uv(
+  tile: ([f32; 2], [f32; 2], [f32; 2], [f32; 2]),
+  normal: [f32; 3],
+  position: [f32; 3],
+  cell: [f32; 3],
+  cell_size: [f32; 3],
+) -> [f32; 2] {
+  let local = [
+    ((position[0] - cell[0]) / cell_size[0]).clamp(0.0, 1.0),
+    ((position[1] - cell[1]) / cell_size[1]).clamp(0.0, 1.0),
+    ((position[2] - cell[2]) / cell_size[2]).clamp(0.0, 1.0),
+  ];
+  let [nx, ny, nz] = normal.map(f32::abs);
+
+  // The `v` coordinate of the side planes follows the `y` axis so the textures stand upright
+  let [u, v] = if nx >= ny && nx >= nz {
+    [local[2], local[1]]
+  } else if ny >= nz {
+    [local[0], local[2]]
+  } else {
+    [local[0], local[1]]
+  };
+
+  let (start, _, end, _) = tile;
+
+  [
+    start[0] + (end[0] - start[0]) * u,
+    start[1] + (end[1] - start[1]) * v,
+  ]
 }
 
 /// Base common denominator across all the 2d texture implementations used.
@@ -94,7 +131,9 @@ where
     self.texture.clone()
   }
 
-  pub fn get_uv(&self, index: u8) -> ([f32; 2], [f32; 2], [f32; 2], [f32; 2]) {
+  /// Corners of the tile `index` in the texture, counter-clockwise from its lowest `u` and `v`. The
+  /// atlas has `TILES` tiles, the materials past the last one are clamped to it.
+  pub fn get_uv(&self, index: u16) -> ([f32; 2], [f32; 2], [f32; 2], [f32; 2]) {
     let xy = index_to_xy(index);
     let (u, v) = xy_to_uv(xy);
 
@@ -123,7 +162,7 @@ where
 
   pub fn set_at_index(&mut self, index: u8, pixels: Vec<[u8; 4]>) {
     // Get UV position on the tex for this index
-    let uv = self.get_uv(index).0;
+    let uv = self.get_uv(index as u16).0;
 
     // Convert uv to tex xy for the origin of this blit
     let x_o = (uv[0] * self.texture.width() as f32).floor() as u32; // Convert uv to tex xy
@@ -270,18 +309,37 @@ mod test {
     y: u32,
   ) -> ([f32; 2], [f32; 2], [f32; 2], [f32; 2]) {
     let index = x + y * ROWS;
-    assert!(index <= 255);
-    let uv = atlas.get_uv(index as u8);
+    assert!(index < TILES as u32);
+    let uv = atlas.get_uv(index as u16);
     (uv.0, uv.1, uv.2, uv.3)
   }
 
   #[test]
   fn test_index_to_xy() {
-    assert_eq!((0, 1), index_to_xy(0 + 1 * COLS as u8));
-    assert_eq!((1, 0), index_to_xy(1 + 0 * COLS as u8));
-    assert_eq!((15, 1), index_to_xy(15 + 1 * COLS as u8));
-    assert_eq!((12, 15), index_to_xy(12 + 15 * COLS as u8));
-    assert_eq!((15, 15), index_to_xy(15 + 15 * COLS as u8));
+    assert_eq!((0, 1), index_to_xy(0 + 1 * COLS as u16));
+    assert_eq!((1, 0), index_to_xy(1 + 0 * COLS as u16));
+    assert_eq!((15, 1), index_to_xy(15 + 1 * COLS as u16));
+    assert_eq!((12, 15), index_to_xy(12 + 15 * COLS as u16));
+    assert_eq!((15, 15), index_to_xy(15 + 15 * COLS as u16));
+    assert_eq!((15, 15), index_to_xy(300));
+  }
+
+  #[test]
+  fn test_triplanar_uv() {
+    let atlas = TextureAtlas2d::<Texture2d>::new_with_padding(16, 0);
+    let tile = atlas.get_uv(1);
+    let (start, _, end, _) = tile;
+    let uv =
+      |normal, position| triplanar_uv(tile, normal, position, [2.0, 0.0, 4.0], [1.0, 2.0, 1.0]);
+
+    // The side planes keep `y` as `v`
+    assert_eq!(start, uv([1.0, 0.0, 0.0], [2.0, 0.0, 4.0]));
+    assert_eq!(end, uv([-1.0, 0.0, 0.0], [2.5, 2.0, 5.0]));
+    assert_eq!([start[0], end[1]], uv([0.0, 0.0, 1.0], [2.0, 2.0, 4.5]));
+    assert_eq!([end[0], start[1]], uv([0.0, 1.0, 0.0], [3.0, 1.0, 4.0]));
+
+    // The positions outside the box are clamped to the tile
+    assert_eq!(end, uv([0.0, 0.0, 1.0], [7.0, 9.0, 4.5]));
   }
 
   #[test]
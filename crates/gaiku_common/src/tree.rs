@@ -1,228 +1,359 @@
-use crate::{boundary::Boundary, boxify::*, chunk::Chunk};
-use mint::Vector3;
+use crate::{boundary::Boundary, boxify::*};
 
-pub type Octree = Tree;
+pub type Octree<C> = Tree<C>;
+
+/// Nodes smaller than this are never subdivided.
+const MIN_NODE_SIZE: f32 = 1.0;
 
 #[derive(Clone, Debug)]
-pub struct Node {
+pub struct Node<C> {
   boundary: Boundary,
   bucket: usize,
-  leafs: Option<Vec<Chunk>>,
-  nodes: Option<Vec<Node>>,
+  leafs: Vec<C>,
+  nodes: Option<Vec<Node<C>>>,
 }
 
-impl Node {
+impl<C> Node<C>
+where
+  C: Boxify,
+{
   fn new(boundary: Boundary, bucket: usize) -> Self {
     Node {
       boundary,
       bucket,
-      leafs: None,
+      leafs: vec![],
       nodes: None,
     }
   }
 
-  fn insert(&mut self, leaf: &Chunk) -> bool {
-    if !self.boundary.contains(&leaf.position().into()) {
-      return false;
+  /// Returns `true` if `point` is inside the node, the start is inclusive and the end exclusive
+  /// so a point on the border of two nodes belongs only to one of them.
+  fn contains(&self, point: [f32; 3]) -> bool {
+    let center: [f32; 3] = self.boundary.center().into();
+    let size: [f32; 3] = self.boundary.size().into();
+
+    (0..3).all(|axis| {
+      center[axis] - size[axis] / 2.0 <= point[axis]
+        && point[axis] < center[axis] + size[axis] / 2.0
+    })
+  }
+
+  /// Index of the child node containing `point`.
+  fn child(&self, point: [f32; 3]) -> usize {
+    child_index(&self.boundary, point)
+  }
+
+  fn len(&self) -> usize {
+    match &self.nodes {
+      Some(nodes) => nodes.iter().map(|node| node.len()).sum(),
+      None => self.leafs.len(),
     }
+  }
 
-    match &mut self.nodes {
-      Some(nodes) => {
-        for node in nodes {
-          if node.insert(leaf) {
-            break;
-          }
-        }
+  /// Inserts `leaf`, returning the leaf it replaces (if any) at the same position.
+  fn insert(&mut self, leaf: C) -> Option<C> {
+    let position = leaf.position();
+
+    let index = self.child(position);
+    if let Some(nodes) = &mut self.nodes {
+      return nodes[index].insert(leaf);
+    }
+
+    if let Some(old) = self.leafs.iter_mut().find(|old| old.position() == position) {
+      return Some(std::mem::replace(old, leaf));
+    }
+
+    self.leafs.push(leaf);
+
+    let size: [f32; 3] = self.boundary.size().into();
+    if self.leafs.len() > self.bucket && size.iter().all(|&side| side > MIN_NODE_SIZE) {
+      let mut nodes = subdivide(&self.boundary, self.bucket);
+
+      for leaf in self.leafs.drain(..) {
+        nodes[child_index(&self.boundary, leaf.position())].insert(leaf);
       }
-      None => match &mut self.leafs {
-        Some(leafs) => {
-          if leafs.len() >= self.bucket {
-            let mut nodes = subdivide(&self.boundary, self.bucket);
-
-            for node in nodes.iter_mut() {
-              if node.insert(leaf) {
-                update_neighbors(&node, leaf);
-                break;
-              }
-            }
-
-            self.nodes = Some(nodes);
-            self.leafs = None;
-          } else {
-            leafs.push(leaf.clone());
-            update_neighbors(&self, leaf);
-          }
-        }
-        None => {
-          self.leafs = Some(vec![leaf.clone()]);
-        }
-      },
+
+      self.nodes = Some(nodes);
     }
 
-    true
+    None
   }
 
-  fn query(&self, range: &Boundary) -> Vec<Chunk> {
-    let mut result = vec![];
-    if !range.intersects(&self.boundary) {
-      return result;
-    }
+  /// Removes the leaf at `position`, merging back the child nodes when they fit in the bucket.
+  fn remove(&mut self, position: [f32; 3]) -> Option<C> {
+    let index = self.child(position);
 
-    match &self.nodes {
+    match &mut self.nodes {
       Some(nodes) => {
-        for node in nodes {
-          result.append(node.query(range).as_mut());
+        let removed = nodes[index].remove(position);
+
+        if removed.is_some()
+          && nodes.iter().all(|node| node.nodes.is_none())
+          && nodes.iter().map(|node| node.leafs.len()).sum::<usize>() <= self.bucket
+        {
+          self.leafs = nodes
+            .iter_mut()
+            .flat_map(|node| node.leafs.drain(..))
+            .collect();
+          self.nodes = None;
         }
+
+        removed
       }
       None => {
-        if let Some(leafs) = &self.leafs {
-          for leaf in leafs {
-            if range.contains(&leaf.position().into()) {
-              result.push(leaf.clone())
-            }
-          }
-        }
+        let index = self
+          .leafs
+          .iter()
+          .position(|leaf| leaf.position() == position)?;
+
+        Some(self.leafs.swap_remove(index))
       }
     }
+  }
 
-    result
+  fn get_leaf(&self, position: [f32; 3]) -> Option<&C> {
+    match &self.nodes {
+      Some(nodes) => nodes[self.child(position)].get_leaf(position),
+      None => self.leafs.iter().find(|leaf| leaf.position() == position),
+    }
   }
 
-  fn get_leaf(&self, point: &Vector3<f32>) -> Option<Chunk> {
-    if !self.boundary.contains(point) {
-      return None;
+  fn get_leaf_mut(&mut self, position: [f32; 3]) -> Option<&mut C> {
+    let index = self.child(position);
+
+    match &mut self.nodes {
+      Some(nodes) => nodes[index].get_leaf_mut(position),
+      None => self
+        .leafs
+        .iter_mut()
+        .find(|leaf| leaf.position() == position),
     }
+  }
 
-    match &self.nodes {
+  fn get_leaves_mut<'a>(&'a mut self, positions: &[[f32; 3]], result: &mut Vec<&'a mut C>) {
+    match &mut self.nodes {
       Some(nodes) => {
         for node in nodes {
-          if let Some(chunk) = node.get_leaf(point) {
-            return Some(chunk);
-          }
-        }
-      }
-      None => {
-        if let Some(leafs) = &self.leafs {
-          for leaf in leafs {
-            let position: Vector3<f32> = leaf.position().into();
-            if position == *point {
-              return Some(leaf.clone());
-            }
+          if positions.iter().any(|position| node.contains(*position)) {
+            node.get_leaves_mut(positions, result);
           }
         }
       }
+      None => result.extend(
+        self
+          .leafs
+          .iter_mut()
+          .filter(|leaf| positions.contains(&leaf.position())),
+      ),
     }
-
-    None
   }
 
-  fn set_leaf(&mut self, leaf: &Chunk) -> bool {
-    if !self.boundary.contains(&leaf.position().into()) {
-      return false;
+  fn query<'a>(&'a self, range: &Boundary, result: &mut Vec<&'a C>) {
+    if !range.intersects(&self.boundary) {
+      return;
     }
 
-    match &mut self.nodes {
+    match &self.nodes {
       Some(nodes) => {
         for node in nodes {
-          if node.set_leaf(leaf) {
-            return true;
-          }
+          node.query(range, result);
         }
       }
       None => {
-        if let Some(leafs) = self.leafs.as_mut() {
-          for (i, old) in leafs.iter().enumerate() {
-            let old_position: Vector3<f32> = old.position().into();
-            let leaf_position: Vector3<f32> = leaf.position().into();
-            if old_position == leaf_position {
-              leafs.insert(i, leaf.clone());
-              update_neighbors(&self, leaf);
-              return true;
-            }
+        for leaf in self.leafs.iter() {
+          if range.contains(&leaf.position().into()) {
+            result.push(leaf);
           }
         }
       }
     }
+  }
 
-    self.insert(leaf)
+  fn leaves<'a>(&'a self, result: &mut Vec<&'a C>) {
+    match &self.nodes {
+      Some(nodes) => {
+        for node in nodes {
+          node.leaves(result);
+        }
+      }
+      None => result.extend(self.leafs.iter()),
+    }
   }
 }
 
+/// Octree storing the chunks by their exact `position()`, there is at most one chunk per
+/// position and the tree grows to fit any chunk inserted outside of it.
 // TODO: In a near future I want to use the same class to manage Quadtree and Octree
 #[derive(Clone, Debug)]
-pub struct Tree {
-  nodes: Vec<Node>,
+pub struct Tree<C> {
+  root: Node<C>,
 }
 
-impl Tree {
+impl<C> Tree<C>
+where
+  C: Boxify,
+{
+  /// Creates an empty tree centered at the origin, `bucket` is the number of chunks stored per
+  /// node before subdividing it. Every axis of `size` is at least `MIN_NODE_SIZE` so the tree
+  /// can grow.
   pub fn new(size: [f32; 3], bucket: usize) -> Self {
+    let size = [
+      size[0].max(MIN_NODE_SIZE),
+      size[1].max(MIN_NODE_SIZE),
+      size[2].max(MIN_NODE_SIZE),
+    ];
     let boundary = Boundary::new([0.0, 0.0, 0.0], size);
 
     Tree {
-      nodes: subdivide(&boundary, bucket),
+      root: Node::new(boundary, bucket.max(1)),
     }
   }
 
-  pub fn insert(&mut self, leaf: &Chunk) {
-    for node in self.nodes.iter_mut() {
-      node.insert(leaf);
+  /// Current boundary of the tree.
+  pub fn boundary(&self) -> &Boundary {
+    &self.root.boundary
+  }
+
+  /// Number of chunks in the tree.
+  pub fn len(&self) -> usize {
+    self.root.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Inserts `leaf`, replacing and returning the chunk at the same position if any.
+  ///
+  /// Panics if the position of `leaf` is not finite, the tree would grow forever towards it.
+  pub fn insert(&mut self, leaf: C) -> Option<C> {
+    let position = leaf.position();
+    assert!(
+      position.iter().all(|value| value.is_finite()),
+      "Chunk position {:?} is not finite",
+      position
+    );
+
+    while !self.root.contains(position) {
+      self.grow(position);
     }
+
+    self.root.insert(leaf)
   }
 
-  pub fn query(&self, boundary: &Boundary) -> Vec<Chunk> {
+  /// Removes and returns the chunk at `position`.
+  pub fn remove(&mut self, position: [f32; 3]) -> Option<C> {
+    if self.root.contains(position) {
+      self.root.remove(position)
+    } else {
+      None
+    }
+  }
+
+  /// Returns the chunks with its position inside `boundary`.
+  pub fn query(&self, boundary: &Boundary) -> Vec<&C> {
     let mut result = vec![];
+    self.root.query(boundary, &mut result);
 
-    for node in self.nodes.iter() {
-      result.append(&mut node.query(boundary));
+    result
+  }
+
+  /// Returns the chunk at `position`.
+  pub fn get_leaf(&self, position: [f32; 3]) -> Option<&C> {
+    if self.root.contains(position) {
+      self.root.get_leaf(position)
+    } else {
+      None
+    }
+  }
+
+  pub fn get_leaf_mut(&mut self, position: [f32; 3]) -> Option<&mut C> {
+    if self.root.contains(position) {
+      self.root.get_leaf_mut(position)
+    } else {
+      None
     }
+  }
+
+  /// Returns the chunks at any of the `positions` at once, in no particular order, so several of
+  /// them can be edited together.
+  pub fn get_leaves_mut(&mut self, positions: &[[f32; 3]]) -> Vec<&mut C> {
+    let mut result = vec![];
+    self.root.get_leaves_mut(positions, &mut result);
 
     result
   }
 
-  pub fn get_leaf(&self, point: &Vector3<f32>) -> Option<Chunk> {
-    for node in self.nodes.iter() {
-      if let Some(chunk) = node.get_leaf(point) {
-        return Some(chunk);
-      }
-    }
+  /// Returns all the chunks in the tree.
+  pub fn leaves(&self) -> Vec<&C> {
+    let mut result = vec![];
+    self.root.leaves(&mut result);
 
-    None
+    result
   }
 
-  pub fn set_leaf(&mut self, leaf: &Chunk) -> bool {
-    for node in self.nodes.iter_mut() {
-      if node.set_leaf(leaf) {
-        return true;
+  /// Doubles the tree size towards `point`, the current root becomes one of the new root nodes.
+  fn grow(&mut self, point: [f32; 3]) {
+    let center: [f32; 3] = self.root.boundary.center().into();
+    let size: [f32; 3] = self.root.boundary.size().into();
+    let mut new_center = center;
+
+    for axis in 0..3 {
+      if point[axis] < center[axis] {
+        new_center[axis] -= size[axis] / 2.0;
+      } else {
+        new_center[axis] += size[axis] / 2.0;
       }
     }
 
-    false
+    let boundary = Boundary::new(new_center, [size[0] * 2.0, size[1] * 2.0, size[2] * 2.0]);
+    let bucket = self.root.bucket;
+    let old = std::mem::replace(&mut self.root, Node::new(boundary, bucket));
+
+    let mut nodes = subdivide(&self.root.boundary, bucket);
+    let index = self.root.child(center);
+    nodes[index] = old;
+
+    self.root.nodes = Some(nodes);
   }
 }
 
+/// Index of the node returned by `subdivide` containing `point`.
+fn child_index(boundary: &Boundary, point: [f32; 3]) -> usize {
+  let center = boundary.center();
+
+  (point[0] >= center.x) as usize
+    | ((point[1] >= center.y) as usize) << 1
+    | ((point[2] >= center.z) as usize) << 2
+}
+
 #[allow(clippy::many_single_char_names)]
-fn subdivide(boundary: &Boundary, bucket: usize) -> Vec<Node> {
-  let w = boundary.size.x / 2.0;
-  let h = boundary.size.y / 2.0;
-  let d = boundary.size.z / 2.0;
+fn subdivide<C>(boundary: &Boundary, bucket: usize) -> Vec<Node<C>>
+where
+  C: Boxify,
+{
+  let w = boundary.size().x / 2.0;
+  let h = boundary.size().y / 2.0;
+  let d = boundary.size().z / 2.0;
   let size: [f32; 3] = [w, h, d];
   let hw = size[0] / 2.0;
   let hh = size[1] / 2.0;
   let hd = size[2] / 2.0;
 
-  let x = w - boundary.center.x;
-  let y = h - boundary.center.y;
-  let z = d - boundary.center.z;
+  let x = boundary.center().x;
+  let y = boundary.center().y;
+  let z = boundary.center().z;
 
+  // Ordered as `child_index`
   let coords: [[f32; 3]; 8] = [
-    [x - hw, y + hh, z + hd],
-    [x + hw, y + hh, z + hd],
+    [x - hw, y - hh, z - hd],
+    [x + hw, y - hh, z - hd],
     [x - hw, y + hh, z - hd],
     [x + hw, y + hh, z - hd],
     [x - hw, y - hh, z + hd],
     [x + hw, y - hh, z + hd],
-    [x - hw, y - hh, z - hd],
-    [x + hw, y - hh, z - hd],
+    [x - hw, y + hh, z + hd],
+    [x + hw, y + hh, z + hd],
   ];
 
   let mut result = vec![];
@@ -234,21 +365,91 @@ fn subdivide(boundary: &Boundary, bucket: usize) -> Vec<Node> {
   result
 }
 
-fn update_neighbors(node: &Node, leaf: &Chunk) {
-  let [x, y, z] = leaf.position();
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::chunk::{Chunk, Chunkify, ChunkifyMut};
+
+  #[test]
+  fn check_insert_replace_remove() {
+    let mut tree = Octree::new([64.0, 64.0, 64.0], 2);
+
+    for x in 0..4 {
+      for z in 0..4 {
+        assert!(tree
+          .insert(Chunk::new(
+            [x as f32 * 16.0, 0.0, z as f32 * 16.0],
+            16,
+            16,
+            16
+          ))
+          .is_none());
+      }
+    }
+    assert_eq!(tree.len(), 16);
+
+    let mut chunk = Chunk::new([16.0, 0.0, 32.0], 16, 16, 16);
+    chunk.set(0, 0, 0, (1, 255));
+    assert!(tree.insert(chunk).is_some());
+    assert_eq!(tree.len(), 16);
+    assert_eq!(
+      tree.get_leaf([16.0, 0.0, 32.0]).unwrap().get(0, 0, 0),
+      (1, 255)
+    );
+    assert!(tree.get_leaf([16.0, 0.0, 33.0]).is_none());
+
+    for x in 0..4 {
+      for z in 0..4 {
+        assert!(tree
+          .remove([x as f32 * 16.0, 0.0, z as f32 * 16.0])
+          .is_some());
+      }
+    }
+    assert!(tree.is_empty());
+    assert!(tree.remove([0.0, 0.0, 0.0]).is_none());
+    assert!(tree.root.nodes.is_none());
+  }
 
-  let coords: [Vector3<f32>; 6] = [
-    [x - 1.0, y, z].into(),
-    [x + 1.0, y, z].into(),
-    [x, y - 1.0, z].into(),
-    [x, y + 1.0, z].into(),
-    [x, y, z - 1.0].into(),
-    [x, y, z + 1.0].into(),
-  ];
+  #[test]
+  fn check_grow_and_query() {
+    let mut tree = Octree::new([32.0, 32.0, 32.0], 1);
 
-  for coord in coords.iter() {
-    if let Some(chunk) = node.get_leaf(coord) {
-      chunk.update_neighbor_data(&leaf);
-    }
+    tree.insert(Chunk::new([0.0, 0.0, 0.0], 16, 16, 16));
+    tree.insert(Chunk::new([-16.0, 0.0, 0.0], 16, 16, 16));
+    tree.insert(Chunk::new([160.0, -48.0, 0.0], 16, 16, 16));
+    tree.insert(Chunk::new([-320.0, 0.0, 16.0], 16, 16, 16));
+
+    assert_eq!(tree.len(), 4);
+    assert!(tree.get_leaf([160.0, -48.0, 0.0]).is_some());
+    assert!(tree.get_leaf([-320.0, 0.0, 16.0]).is_some());
+    assert!(tree.get_leaf([-16.0, 0.0, 0.0]).is_some());
+
+    let found = tree.query(&Boundary::new([0.0, 0.0, 0.0], [40.0, 40.0, 40.0]));
+    assert_eq!(found.len(), 2);
+    assert_eq!(tree.leaves().len(), 4);
+
+    let leaves = tree.get_leaves_mut(&[[0.0, 0.0, 0.0], [160.0, -48.0, 0.0], [16.0, 0.0, 0.0]]);
+    assert_eq!(leaves.len(), 2);
+  }
+
+  #[test]
+  #[should_panic]
+  fn check_insert_nan() {
+    let mut tree = Octree::new([32.0, 32.0, 32.0], 1);
+
+    tree.insert(Chunk::new([0.0, f32::NAN, 0.0], 16, 16, 16));
+  }
+
+  #[test]
+  fn check_min_node_size() {
+    let mut tree = Octree::new([4.0, 0.0, 4.0], 1);
+    assert_eq!(tree.boundary().size().y, MIN_NODE_SIZE);
+
+    // The y axis is already at the minimum size, so the root keeps both chunks
+    tree.insert(Chunk::new([0.0, 0.0, 0.0], 1, 1, 1));
+    tree.insert(Chunk::new([1.0, 0.0, 1.0], 1, 1, 1));
+
+    assert!(tree.root.nodes.is_none());
+    assert_eq!(tree.len(), 2);
   }
 }
@@ -0,0 +1,83 @@
+/// Base common denominator across all the voxel values stored in the chunks.
+///
+/// The bakers and file formats only access the voxel data through this trait, so any value
+/// that can describe a material and a density can be used, i.e. `(u16, f32)` or a custom struct.
+pub trait Voxel: Copy + Default + PartialEq {
+  /// Creates a voxel from a material index and a density in the `0.0..=1.0` range.
+  fn new(material: u16, density: f32) -> Self;
+
+  /// Material of the voxel, used as texture atlas index by the bakers.
+  fn material(&self) -> u16;
+
+  /// Density of the voxel in the `0.0..=1.0` range, used by the smooth bakers.
+  fn density(&self) -> f32;
+
+  /// Returns `true` if the voxel is air, by default when it has no density.
+  fn is_empty(&self) -> bool {
+    self.density() <= 0.0
+  }
+}
+
+/// Material index and density, the density is stored as `0..=255`.
+impl Voxel for (u8, u8) {
+  fn new(material: u16, density: f32) -> Self {
+    (
+      material.min(u8::MAX as u16) as u8,
+      (density.clamp(0.0, 1.0) * 255.0).round() as u8,
+    )
+  }
+
+  fn material(&self) -> u16 {
+    self.0 as u16
+  }
+
+  fn density(&self) -> f32 {
+    self.1 as f32 / 255.0
+  }
+
+  fn is_empty(&self) -> bool {
+    self.1 == 0
+  }
+}
+
+/// Material index and density.
+impl Voxel for (u16, f32) {
+  fn new(material: u16, density: f32) -> Self {
+    (material, density)
+  }
+
+  fn material(&self) -> u16 {
+    self.0
+  }
+
+  fn density(&self) -> f32 {
+    self.1
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn check_u8_tuple() {
+    let voxel = <(u8, u8)>::new(3, 1.0);
+    assert_eq!(voxel, (3, 255));
+    assert_eq!(voxel.material(), 3);
+    assert_eq!(voxel.density(), 1.0);
+    assert!(!voxel.is_empty());
+
+    assert_eq!(<(u8, u8)>::new(300, 2.0), (255, 255));
+    assert!(<(u8, u8)>::new(3, 0.0).is_empty());
+    assert!(<(u8, u8)>::default().is_empty());
+  }
+
+  #[test]
+  fn check_u16_f32_tuple() {
+    let voxel = <(u16, f32)>::new(300, 0.5);
+    assert_eq!(voxel.material(), 300);
+    assert_eq!(voxel.density(), 0.5);
+    assert!(!voxel.is_empty());
+    assert!(<(u16, f32)>::default().is_empty());
+  }
+}
@@ -1,5 +1,5 @@
-use gaiku_common::{prelude::*, Result};
-use std::convert::TryInto;
+use gaiku_common::{prelude::*, texture::TILES, Result};
+use std::convert::TryFrom;
 
 use gox::{Block, Data, Gox, Only};
 
@@ -8,11 +8,10 @@ pub struct GoxReader;
 
 // TODO: The generated data appears rotated, need to rotate from back to bottom
 impl FileFormat for GoxReader {
-  type Value = (u8, u8);
-
-  fn load<C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
+  fn load<V, C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
   where
-    C: Chunkify<Self::Value> + ChunkifyMut<Self::Value> + Boxify,
+    V: Voxel,
+    C: Chunkify<V> + ChunkifyMut<V> + Boxify,
     T: Texturify2d,
   {
     let gox = Gox::from_bytes(bytes, vec![Only::Layers, Only::Blocks]);
@@ -53,9 +52,7 @@ impl FileFormat for GoxReader {
                         index
                       };
 
-                      if index <= std::u8::MAX as usize {
-                        chunk.set(x, z, y, (index as u8, 255));
-                      }
+                      chunk.set(x, z, y, V::new(u16::try_from(index)?, 1.0));
                     }
                   }
                 }
@@ -71,9 +68,10 @@ impl FileFormat for GoxReader {
     if !colors.is_empty() {
       let mut atlas = TextureAtlas2d::new(1);
 
-      for (index, color) in colors.iter().enumerate() {
-        // colors should limited to 255 so (index.try_into().unwrap()) should fit into u8 for set_at_index
-        atlas.fill_at_index(index.try_into().unwrap(), *color);
+      // The atlas has a tile for the first `TILES` colors, the materials past them use the
+      // last tile
+      for (index, color) in colors.iter().enumerate().take(TILES as usize) {
+        atlas.fill_at_index(index as u8, *color);
       }
 
       Ok((result, Some(atlas)))
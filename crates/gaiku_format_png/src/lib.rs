@@ -6,11 +6,10 @@ use image::load_from_memory;
 pub struct PNGReader;
 
 impl FileFormat for PNGReader {
-  type Value = (u8, u8);
-
-  fn load<C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
+  fn load<V, C, T>(bytes: Vec<u8>) -> Result<(Vec<C>, Option<TextureAtlas2d<T>>)>
   where
-    C: Chunkify<Self::Value> + ChunkifyMut<Self::Value> + Boxify,
+    V: Voxel,
+    C: Chunkify<V> + ChunkifyMut<V> + Boxify,
     T: Texturify2d,
   {
     let mut result = vec![];
@@ -24,7 +23,12 @@ impl FileFormat for PNGReader {
     for x in 0..img.width() as u32 {
       for y in 0..img.height() as u32 {
         let color = img.get_pixel(x, y).0[0];
-        chunk.set(x as usize, y as usize, 0, (color, color));
+        chunk.set(
+          x as usize,
+          y as usize,
+          0,
+          V::new(color as u16, color as f32 / 255.0),
+        );
       }
     }
 
@@ -0,0 +1,68 @@
+use std::time::Instant;
+
+use gaiku_baker_surface_nets::{SmoothSurfaceNetsBaker, SurfaceNetsBaker};
+use gaiku_common::{
+  chunk::Chunk,
+  mesh::Mesh,
+  prelude::*,
+  texture::{Texture2d, TextureAtlas2d},
+  Result,
+};
+use gaiku_format_gox::GoxReader;
+
+mod common;
+
+use crate::common::export;
+
+fn read<B: Baker>(name: &str, suffix: &str) -> Result<()> {
+  let now = Instant::now();
+  let file = format!(
+    "{}/examples/assets/{}.gox",
+    env!("CARGO_MANIFEST_DIR"),
+    name
+  );
+  let (mut chunks, texture): (Vec<Chunk>, Option<TextureAtlas2d<Texture2d>>) =
+    GoxReader::read(&file)?;
+  Chunk::update_neighbors(&mut chunks);
+  let options = BakerOptions {
+    texture,
+    ..Default::default()
+  };
+  let mut meshes: Vec<(Mesh, [f32; 3])> = vec![];
+
+  let reader_elapsed = now.elapsed().as_micros();
+  let now = Instant::now();
+
+  for chunk in chunks.iter() {
+    let mesh = B::bake(chunk, &options)?;
+    if let Some(mesh) = mesh {
+      meshes.push((mesh, chunk.position()));
+    }
+  }
+
+  let baker_elapsed = now.elapsed().as_micros();
+  let now = Instant::now();
+
+  export(meshes, &format!("{}_{}", name, suffix));
+
+  println!(
+    "<<{} {}>> Chunks: {} Reader: {} micros Baker: {} micros Export: {} micros",
+    name,
+    suffix,
+    chunks.len(),
+    reader_elapsed,
+    baker_elapsed,
+    now.elapsed().as_micros()
+  );
+
+  Ok(())
+}
+
+fn main() -> Result<()> {
+  for name in ["small_tree", "terrain", "planet"].iter() {
+    let _ = read::<SurfaceNetsBaker>(name, "sn");
+    let _ = read::<SmoothSurfaceNetsBaker>(name, "ssn");
+  }
+
+  Ok(())
+}
@@ -21,7 +21,9 @@ fn read(name: &str) -> Result<()> {
     env!("CARGO_MANIFEST_DIR"),
     name
   );
-  let (chunks, texture): (Vec<Chunk>, Option<TextureAtlas2d<Texture2d>>) = GoxReader::read(&file)?;
+  let (mut chunks, texture): (Vec<Chunk>, Option<TextureAtlas2d<Texture2d>>) =
+    GoxReader::read(&file)?;
+  Chunk::update_neighbors(&mut chunks);
   let options = BakerOptions {
     texture,
     ..Default::default()
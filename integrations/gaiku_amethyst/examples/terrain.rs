@@ -125,7 +125,7 @@ impl GameLoad {
       env!("CARGO_MANIFEST_DIR"),
       "terrain"
     );
-    let (chunks, texture) = GoxReader::read::<Chunk, GaikuTexture2d>(&file).unwrap();
+    let (chunks, texture) = GoxReader::read::<_, Chunk, GaikuTexture2d>(&file).unwrap();
     let options = BakerOptions {
       texture,
       ..Default::default()
@@ -133,7 +133,7 @@ impl GameLoad {
     let mut meshes = vec![];
 
     for chunk in chunks.iter() {
-      let mesh = VoxelBaker::bake::<Chunk, GaikuTexture2d, GaikuMesh>(chunk, &options).unwrap();
+      let mesh = VoxelBaker::bake::<_, Chunk, GaikuTexture2d, GaikuMesh>(chunk, &options).unwrap();
       if let Some(mesh) = mesh {
         meshes.push((mesh, chunk.position()));
       }
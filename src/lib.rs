@@ -1,7 +1,13 @@
+#[cfg(feature = "gaiku_baker_dual_contouring")]
+pub use gaiku_baker_dual_contouring::*;
+#[cfg(feature = "gaiku_baker_greedy")]
+pub use gaiku_baker_greedy::*;
 #[cfg(feature = "gaiku_baker_heightmap")]
 pub use gaiku_baker_heightmap::*;
 #[cfg(feature = "gaiku_baker_marching_cubes")]
 pub use gaiku_baker_marching_cubes::*;
+#[cfg(feature = "gaiku_baker_surface_nets")]
+pub use gaiku_baker_surface_nets::*;
 #[cfg(feature = "gaiku_baker_voxel")]
 pub use gaiku_baker_voxel::*;
 
@@ -10,7 +16,4 @@ pub use gaiku_format_gox::*;
 #[cfg(feature = "gaiku_format_png")]
 pub use gaiku_format_png::*;
 
-#[cfg(feature = "gaiku_amethyst")]
-pub use gaiku_amethyst::*;
-
 pub use gaiku_common as common;